@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::accounts::core::api::AccountDataAPI;
+use crate::accounts::core::domain::Account;
+use crate::accounts::core::error::AccountDataError;
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn resolve_account(
+    api: tauri::State<'_, Arc<dyn AccountDataAPI>>,
+    profile_name: String,
+) -> Result<Account, AccountDataError> {
+    api.resolve_account(profile_name.as_str())
+        .await
+        .map_err(AccountDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn get_profile_accounts(
+    api: tauri::State<'_, Arc<dyn AccountDataAPI>>,
+) -> Result<HashMap<String, Account>, AccountDataError> {
+    api.get_profile_accounts().map_err(AccountDataError::from)
+}
@@ -0,0 +1,5 @@
+pub mod account_service;
+pub mod api;
+pub mod domain;
+pub mod error;
+pub mod spi;
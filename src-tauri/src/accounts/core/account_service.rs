@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tokio::sync::Mutex;
+
+use crate::accounts::core::api::AccountDataAPI;
+use crate::accounts::core::domain::Account;
+use crate::accounts::core::error::AccountDataError;
+use crate::accounts::core::spi::{AccountCacheSPI, AccountDataSPI};
+
+pub struct AccountService {
+    account_data_spi: Box<dyn AccountDataSPI>,
+    account_cache_spi: Box<dyn AccountCacheSPI>,
+    // Serializes read-modify-write cycles on the cache.
+    cache_lock: Mutex<()>,
+}
+
+impl AccountService {
+    const MAX_CACHE_AGE_DAYS: i64 = 1;
+
+    pub fn new(
+        account_data_spi: Box<dyn AccountDataSPI>,
+        account_cache_spi: Box<dyn AccountCacheSPI>,
+    ) -> Self {
+        Self {
+            account_data_spi,
+            account_cache_spi,
+            cache_lock: Mutex::new(()),
+        }
+    }
+
+    async fn load_account(
+        &self,
+        profile_name: &str,
+        account_id: &str,
+    ) -> error_stack::Result<Account, AccountDataError> {
+        let alias = self
+            .account_data_spi
+            .load_account_alias(profile_name)
+            .await?;
+        let organization_account_name = self
+            .account_data_spi
+            .load_organization_account_name(profile_name, account_id)
+            .await?;
+
+        Ok(Account::new(
+            account_id.to_string(),
+            alias,
+            organization_account_name,
+            Utc::now(),
+        ))
+    }
+}
+
+#[async_trait]
+impl AccountDataAPI for AccountService {
+    async fn resolve_account(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<Account, AccountDataError> {
+        let account_id = self.account_data_spi.load_account_id(profile_name).await?;
+
+        let cached_account = {
+            let _guard = self.cache_lock.lock().await;
+            self.account_cache_spi
+                .load_account_cache()?
+                .get_account(&account_id)
+                .filter(|account| {
+                    !account.is_stale(Utc::now(), Duration::days(Self::MAX_CACHE_AGE_DAYS))
+                })
+                .cloned()
+        };
+        // Loaded without holding the lock, so a slow profile does not hold up the others.
+        let account = match cached_account {
+            Some(account) => account,
+            None => self.load_account(profile_name, &account_id).await?,
+        };
+
+        // Read again, other profiles may have been resolved in the meantime.
+        let _guard = self.cache_lock.lock().await;
+        let mut account_cache = self.account_cache_spi.load_account_cache()?;
+        account_cache.add_account(account.clone());
+        account_cache.assign_profile(profile_name, &account_id);
+        self.account_cache_spi.save_account_cache(&account_cache)?;
+
+        Ok(account)
+    }
+
+    fn get_profile_accounts(
+        &self,
+    ) -> error_stack::Result<HashMap<String, Account>, AccountDataError> {
+        let account_cache = self.account_cache_spi.load_account_cache()?;
+
+        Ok(account_cache.profile_accounts())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use mockall::predicate::eq;
+    use mockall::Sequence;
+    use spectral::prelude::*;
+
+    use crate::accounts::core::domain::AccountCache;
+    use crate::accounts::core::spi::{MockAccountCacheSPI, MockAccountDataSPI};
+
+    use super::*;
+
+    const ACCOUNT_ID: &str = "123456789012";
+
+    #[tokio::test]
+    async fn should_resolve_alias_and_organization_account_name_on_cache_miss() {
+        let mut account_data_spi_mock = MockAccountDataSPI::new();
+        account_data_spi_mock
+            .expect_load_account_id()
+            .with(eq("prod"))
+            .returning(|_| Ok(ACCOUNT_ID.to_string()));
+        account_data_spi_mock
+            .expect_load_account_alias()
+            .with(eq("prod"))
+            .times(1)
+            .returning(|_| Ok(Some("payments-prod".to_string())));
+        account_data_spi_mock
+            .expect_load_organization_account_name()
+            .with(eq("prod"), eq(ACCOUNT_ID))
+            .times(1)
+            .returning(|_, _| Ok(Some("Payments Production".to_string())));
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .returning(|| Ok(AccountCache::new()));
+        account_cache_spi_mock
+            .expect_save_account_cache()
+            .withf(|cache| {
+                cache
+                    .get_profile_account("prod")
+                    .and_then(|account| account.alias.clone())
+                    == Some("payments-prod".to_string())
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let cut = AccountService::new(
+            Box::new(account_data_spi_mock),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.resolve_account("prod").await;
+
+        assert_that!(actual).is_ok();
+        let account = actual.unwrap();
+        assert_that!(account.alias).is_equal_to(Some("payments-prod".to_string()));
+        assert_that!(account.organization_account_name)
+            .is_equal_to(Some("Payments Production".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_use_cached_account_and_assign_profile_on_cache_hit() {
+        let mut account_data_spi_mock = MockAccountDataSPI::new();
+        account_data_spi_mock
+            .expect_load_account_id()
+            .returning(|_| Ok(ACCOUNT_ID.to_string()));
+        account_data_spi_mock.expect_load_account_alias().never();
+        account_data_spi_mock
+            .expect_load_organization_account_name()
+            .never();
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .returning(|| {
+                let mut cache = AccountCache::new();
+                cache.add_account(Account::new(
+                    ACCOUNT_ID.to_string(),
+                    Some("payments-prod".to_string()),
+                    None,
+                    Utc::now(),
+                ));
+                Ok(cache)
+            });
+        account_cache_spi_mock
+            .expect_save_account_cache()
+            .withf(|cache| cache.get_profile_account("prod-admin").is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+        let cut = AccountService::new(
+            Box::new(account_data_spi_mock),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.resolve_account("prod-admin").await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_reload_stale_cached_account() {
+        let mut account_data_spi_mock = MockAccountDataSPI::new();
+        account_data_spi_mock
+            .expect_load_account_id()
+            .returning(|_| Ok(ACCOUNT_ID.to_string()));
+        account_data_spi_mock
+            .expect_load_account_alias()
+            .times(1)
+            .returning(|_| Ok(Some("payments-prod-renamed".to_string())));
+        account_data_spi_mock
+            .expect_load_organization_account_name()
+            .times(1)
+            .returning(|_, _| Ok(None));
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .returning(|| {
+                let mut cache = AccountCache::new();
+                cache.add_account(Account::new(
+                    ACCOUNT_ID.to_string(),
+                    Some("payments-prod".to_string()),
+                    None,
+                    Utc::now() - Duration::days(7),
+                ));
+                Ok(cache)
+            });
+        account_cache_spi_mock
+            .expect_save_account_cache()
+            .returning(|_| Ok(()));
+        let cut = AccountService::new(
+            Box::new(account_data_spi_mock),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.resolve_account("prod").await;
+
+        assert_that!(actual)
+            .is_ok()
+            .map(|account| &account.alias)
+            .is_equal_to(Some("payments-prod-renamed".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_keep_accounts_resolved_meanwhile_when_saving() {
+        let mut account_data_spi_mock = MockAccountDataSPI::new();
+        account_data_spi_mock
+            .expect_load_account_id()
+            .returning(|_| Ok(ACCOUNT_ID.to_string()));
+        account_data_spi_mock
+            .expect_load_account_alias()
+            .returning(|_| Ok(None));
+        account_data_spi_mock
+            .expect_load_organization_account_name()
+            .returning(|_, _| Ok(None));
+        let mut sequence = Sequence::new();
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|| Ok(AccountCache::new()));
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|| {
+                let mut cache = AccountCache::new();
+                cache.add_account(Account::new(
+                    "210987654321".to_string(),
+                    None,
+                    None,
+                    Utc::now(),
+                ));
+                cache.assign_profile("dev", "210987654321");
+                Ok(cache)
+            });
+        account_cache_spi_mock
+            .expect_save_account_cache()
+            .withf(|cache| {
+                cache.get_profile_account("dev").is_some()
+                    && cache.get_profile_account("prod").is_some()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let cut = AccountService::new(
+            Box::new(account_data_spi_mock),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.resolve_account("prod").await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_return_error_when_caller_identity_cannot_be_resolved() {
+        let mut account_data_spi_mock = MockAccountDataSPI::new();
+        account_data_spi_mock
+            .expect_load_account_id()
+            .returning(|_| Err(Report::new(AccountDataError::InvalidCredentialsError)));
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock.expect_save_account_cache().never();
+        let cut = AccountService::new(
+            Box::new(account_data_spi_mock),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.resolve_account("prod").await;
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_return_cached_accounts_by_profile() {
+        let mut account_cache_spi_mock = MockAccountCacheSPI::new();
+        account_cache_spi_mock
+            .expect_load_account_cache()
+            .returning(|| {
+                let mut cache = AccountCache::new();
+                cache.add_account(Account::new(
+                    ACCOUNT_ID.to_string(),
+                    Some("payments-prod".to_string()),
+                    None,
+                    Utc::now(),
+                ));
+                cache.assign_profile("prod", ACCOUNT_ID);
+                Ok(cache)
+            });
+        let cut = AccountService::new(
+            Box::new(MockAccountDataSPI::new()),
+            Box::new(account_cache_spi_mock),
+        );
+
+        let actual = cut.get_profile_accounts();
+
+        assert_that!(actual)
+            .is_ok()
+            .contains_key("prod".to_string());
+    }
+}
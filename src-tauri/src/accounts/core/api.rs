@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::accounts::core::domain::Account;
+use crate::accounts::core::error::AccountDataError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait AccountDataAPI: Send + Sync {
+    async fn resolve_account(&self, profile_name: &str) -> Result<Account, AccountDataError>;
+
+    fn get_profile_accounts(&self) -> Result<HashMap<String, Account>, AccountDataError>;
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Account {
+    pub account_id: String,
+    pub alias: Option<String>,
+    pub organization_account_name: Option<String>,
+    pub resolved_at: DateTime<Utc>,
+}
+
+impl Account {
+    pub fn new(
+        account_id: String,
+        alias: Option<String>,
+        organization_account_name: Option<String>,
+        resolved_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            account_id,
+            alias,
+            organization_account_name,
+            resolved_at,
+        }
+    }
+
+    /// The most human readable name known for this account. The IAM alias takes precedence as
+    /// it is usually maintained by the account owners themselves.
+    pub fn display_name(&self) -> Option<&str> {
+        self.alias
+            .as_deref()
+            .or(self.organization_account_name.as_deref())
+    }
+
+    pub fn is_stale(&self, now: DateTime<Utc>, max_age: chrono::Duration) -> bool {
+        now - self.resolved_at > max_age
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AccountCache {
+    accounts: HashMap<String, Account>,
+    profiles: HashMap<String, String>,
+}
+
+impl AccountCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_account(&self, account_id: &str) -> Option<&Account> {
+        self.accounts.get(account_id)
+    }
+
+    pub fn get_profile_account(&self, profile_name: &str) -> Option<&Account> {
+        self.profiles
+            .get(profile_name)
+            .and_then(|account_id| self.accounts.get(account_id))
+    }
+
+    pub fn add_account(&mut self, account: Account) {
+        self.accounts.insert(account.account_id.clone(), account);
+    }
+
+    pub fn assign_profile(&mut self, profile_name: &str, account_id: &str) {
+        self.profiles
+            .insert(profile_name.to_string(), account_id.to_string());
+    }
+
+    pub fn profile_accounts(&self) -> HashMap<String, Account> {
+        self.profiles
+            .iter()
+            .filter_map(|(profile_name, account_id)| {
+                self.accounts
+                    .get(account_id)
+                    .map(|account| (profile_name.clone(), account.clone()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn account(alias: Option<&str>, organization_account_name: Option<&str>) -> Account {
+        Account::new(
+            "123456789012".to_string(),
+            alias.map(|alias| alias.to_string()),
+            organization_account_name.map(|name| name.to_string()),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn should_prefer_alias_as_display_name() {
+        let cut = account(Some("payments-prod"), Some("Payments Production"));
+
+        assert_that!(cut.display_name()).is_equal_to(Some("payments-prod"));
+    }
+
+    #[test]
+    fn should_fall_back_to_organization_account_name_as_display_name() {
+        let cut = account(None, Some("Payments Production"));
+
+        assert_that!(cut.display_name()).is_equal_to(Some("Payments Production"));
+    }
+
+    #[test]
+    fn should_be_stale_when_older_than_max_age() {
+        let now = Utc::now();
+        let mut cut = account(None, None);
+        cut.resolved_at = now - Duration::days(2);
+
+        assert_that!(cut.is_stale(now, Duration::days(1))).is_true();
+        assert_that!(cut.is_stale(now, Duration::days(3))).is_false();
+    }
+
+    #[test]
+    fn should_resolve_account_of_assigned_profile() {
+        let mut cut = AccountCache::new();
+        let account = account(Some("payments-prod"), None);
+
+        cut.add_account(account.clone());
+        cut.assign_profile("prod", &account.account_id);
+
+        assert_that!(cut.get_profile_account("prod")).is_equal_to(Some(&account));
+        assert_that!(cut.get_profile_account("dev")).is_none();
+    }
+
+    #[test]
+    fn should_list_accounts_by_profile() {
+        let mut cut = AccountCache::new();
+        let account = account(Some("payments-prod"), None);
+        cut.add_account(account.clone());
+        cut.assign_profile("prod", &account.account_id);
+        cut.assign_profile("prod-admin", &account.account_id);
+        cut.assign_profile("orphan", "999999999999");
+
+        let actual = cut.profile_accounts();
+
+        assert_that!(actual).has_length(2);
+        assert_that!(actual.get("prod")).is_equal_to(Some(&account));
+        assert_that!(actual.get("prod-admin")).is_equal_to(Some(&account));
+    }
+}
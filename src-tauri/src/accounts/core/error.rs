@@ -0,0 +1,92 @@
+use std::fmt::{Display, Formatter};
+
+use error_stack::{Context, Report};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum AccountDataError {
+    InvalidCredentialsError,
+    AccountDataLoadError(String),
+    AccountCacheLoadError,
+    AccountCacheWriteError,
+}
+
+impl Display for AccountDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountDataError::InvalidCredentialsError => write!(f, "invalid credentials error"),
+            AccountDataError::AccountDataLoadError(reason) => {
+                write!(f, "failed to load account data: {}", reason)
+            }
+            AccountDataError::AccountCacheLoadError => write!(f, "failed to load account cache"),
+            AccountDataError::AccountCacheWriteError => {
+                write!(f, "failed to write account cache")
+            }
+        }
+    }
+}
+
+impl Context for AccountDataError {}
+
+impl From<Report<AccountDataError>> for AccountDataError {
+    fn from(value: Report<AccountDataError>) -> Self {
+        let context = value.current_context();
+        context.clone()
+    }
+}
+
+impl Serialize for AccountDataError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AccountDataError", 1)?;
+        let (code, message) = match self {
+            AccountDataError::InvalidCredentialsError => {
+                ("InvalidCredentialsError", self.to_string())
+            }
+            AccountDataError::AccountDataLoadError(_) => ("AccountDataLoadError", self.to_string()),
+            AccountDataError::AccountCacheLoadError => ("AccountCacheLoadError", self.to_string()),
+            AccountDataError::AccountCacheWriteError => {
+                ("AccountCacheWriteError", self.to_string())
+            }
+        };
+        state.serialize_field("error", &json!({ "code": code, "message": message }))?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_return_account_cache_load_error_when_calling_from_on_report_with_context_account_cache_load_error(
+    ) {
+        let error = AccountDataError::AccountCacheLoadError;
+        let report = Report::new(error.clone());
+
+        let result: AccountDataError = report.into();
+
+        assert_eq!(error, result);
+    }
+
+    #[test]
+    fn should_serialize_account_data_load_error_to_json() {
+        let error = AccountDataError::AccountDataLoadError("AccessDenied".to_string());
+        let expected = json!({
+            "error": {
+                "code": "AccountDataLoadError",
+                "message": "failed to load account data: AccessDenied"
+            }
+        });
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected.to_string());
+    }
+}
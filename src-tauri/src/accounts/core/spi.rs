@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::accounts::core::domain::AccountCache;
+use crate::accounts::core::error::AccountDataError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait AccountDataSPI: Send + Sync {
+    async fn load_account_id(&self, profile_name: &str) -> Result<String, AccountDataError>;
+
+    /// Returns `None` when the account has no alias or the profile may not list it.
+    async fn load_account_alias(
+        &self,
+        profile_name: &str,
+    ) -> Result<Option<String>, AccountDataError>;
+
+    /// Returns `None` when the account is not part of an organization or the profile may not
+    /// describe it.
+    async fn load_organization_account_name(
+        &self,
+        profile_name: &str,
+        account_id: &str,
+    ) -> Result<Option<String>, AccountDataError>;
+}
+
+#[cfg_attr(test, automock)]
+pub trait AccountCacheSPI: Send + Sync {
+    fn load_account_cache(&self) -> Result<AccountCache, AccountDataError>;
+
+    fn save_account_cache(&self, account_cache: &AccountCache) -> Result<(), AccountDataError>;
+}
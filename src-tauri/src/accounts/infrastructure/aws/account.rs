@@ -0,0 +1 @@
+pub mod account_adapter;
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use aws_sdk_iam::error::ProvideErrorMetadata;
+use error_stack::Report;
+
+use crate::accounts::core::error::AccountDataError;
+use crate::accounts::core::spi::AccountDataSPI;
+use crate::common::aws::client_factory;
+
+pub struct AccountAdapter;
+
+#[async_trait]
+impl AccountDataSPI for AccountAdapter {
+    async fn load_account_id(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<String, AccountDataError> {
+        let client = client_factory()
+            .sts_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(AccountDataError::AccountDataLoadError(err)))?;
+
+        let result = client.get_caller_identity().send().await;
+        match result {
+            Ok(output) => output
+                .account()
+                .map(|account| account.to_string())
+                .ok_or_else(|| {
+                    Report::new(AccountDataError::AccountDataLoadError(
+                        "caller identity should have an account".to_string(),
+                    ))
+                }),
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                match error_code {
+                    Some("InvalidClientTokenId") => {
+                        Err(Report::new(AccountDataError::InvalidCredentialsError))
+                    }
+                    _ => Err(Report::new(AccountDataError::AccountDataLoadError(
+                        error_code.unwrap_or("Server Error").to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+
+    async fn load_account_alias(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<Option<String>, AccountDataError> {
+        let client = client_factory()
+            .iam_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(AccountDataError::AccountDataLoadError(err)))?;
+
+        let result = client.list_account_aliases().send().await;
+        match result {
+            // An account can have at most one alias.
+            Ok(output) => Ok(output.account_aliases().first().cloned()),
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                match error_code {
+                    Some("AccessDenied") => Ok(None),
+                    _ => Err(Report::new(AccountDataError::AccountDataLoadError(
+                        error_code.unwrap_or("Server Error").to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+
+    async fn load_organization_account_name(
+        &self,
+        profile_name: &str,
+        account_id: &str,
+    ) -> error_stack::Result<Option<String>, AccountDataError> {
+        let client = client_factory()
+            .organizations_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(AccountDataError::AccountDataLoadError(err)))?;
+
+        let result = client
+            .describe_account()
+            .account_id(account_id)
+            .send()
+            .await;
+        match result {
+            Ok(output) => Ok(output
+                .account()
+                .and_then(|account| account.name())
+                .map(|name| name.to_string())),
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::warn!("Error: [{:?}] {:?}", error_code, error_message);
+
+                // Member accounts usually may not describe themselves, which is not an error.
+                match error_code {
+                    Some("AccessDeniedException")
+                    | Some("AWSOrganizationsNotInUseException")
+                    | Some("AccountNotFoundException") => Ok(None),
+                    _ => Err(Report::new(AccountDataError::AccountDataLoadError(
+                        error_code.unwrap_or("Server Error").to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+}
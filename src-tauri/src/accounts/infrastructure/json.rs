@@ -0,0 +1 @@
+pub mod json_account_cache_adapter;
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+
+use crate::accounts::core::domain::AccountCache;
+use crate::accounts::core::error::AccountDataError;
+use crate::accounts::core::spi::AccountCacheSPI;
+use crate::common::app_data::JsonFileStore;
+
+const ACCOUNT_CACHE_FILE_NAME: &str = "accounts.json";
+
+pub struct JsonAccountCacheAdapter {
+    store: JsonFileStore,
+}
+
+impl JsonAccountCacheAdapter {
+    pub fn new(cache_file_location: PathBuf) -> Self {
+        Self {
+            store: JsonFileStore::new(cache_file_location),
+        }
+    }
+}
+
+impl Default for JsonAccountCacheAdapter {
+    fn default() -> Self {
+        Self {
+            store: JsonFileStore::in_app_data_dir(ACCOUNT_CACHE_FILE_NAME),
+        }
+    }
+}
+
+impl AccountCacheSPI for JsonAccountCacheAdapter {
+    fn load_account_cache(&self) -> error_stack::Result<AccountCache, AccountDataError> {
+        self.store
+            .load()
+            .change_context(AccountDataError::AccountCacheLoadError)
+    }
+
+    fn save_account_cache(
+        &self,
+        account_cache: &AccountCache,
+    ) -> error_stack::Result<(), AccountDataError> {
+        self.store
+            .save(account_cache)
+            .change_context(AccountDataError::AccountCacheWriteError)
+    }
+}
@@ -1,2 +1,4 @@
+pub mod app_data;
 pub mod aws;
+pub mod demo;
 pub mod secure_string;
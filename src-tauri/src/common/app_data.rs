@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+use directories::BaseDirs;
+use error_stack::{Context, ResultExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Must match the bundle identifier in `tauri.conf.json`, so that files end up in the same
+/// directory Tauri resolves as the app data directory.
+pub const APP_IDENTIFIER: &str = "com.maikbasel.aws-custodian";
+
+pub fn app_data_dir() -> PathBuf {
+    let base_dirs = BaseDirs::new().expect("base dirs should exist");
+
+    base_dirs.data_dir().join(APP_IDENTIFIER)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum JsonFileError {
+    LoadError,
+    WriteError,
+}
+
+impl Display for JsonFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonFileError::LoadError => write!(f, "failed to load json file"),
+            JsonFileError::WriteError => write!(f, "failed to write json file"),
+        }
+    }
+}
+
+impl Context for JsonFileError {}
+
+/// A single JSON document in the app data directory. A missing file reads as the default value,
+/// missing parent directories are created on the first save.
+pub struct JsonFileStore {
+    file_location: PathBuf,
+}
+
+impl JsonFileStore {
+    pub fn new(file_location: PathBuf) -> Self {
+        Self { file_location }
+    }
+
+    pub fn in_app_data_dir(file_name: &str) -> Self {
+        Self::new(app_data_dir().join(file_name))
+    }
+
+    pub fn load<T: DeserializeOwned + Default>(&self) -> error_stack::Result<T, JsonFileError> {
+        if !self.file_location.exists() {
+            return Ok(T::default());
+        }
+
+        let content =
+            fs::read_to_string(&self.file_location).change_context(JsonFileError::LoadError)?;
+
+        serde_json::from_str(&content).change_context(JsonFileError::LoadError)
+    }
+
+    pub fn save<T: Serialize>(&self, value: &T) -> error_stack::Result<(), JsonFileError> {
+        if let Some(parent) = self.file_location.parent() {
+            fs::create_dir_all(parent).change_context(JsonFileError::WriteError)?;
+        }
+
+        let content =
+            serde_json::to_string_pretty(value).change_context(JsonFileError::WriteError)?;
+
+        fs::write(&self.file_location, content).change_context(JsonFileError::WriteError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use spectral::prelude::*;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn should_load_default_when_file_does_not_exist() {
+        let test_dir = tempdir().unwrap();
+        let cut = JsonFileStore::new(test_dir.path().join("missing.json"));
+
+        let actual = cut.load::<HashMap<String, u32>>();
+
+        assert_that!(actual).is_ok().is_equal_to(HashMap::new());
+    }
+
+    #[test]
+    fn should_create_parent_directories_and_load_saved_value() {
+        let test_dir = tempdir().unwrap();
+        let cut = JsonFileStore::new(test_dir.path().join("nested").join("store.json"));
+        let value = HashMap::from([("prod".to_string(), 2u32)]);
+
+        let result = cut.save(&value);
+
+        assert_that!(result).is_ok();
+        assert_that!(cut.load::<HashMap<String, u32>>())
+            .is_ok()
+            .is_equal_to(value);
+    }
+
+    #[test]
+    fn should_return_load_error_when_file_is_corrupt() {
+        let test_dir = tempdir().unwrap();
+        let file_location = test_dir.path().join("store.json");
+        fs::write(&file_location, "{ not json").unwrap();
+        let cut = JsonFileStore::new(file_location);
+
+        let actual = cut.load::<HashMap<String, u32>>();
+
+        assert_that!(actual).is_err();
+        assert_that!(actual.unwrap_err().current_context()).is_equal_to(&JsonFileError::LoadError);
+    }
+
+    #[test]
+    fn should_return_write_error_when_parent_is_a_file() {
+        let test_dir = tempdir().unwrap();
+        let blocking_file = test_dir.path().join("blocking");
+        fs::write(&blocking_file, "").unwrap();
+        let cut = JsonFileStore::new(blocking_file.join("store.json"));
+
+        let actual = cut.save(&HashMap::<String, u32>::new());
+
+        assert_that!(actual).is_err();
+        assert_that!(actual.unwrap_err().current_context()).is_equal_to(&JsonFileError::WriteError);
+    }
+}
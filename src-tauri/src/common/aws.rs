@@ -1,19 +1,42 @@
+pub mod api_call_interceptor;
+pub mod api_call_log;
+pub mod client_settings;
+pub mod http_client;
+
 use aws_config::default_provider::region::DefaultRegionChain;
-use aws_config::{BehaviorVersion, ConfigLoader};
-use aws_sdk_ssm::config::retry::RetryConfig;
+use aws_config::{BehaviorVersion, ConfigLoader, Region, SdkConfig};
+use aws_sdk_iam::config::Builder as IamBuilder;
+use aws_sdk_iam::Client as IamClient;
+use aws_sdk_organizations::config::Builder as OrganizationsBuilder;
+use aws_sdk_organizations::Client as OrganizationsClient;
 use aws_sdk_ssm::config::Builder as SsmBuilder;
+use aws_sdk_ssm::config::SharedHttpClient;
 use aws_sdk_ssm::Client as SsmClient;
 use aws_sdk_sts::config::Builder as StsBuilder;
 use aws_sdk_sts::Client as StsClient;
-use std::time::Duration;
+use directories::UserDirs;
+use lazy_static::lazy_static;
+use std::any::Any;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, RwLock};
+use std::time::SystemTime;
+use std::{env, fs};
 
-pub fn localstack_endpoint() -> Option<String> {
-    match std::env::var("LOCALSTACK_ENDPOINT") {
-        Ok(value) => Some(value),
-        Err(_) => None,
-    }
-}
+use crate::common::aws::api_call_interceptor::ApiCallInterceptor;
+use crate::common::aws::client_settings::{
+    ClientSettings, EffectiveClientSettings, ENVIRONMENT_KEYS, PROFILE_KEYS,
+};
+use crate::common::aws::http_client::{build_http_client, HttpClientKey};
+
+const ENCODED_AUTHORIZATION_MESSAGE_PREFIX: &str = "Encoded authorization failure message:";
 
+/// Region assumed for profiles that target a custom endpoint, e.g. LocalStack, without
+/// configuring a region of their own.
+const CUSTOM_ENDPOINT_FALLBACK_REGION: &str = "us-east-1";
+
+/// Endpoints are resolved per profile and per service by the SDK from the standard settings:
+/// `AWS_ENDPOINT_URL[_<SERVICE>]`, the profile's `endpoint_url` and its `[services]` section.
 pub async fn shared_config_loader(profile_name: &str) -> ConfigLoader {
     let credentials_provider = aws_config::profile::ProfileFileCredentialsProvider::builder()
         .profile_name(profile_name)
@@ -23,13 +46,45 @@ pub async fn shared_config_loader(profile_name: &str) -> ConfigLoader {
         .profile_name(profile_name)
         .build();
 
-    let region = region_chain.region().await;
+    let mut region = region_chain.region().await;
+    if region.is_none() && has_custom_endpoint(profile_name).await {
+        region = Some(Region::from_static(CUSTOM_ENDPOINT_FALLBACK_REGION));
+    }
 
     aws_config::defaults(BehaviorVersion::latest())
+        .profile_name(profile_name)
         .credentials_provider(credentials_provider)
         .region(region)
 }
 
+async fn has_custom_endpoint(profile_name: &str) -> bool {
+    if env::vars().any(|(key, _)| key.starts_with("AWS_ENDPOINT_URL")) {
+        return true;
+    }
+
+    match load_profile_set().await {
+        Ok(profile_set) => profile_set
+            .get_profile(profile_name)
+            .map(|profile| {
+                profile.get("ignore_configured_endpoint_urls") != Some("true")
+                    && (profile.get("endpoint_url").is_some() || profile.get("services").is_some())
+            })
+            .unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+async fn load_profile_set(
+) -> Result<aws_config::profile::ProfileSet, aws_config::profile::ProfileFileLoadError> {
+    aws_config::profile::load(
+        &Default::default(),
+        &Default::default(),
+        &Default::default(),
+        None,
+    )
+    .await
+}
+
 pub fn sts_client(config: &aws_config::SdkConfig) -> StsClient {
     // Copy config from aws_config::SdkConfig to aws_sdk_sts::Config
     let sts_config_builder = StsBuilder::from(config);
@@ -38,11 +93,433 @@ pub fn sts_client(config: &aws_config::SdkConfig) -> StsClient {
 }
 
 pub fn ssm_client(config: &aws_config::SdkConfig) -> SsmClient {
-    let retry_config = RetryConfig::adaptive()
-        .with_max_attempts(8)
-        .with_initial_backoff(Duration::from_millis(200))
-        .with_max_backoff(Duration::from_secs(5));
-    let sts_config_builder = SsmBuilder::from(config).retry_config(retry_config);
+    let ssm_config_builder = SsmBuilder::from(config);
+
+    SsmClient::from_conf(ssm_config_builder.build())
+}
+
+pub fn iam_client(config: &aws_config::SdkConfig) -> IamClient {
+    let iam_config_builder = IamBuilder::from(config);
+
+    IamClient::from_conf(iam_config_builder.build())
+}
+
+pub fn organizations_client(config: &aws_config::SdkConfig) -> OrganizationsClient {
+    let organizations_config_builder = OrganizationsBuilder::from(config);
+
+    OrganizationsClient::from_conf(organizations_config_builder.build())
+}
+
+lazy_static! {
+    static ref CLIENT_FACTORY: AwsClientFactory = AwsClientFactory::new();
+}
+
+/// The process-wide client factory shared by all AWS adapters.
+pub fn client_factory() -> &'static AwsClientFactory {
+    &CLIENT_FACTORY
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ConfigKey {
+    profile_name: String,
+    region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClientKey {
+    config_key: ConfigKey,
+    service: &'static str,
+}
+
+/// Identifies the state of the profile files a cached config was loaded from.
+type ProfileFilesFingerprint = Vec<(PathBuf, Option<SystemTime>, Option<u64>)>;
+
+#[derive(Default)]
+struct ClientCache {
+    profile_files_fingerprint: ProfileFilesFingerprint,
+    configs: HashMap<ConfigKey, SdkConfig>,
+    clients: HashMap<ClientKey, Box<dyn Any + Send + Sync>>,
+}
+
+/// Caches `SdkConfig`s and service clients by profile, region and service, so repeated calls
+/// neither re-read the profile files nor rebuild the HTTP stack. The cache is dropped as soon as
+/// the profile files change.
+pub struct AwsClientFactory {
+    cache: Mutex<ClientCache>,
+    client_defaults: RwLock<ClientSettings>,
+    http_clients: Mutex<HashMap<HttpClientKey, SharedHttpClient>>,
+}
+
+impl AwsClientFactory {
+    pub fn new() -> Self {
+        Self {
+            cache: Mutex::new(ClientCache::default()),
+            client_defaults: RwLock::new(ClientSettings::app_defaults()),
+            http_clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn client_defaults(&self) -> ClientSettings {
+        self.client_defaults
+            .read()
+            .expect("client defaults lock should not be poisoned")
+            .clone()
+    }
+
+    /// Replaces the app-level client defaults. Cached configs were built with the previous
+    /// defaults and are therefore dropped.
+    pub fn set_client_defaults(&self, client_defaults: ClientSettings) {
+        *self
+            .client_defaults
+            .write()
+            .expect("client defaults lock should not be poisoned") = client_defaults;
+        self.invalidate_all();
+    }
+
+    /// Resolves the retry, timeout, proxy and CA bundle settings the clients of the profile use.
+    pub async fn effective_client_settings(&self, profile_name: &str) -> EffectiveClientSettings {
+        let environment = ENVIRONMENT_KEYS
+            .iter()
+            .filter_map(|key| env::var(key).ok().map(|value| (key.to_string(), value)))
+            .collect();
+
+        let profile = match load_profile_set().await {
+            Ok(profile_set) => profile_set
+                .get_profile(profile_name)
+                .map(|profile| {
+                    PROFILE_KEYS
+                        .iter()
+                        .filter_map(|key| {
+                            profile
+                                .get(key)
+                                .map(|value| (key.to_string(), value.to_string()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            Err(err) => {
+                tracing::error!("Error: failed to load profile files: {}", err);
+
+                HashMap::new()
+            }
+        };
+
+        EffectiveClientSettings::resolve(
+            profile_name,
+            &environment,
+            &profile,
+            &self.client_defaults(),
+        )
+    }
+
+    /// Loads the shared config of the profile. Without an explicit region the profile's own
+    /// region is used. Fails if the proxy or CA bundle settings do not allow to build an HTTP
+    /// client, rather than silently bypassing them.
+    pub async fn sdk_config(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> Result<SdkConfig, String> {
+        let config_key = ConfigKey {
+            profile_name: profile_name.to_string(),
+            region: region.map(|region| region.to_string()),
+        };
+
+        if let Some(config) = self.lock_cache().configs.get(&config_key) {
+            return Ok(config.clone());
+        }
+
+        let client_settings = self.effective_client_settings(profile_name).await;
+        let mut shared_config_loader = shared_config_loader(profile_name)
+            .await
+            .retry_config(client_settings.retry_config())
+            .timeout_config(client_settings.timeout_config());
+        if let Some(region) = region {
+            shared_config_loader = shared_config_loader.region(Region::new(region.to_string()));
+        }
+        if let Some(http_client) = self.http_client(&client_settings)? {
+            shared_config_loader = shared_config_loader.http_client(http_client);
+        }
+        let config = shared_config_loader.load().await;
+
+        self.lock_cache().configs.insert(config_key, config.clone());
+
+        Ok(config)
+    }
+
+    pub async fn sts_client(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> Result<StsClient, String> {
+        self.client(profile_name, region, "sts", |config, interceptor| {
+            StsClient::from_conf(StsBuilder::from(config).interceptor(interceptor).build())
+        })
+        .await
+    }
+
+    pub async fn ssm_client(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> Result<SsmClient, String> {
+        self.client(profile_name, region, "ssm", |config, interceptor| {
+            SsmClient::from_conf(SsmBuilder::from(config).interceptor(interceptor).build())
+        })
+        .await
+    }
+
+    pub async fn iam_client(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> Result<IamClient, String> {
+        self.client(profile_name, region, "iam", |config, interceptor| {
+            IamClient::from_conf(IamBuilder::from(config).interceptor(interceptor).build())
+        })
+        .await
+    }
+
+    pub async fn organizations_client(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> Result<OrganizationsClient, String> {
+        self.client(
+            profile_name,
+            region,
+            "organizations",
+            |config, interceptor| {
+                OrganizationsClient::from_conf(
+                    OrganizationsBuilder::from(config)
+                        .interceptor(interceptor)
+                        .build(),
+                )
+            },
+        )
+        .await
+    }
+
+    /// Drops the cached configs and clients of the profile, e.g. after its credentials were
+    /// refreshed.
+    pub fn invalidate_profile(&self, profile_name: &str) {
+        let mut cache = self.lock_cache();
+        cache
+            .configs
+            .retain(|key, _| key.profile_name != profile_name);
+        cache
+            .clients
+            .retain(|key, _| key.config_key.profile_name != profile_name);
+    }
+
+    pub fn invalidate_all(&self) {
+        let mut cache = self.lock_cache();
+        cache.configs.clear();
+        cache.clients.clear();
+    }
+
+    async fn client<C>(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+        service: &'static str,
+        build_client: fn(&SdkConfig, ApiCallInterceptor) -> C,
+    ) -> Result<C, String>
+    where
+        C: Clone + Send + Sync + 'static,
+    {
+        let client_key = ClientKey {
+            config_key: ConfigKey {
+                profile_name: profile_name.to_string(),
+                region: region.map(|region| region.to_string()),
+            },
+            service,
+        };
+
+        let cached_client = self
+            .lock_cache()
+            .clients
+            .get(&client_key)
+            .and_then(|client| client.downcast_ref::<C>())
+            .cloned();
+        if let Some(client) = cached_client {
+            return Ok(client);
+        }
+
+        let config = self.sdk_config(profile_name, region).await?;
+        let interceptor = ApiCallInterceptor::new(
+            profile_name,
+            config.region().map(|region| region.to_string()),
+        );
+        let client = build_client(&config, interceptor);
 
-    SsmClient::from_conf(sts_config_builder.build())
+        self.lock_cache()
+            .clients
+            .insert(client_key, Box::new(client.clone()));
+
+        Ok(client)
+    }
+
+    /// Returns the HTTP client honoring the proxy and CA bundle settings, if any are set.
+    fn http_client(
+        &self,
+        client_settings: &EffectiveClientSettings,
+    ) -> Result<Option<SharedHttpClient>, String> {
+        let Some(key) = HttpClientKey::from_settings(client_settings) else {
+            return Ok(None);
+        };
+
+        let mut http_clients = self
+            .http_clients
+            .lock()
+            .expect("http client cache lock should not be poisoned");
+        if let Some(http_client) = http_clients.get(&key) {
+            return Ok(Some(http_client.clone()));
+        }
+
+        let http_client = build_http_client(&key).map_err(|err| {
+            tracing::error!(
+                "Error: failed to build HTTP client for profile {}: {}",
+                client_settings.profile_name,
+                err
+            );
+
+            format!("invalid proxy or CA bundle settings: {}", err)
+        })?;
+        http_clients.insert(key, http_client.clone());
+
+        Ok(Some(http_client))
+    }
+
+    /// Locks the cache after dropping it if the profile files changed since it was filled.
+    fn lock_cache(&self) -> MutexGuard<'_, ClientCache> {
+        let mut cache = self
+            .cache
+            .lock()
+            .expect("client cache lock should not be poisoned");
+
+        let fingerprint = profile_files_fingerprint();
+        if cache.profile_files_fingerprint != fingerprint {
+            cache.configs.clear();
+            cache.clients.clear();
+            cache.profile_files_fingerprint = fingerprint;
+        }
+
+        cache
+    }
+}
+
+impl Default for AwsClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn profile_files_fingerprint() -> ProfileFilesFingerprint {
+    let home_dir = UserDirs::new().map(|user_dirs| user_dirs.home_dir().join(".aws"));
+    let profile_file = |env_var: &str, default_file_name: &str| {
+        env::var(env_var).ok().map(PathBuf::from).or_else(|| {
+            home_dir
+                .as_ref()
+                .map(|aws_dir| aws_dir.join(default_file_name))
+        })
+    };
+
+    [
+        profile_file("AWS_CONFIG_FILE", "config"),
+        profile_file("AWS_SHARED_CREDENTIALS_FILE", "credentials"),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|path| {
+        let metadata = fs::metadata(&path).ok();
+        let modified = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.modified().ok());
+        let length = metadata.map(|metadata| metadata.len());
+        (path, modified, length)
+    })
+    .collect()
+}
+
+/// Extracts the encoded authorization failure message AWS appends to some access denied errors,
+/// which can be decoded via `sts:DecodeAuthorizationMessage`.
+pub fn encoded_authorization_message(error_message: Option<&str>) -> Option<String> {
+    let error_message = error_message?;
+    let start = error_message.find(ENCODED_AUTHORIZATION_MESSAGE_PREFIX)?
+        + ENCODED_AUTHORIZATION_MESSAGE_PREFIX.len();
+
+    error_message[start..]
+        .split_whitespace()
+        .next()
+        .map(|encoded_message| encoded_message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_extract_encoded_authorization_message() {
+        let error_message = "You are not authorized to perform this operation. Encoded authorization failure message: fK1yQ-9x_Zb3 ";
+
+        let actual = encoded_authorization_message(Some(error_message));
+
+        assert_that!(actual).is_equal_to(Some("fK1yQ-9x_Zb3".to_string()));
+    }
+
+    #[test]
+    fn should_not_extract_encoded_authorization_message_from_plain_error() {
+        let actual = encoded_authorization_message(Some("User is not authorized"));
+
+        assert_that!(actual).is_none();
+    }
+
+    #[test]
+    fn should_drop_cached_configs_of_invalidated_profile_only() {
+        let cut = AwsClientFactory::new();
+        {
+            let mut cache = cut.lock_cache();
+            for profile_name in ["dev", "prod"] {
+                cache.configs.insert(
+                    ConfigKey {
+                        profile_name: profile_name.to_string(),
+                        region: None,
+                    },
+                    SdkConfig::builder().build(),
+                );
+            }
+        }
+
+        cut.invalidate_profile("dev");
+
+        let cache = cut.lock_cache();
+        assert_that!(cache.configs).has_length(1);
+        assert_that!(cache
+            .configs
+            .keys()
+            .next()
+            .map(|key| key.profile_name.as_str()))
+        .is_equal_to(Some("prod"));
+    }
+
+    #[test]
+    fn should_return_error_instead_of_default_client_when_http_client_cannot_be_built() {
+        let cut = AwsClientFactory::new();
+        let client_settings = EffectiveClientSettings::resolve(
+            "dev",
+            &HashMap::new(),
+            &HashMap::new(),
+            &ClientSettings {
+                ca_bundle: Some("/does/not/exist.pem".to_string()),
+                ..ClientSettings::default()
+            },
+        );
+
+        let actual = cut.http_client(&client_settings);
+
+        assert_that!(actual).is_err();
+    }
 }
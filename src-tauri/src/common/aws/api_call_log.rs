@@ -0,0 +1,175 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_CAPACITY: usize = 500;
+
+lazy_static! {
+    static ref API_CALL_LOG: ApiCallLog = ApiCallLog::new(DEFAULT_CAPACITY);
+}
+
+/// The process-wide log the AWS clients record their calls to.
+pub fn api_call_log() -> &'static ApiCallLog {
+    &API_CALL_LOG
+}
+
+/// A single AWS API call as seen by the client. It deliberately carries no request headers or
+/// bodies, so neither signatures nor parameter values can end up in it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiCallRecord {
+    pub id: u64,
+    pub started_at: DateTime<Utc>,
+    pub service: String,
+    pub operation: String,
+    pub profile_name: String,
+    pub region: Option<String>,
+    pub endpoint: Option<String>,
+    pub http_status: Option<u16>,
+    pub request_id: Option<String>,
+    pub retry_count: u32,
+    pub latency_ms: u64,
+    pub success: bool,
+}
+
+type ApiCallListener = Arc<dyn Fn(&ApiCallRecord) + Send + Sync>;
+
+struct ApiCallLogState {
+    next_id: u64,
+    records: VecDeque<ApiCallRecord>,
+    listeners: Vec<ApiCallListener>,
+}
+
+/// Keeps the most recent API calls in a ring buffer and notifies listeners of every new one.
+pub struct ApiCallLog {
+    capacity: usize,
+    state: Mutex<ApiCallLogState>,
+}
+
+impl ApiCallLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(ApiCallLogState {
+                next_id: 1,
+                records: VecDeque::with_capacity(capacity),
+                listeners: Vec::new(),
+            }),
+        }
+    }
+
+    /// Assigns the record its id, stores it and hands it to the listeners.
+    pub fn record(&self, mut record: ApiCallRecord) {
+        let listeners = {
+            let mut state = self.lock_state();
+            record.id = state.next_id;
+            state.next_id += 1;
+
+            // Without capacity nothing is kept, but listeners are still notified.
+            if self.capacity > 0 {
+                if state.records.len() >= self.capacity {
+                    state.records.pop_front();
+                }
+                state.records.push_back(record.clone());
+            }
+
+            state.listeners.clone()
+        };
+
+        // Listeners are called outside of the lock, so they may read the log themselves.
+        for listener in listeners {
+            listener(&record);
+        }
+    }
+
+    /// Returns the recorded calls, oldest first.
+    pub fn records(&self) -> Vec<ApiCallRecord> {
+        self.lock_state().records.iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.lock_state().records.clear();
+    }
+
+    pub fn subscribe(&self, listener: impl Fn(&ApiCallRecord) + Send + Sync + 'static) {
+        self.lock_state().listeners.push(Arc::new(listener));
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, ApiCallLogState> {
+        self.state
+            .lock()
+            .expect("api call log lock should not be poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn record(operation: &str) -> ApiCallRecord {
+        ApiCallRecord {
+            id: 0,
+            started_at: Utc::now(),
+            service: "ssm".to_string(),
+            operation: operation.to_string(),
+            profile_name: "dev".to_string(),
+            region: Some("eu-west-1".to_string()),
+            endpoint: Some("https://ssm.eu-west-1.amazonaws.com/".to_string()),
+            http_status: Some(200),
+            request_id: Some("c0ffee".to_string()),
+            retry_count: 0,
+            latency_ms: 42,
+            success: true,
+        }
+    }
+
+    #[test]
+    fn should_evict_oldest_record_when_capacity_is_reached() {
+        let cut = ApiCallLog::new(2);
+
+        cut.record(record("DescribeParameters"));
+        cut.record(record("GetParameters"));
+        cut.record(record("PutParameter"));
+
+        let actual = cut.records();
+        assert_that!(actual).has_length(2);
+        assert_that!(actual[0].operation.as_str()).is_equal_to("GetParameters");
+        assert_that!(actual[1].id).is_equal_to(3);
+    }
+
+    #[test]
+    fn should_notify_listeners_of_new_records() {
+        let cut = ApiCallLog::new(2);
+        let notified = Arc::new(AtomicUsize::new(0));
+        let listener_notified = notified.clone();
+        cut.subscribe(move |_| {
+            listener_notified.fetch_add(1, Ordering::SeqCst);
+        });
+
+        cut.record(record("GetCallerIdentity"));
+
+        assert_that!(notified.load(Ordering::SeqCst)).is_equal_to(1);
+    }
+
+    #[test]
+    fn should_keep_no_records_without_capacity() {
+        let cut = ApiCallLog::new(0);
+        let notified = Arc::new(AtomicUsize::new(0));
+        let listener_notified = notified.clone();
+        cut.subscribe(move |_| {
+            listener_notified.fetch_add(1, Ordering::SeqCst);
+        });
+
+        cut.record(record("DescribeParameters"));
+        cut.record(record("GetParameters"));
+
+        assert_that!(cut.records()).is_empty();
+        assert_that!(notified.load(Ordering::SeqCst)).is_equal_to(2);
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use aws_sdk_ssm::config::retry::RetryConfig;
+use aws_sdk_ssm::config::timeout::TimeoutConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::common::aws::http_client::validate_http_settings;
+
+/// Environment variables taken into account when resolving the client settings.
+pub const ENVIRONMENT_KEYS: [&str; 7] = [
+    "AWS_RETRY_MODE",
+    "AWS_MAX_ATTEMPTS",
+    "AWS_CA_BUNDLE",
+    "HTTPS_PROXY",
+    "https_proxy",
+    "NO_PROXY",
+    "no_proxy",
+];
+
+/// Profile keys taken into account when resolving the client settings. The timeouts are not part
+/// of the standard profile settings and are given in seconds.
+pub const PROFILE_KEYS: [&str; 5] = [
+    "retry_mode",
+    "max_attempts",
+    "ca_bundle",
+    "connect_timeout",
+    "read_timeout",
+];
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RetryMode {
+    Standard,
+    Adaptive,
+}
+
+impl RetryMode {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            // The SDKs treat the deprecated legacy mode like standard.
+            "standard" | "legacy" => Some(RetryMode::Standard),
+            "adaptive" => Some(RetryMode::Adaptive),
+            _ => None,
+        }
+    }
+}
+
+/// Client settings that apply to every profile unless the profile or the environment says
+/// otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClientSettings {
+    pub retry_mode: Option<RetryMode>,
+    pub max_attempts: Option<u32>,
+    pub connect_timeout_ms: Option<u64>,
+    pub read_timeout_ms: Option<u64>,
+    pub https_proxy: Option<String>,
+    pub no_proxy: Option<String>,
+    pub ca_bundle: Option<String>,
+}
+
+impl ClientSettings {
+    /// The defaults the app ships with. Parameter Store throttles aggressively, hence the
+    /// generous adaptive retry policy.
+    pub fn app_defaults() -> Self {
+        Self {
+            retry_mode: Some(RetryMode::Adaptive),
+            max_attempts: Some(8),
+            ..Self::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_attempts == Some(0) {
+            return Err("max attempts must be at least 1".to_string());
+        }
+        if self.connect_timeout_ms == Some(0) || self.read_timeout_ms == Some(0) {
+            return Err("timeouts must be greater than 0".to_string());
+        }
+
+        validate_http_settings(self.https_proxy.as_deref(), self.ca_bundle.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SettingSource {
+    Environment,
+    Profile,
+    AppDefault,
+    SdkDefault,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveSetting<T> {
+    pub value: Option<T>,
+    pub source: SettingSource,
+}
+
+impl<T> EffectiveSetting<T> {
+    fn first_of<const N: usize>(candidates: [(SettingSource, Option<T>); N]) -> Self {
+        candidates
+            .into_iter()
+            .find_map(|(source, value)| value.map(|value| (source, value)))
+            .map(|(source, value)| Self {
+                value: Some(value),
+                source,
+            })
+            .unwrap_or(Self {
+                value: None,
+                source: SettingSource::SdkDefault,
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveClientSettings {
+    pub profile_name: String,
+    pub retry_mode: EffectiveSetting<RetryMode>,
+    pub max_attempts: EffectiveSetting<u32>,
+    pub connect_timeout_ms: EffectiveSetting<u64>,
+    pub read_timeout_ms: EffectiveSetting<u64>,
+    pub https_proxy: EffectiveSetting<String>,
+    pub no_proxy: EffectiveSetting<String>,
+    pub ca_bundle: EffectiveSetting<String>,
+}
+
+impl EffectiveClientSettings {
+    /// Resolves every setting with the precedence of the SDKs, environment before profile, and
+    /// consults the app defaults before leaving it to the SDK defaults.
+    pub fn resolve(
+        profile_name: &str,
+        environment: &HashMap<String, String>,
+        profile: &HashMap<String, String>,
+        app_defaults: &ClientSettings,
+    ) -> Self {
+        let from_environment = |key: &str| environment.get(key).filter(|value| !value.is_empty());
+        let from_profile = |key: &str| profile.get(key).filter(|value| !value.is_empty());
+
+        Self {
+            profile_name: profile_name.to_string(),
+            retry_mode: EffectiveSetting::first_of([
+                (
+                    SettingSource::Environment,
+                    from_environment("AWS_RETRY_MODE").and_then(|value| RetryMode::parse(value)),
+                ),
+                (
+                    SettingSource::Profile,
+                    from_profile("retry_mode").and_then(|value| RetryMode::parse(value)),
+                ),
+                (SettingSource::AppDefault, app_defaults.retry_mode),
+            ]),
+            max_attempts: EffectiveSetting::first_of([
+                (
+                    SettingSource::Environment,
+                    from_environment("AWS_MAX_ATTEMPTS")
+                        .and_then(|value| parse_max_attempts(value)),
+                ),
+                (
+                    SettingSource::Profile,
+                    from_profile("max_attempts").and_then(|value| parse_max_attempts(value)),
+                ),
+                (SettingSource::AppDefault, app_defaults.max_attempts),
+            ]),
+            connect_timeout_ms: EffectiveSetting::first_of([
+                (
+                    SettingSource::Profile,
+                    from_profile("connect_timeout").and_then(|value| parse_timeout_ms(value)),
+                ),
+                (SettingSource::AppDefault, app_defaults.connect_timeout_ms),
+            ]),
+            read_timeout_ms: EffectiveSetting::first_of([
+                (
+                    SettingSource::Profile,
+                    from_profile("read_timeout").and_then(|value| parse_timeout_ms(value)),
+                ),
+                (SettingSource::AppDefault, app_defaults.read_timeout_ms),
+            ]),
+            https_proxy: EffectiveSetting::first_of([
+                (
+                    SettingSource::Environment,
+                    from_environment("HTTPS_PROXY")
+                        .or_else(|| from_environment("https_proxy"))
+                        .cloned(),
+                ),
+                (SettingSource::AppDefault, app_defaults.https_proxy.clone()),
+            ]),
+            no_proxy: EffectiveSetting::first_of([
+                (
+                    SettingSource::Environment,
+                    from_environment("NO_PROXY")
+                        .or_else(|| from_environment("no_proxy"))
+                        .cloned(),
+                ),
+                (SettingSource::AppDefault, app_defaults.no_proxy.clone()),
+            ]),
+            ca_bundle: EffectiveSetting::first_of([
+                (
+                    SettingSource::Environment,
+                    from_environment("AWS_CA_BUNDLE").cloned(),
+                ),
+                (SettingSource::Profile, from_profile("ca_bundle").cloned()),
+                (SettingSource::AppDefault, app_defaults.ca_bundle.clone()),
+            ]),
+        }
+    }
+
+    pub fn retry_config(&self) -> RetryConfig {
+        let retry_config = match self.retry_mode.value {
+            Some(RetryMode::Adaptive) => RetryConfig::adaptive(),
+            _ => RetryConfig::standard(),
+        }
+        .with_initial_backoff(INITIAL_BACKOFF)
+        .with_max_backoff(MAX_BACKOFF);
+
+        match self.max_attempts.value {
+            Some(max_attempts) => retry_config.with_max_attempts(max_attempts),
+            None => retry_config,
+        }
+    }
+
+    pub fn timeout_config(&self) -> TimeoutConfig {
+        let mut timeout_config = TimeoutConfig::builder();
+        if let Some(connect_timeout_ms) = self.connect_timeout_ms.value {
+            timeout_config =
+                timeout_config.connect_timeout(Duration::from_millis(connect_timeout_ms));
+        }
+        if let Some(read_timeout_ms) = self.read_timeout_ms.value {
+            timeout_config = timeout_config.read_timeout(Duration::from_millis(read_timeout_ms));
+        }
+
+        timeout_config.build()
+    }
+}
+
+fn parse_max_attempts(value: &str) -> Option<u32> {
+    value
+        .trim()
+        .parse()
+        .ok()
+        .filter(|max_attempts| *max_attempts > 0)
+}
+
+fn parse_timeout_ms(value: &str) -> Option<u64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| seconds.is_finite() && *seconds > 0.0)
+        .map(|seconds| (seconds * 1000.0).round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_prefer_environment_over_profile_over_app_defaults() {
+        let environment = values(&[("AWS_MAX_ATTEMPTS", "4")]);
+        let profile = values(&[("max_attempts", "6"), ("retry_mode", "standard")]);
+
+        let actual = EffectiveClientSettings::resolve(
+            "dev",
+            &environment,
+            &profile,
+            &ClientSettings::app_defaults(),
+        );
+
+        assert_that!(actual.max_attempts).is_equal_to(EffectiveSetting {
+            value: Some(4),
+            source: SettingSource::Environment,
+        });
+        assert_that!(actual.retry_mode).is_equal_to(EffectiveSetting {
+            value: Some(RetryMode::Standard),
+            source: SettingSource::Profile,
+        });
+    }
+
+    #[test]
+    fn should_fall_back_to_app_defaults_and_then_sdk_defaults() {
+        let actual = EffectiveClientSettings::resolve(
+            "dev",
+            &HashMap::new(),
+            &HashMap::new(),
+            &ClientSettings::app_defaults(),
+        );
+
+        assert_that!(actual.retry_mode.source).is_equal_to(SettingSource::AppDefault);
+        assert_that!(actual.ca_bundle).is_equal_to(EffectiveSetting {
+            value: None,
+            source: SettingSource::SdkDefault,
+        });
+    }
+
+    #[test]
+    fn should_ignore_invalid_values() {
+        let profile = values(&[
+            ("retry_mode", "aggressive"),
+            ("max_attempts", "0"),
+            ("connect_timeout", "soon"),
+        ]);
+
+        let actual = EffectiveClientSettings::resolve(
+            "dev",
+            &HashMap::new(),
+            &profile,
+            &ClientSettings::default(),
+        );
+
+        assert_that!(actual.retry_mode.source).is_equal_to(SettingSource::SdkDefault);
+        assert_that!(actual.max_attempts.source).is_equal_to(SettingSource::SdkDefault);
+        assert_that!(actual.connect_timeout_ms.source).is_equal_to(SettingSource::SdkDefault);
+    }
+
+    #[test]
+    fn should_convert_profile_timeouts_from_seconds() {
+        let profile = values(&[("connect_timeout", "2.5"), ("read_timeout", "30")]);
+
+        let actual = EffectiveClientSettings::resolve(
+            "dev",
+            &HashMap::new(),
+            &profile,
+            &ClientSettings::default(),
+        );
+
+        assert_that!(actual.connect_timeout_ms.value).is_equal_to(Some(2500));
+        assert_that!(actual.timeout_config().read_timeout())
+            .is_equal_to(Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn should_reject_zero_max_attempts() {
+        let cut = ClientSettings {
+            max_attempts: Some(0),
+            ..ClientSettings::app_defaults()
+        };
+
+        assert_that!(cut.validate()).is_err();
+    }
+
+    #[test]
+    fn should_build_retry_config_from_effective_settings() {
+        let environment = values(&[("AWS_RETRY_MODE", "adaptive"), ("AWS_MAX_ATTEMPTS", "5")]);
+
+        let actual = EffectiveClientSettings::resolve(
+            "dev",
+            &environment,
+            &HashMap::new(),
+            &ClientSettings::default(),
+        )
+        .retry_config();
+
+        assert_that!(actual.max_attempts()).is_equal_to(5);
+        assert_that!(actual.mode()).is_equal_to(aws_sdk_ssm::config::retry::RetryMode::Adaptive);
+    }
+}
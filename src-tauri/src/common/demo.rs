@@ -0,0 +1,190 @@
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::common::secure_string::SecureString;
+use crate::profiles::core::domain::ProtectionLevel;
+
+/// Command line flag that starts the app against the in-memory adapters instead of AWS.
+pub const DEMO_FLAG: &str = "--demo";
+/// Environment variable that enables the demo mode, e.g. for `tauri dev`.
+pub const DEMO_ENV_VAR: &str = "CUSTODIAN_DEMO";
+/// Environment variable pointing to a fixture file that replaces the bundled one.
+pub const DEMO_FIXTURE_ENV_VAR: &str = "CUSTODIAN_DEMO_FIXTURE";
+
+const BUNDLED_FIXTURE: &str = include_str!("../../fixtures/demo.json");
+const FALLBACK_REGION: &str = "us-east-1";
+
+pub fn demo_mode_requested(
+    mut args: impl Iterator<Item = String>,
+    env_value: Option<String>,
+) -> bool {
+    let enabled_by_env = env_value
+        .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false);
+
+    enabled_by_env || args.any(|arg| arg == DEMO_FLAG)
+}
+
+/// Loads the fixture named by `CUSTODIAN_DEMO_FIXTURE`, or the bundled one if it is not set.
+pub fn load_demo_fixture() -> Result<DemoFixture, String> {
+    let content = match std::env::var(DEMO_FIXTURE_ENV_VAR) {
+        Ok(path) => fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read demo fixture '{}': {}", path, err))?,
+        Err(_) => BUNDLED_FIXTURE.to_string(),
+    };
+
+    DemoFixture::from_json(&content).map_err(|err| format!("invalid demo fixture: {}", err))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct DemoFixture {
+    #[serde(default)]
+    pub profiles: Vec<DemoProfile>,
+    #[serde(default)]
+    pub parameters: Vec<DemoParameter>,
+    #[serde(default)]
+    pub errors: Vec<InjectedError>,
+}
+
+impl DemoFixture {
+    pub fn from_json(content: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(content)
+    }
+
+    /// The region used when a call does not name one, like the profile's region in AWS.
+    pub fn default_region(&self, profile_name: &str) -> String {
+        self.profiles
+            .iter()
+            .find(|profile| profile.name == profile_name)
+            .and_then(|profile| profile.region.clone())
+            .unwrap_or_else(|| FALLBACK_REGION.to_string())
+    }
+
+    /// Returns the error to fail the operation with, if the fixture injects one for the profile.
+    pub fn injected_error(&self, operation: &str, profile_name: &str) -> Option<&InjectedError> {
+        self.errors.iter().find(|error| {
+            error.operation == operation
+                && error
+                    .profile_name
+                    .as_ref()
+                    .map_or(true, |error_profile_name| {
+                        error_profile_name == profile_name
+                    })
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DemoProfile {
+    pub name: String,
+    pub region: Option<String>,
+    pub output_format: Option<String>,
+    pub access_key_id: Option<String>,
+    pub secret_access_key: Option<SecureString>,
+    /// Minutes until the profile's session expires. Profiles without it use long-lived
+    /// credentials.
+    pub session_minutes: Option<i64>,
+    #[serde(default)]
+    pub protection_level: ProtectionLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DemoParameterType {
+    String,
+    StringList,
+    SecureString,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DemoParameter {
+    pub profile_name: String,
+    /// The profile's default region if not set.
+    pub region: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub parameter_type: DemoParameterType,
+    /// The values of all versions, oldest first.
+    pub versions: Vec<String>,
+}
+
+/// Makes an operation fail with the error of the given code, for all profiles unless a profile
+/// is named.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct InjectedError {
+    pub operation: String,
+    pub profile_name: Option<String>,
+    pub code: String,
+    pub message: Option<String>,
+}
+
+impl InjectedError {
+    pub fn message(&self) -> String {
+        self.message
+            .clone()
+            .unwrap_or_else(|| "injected demo error".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_parse_bundled_fixture() {
+        let actual = DemoFixture::from_json(BUNDLED_FIXTURE);
+
+        assert_that!(actual).is_ok();
+        let fixture = actual.unwrap();
+        assert_that!(fixture.profiles).is_not_empty();
+        assert_that!(fixture.parameters).is_not_empty();
+    }
+
+    #[test]
+    fn should_enable_demo_mode_via_flag_or_environment() {
+        let args = |args: &[&str]| {
+            args.iter()
+                .map(|arg| arg.to_string())
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+
+        assert_that!(demo_mode_requested(args(&["backend", "--demo"]), None)).is_true();
+        assert_that!(demo_mode_requested(
+            args(&["backend"]),
+            Some("true".to_string())
+        ))
+        .is_true();
+        assert_that!(demo_mode_requested(
+            args(&["backend"]),
+            Some("0".to_string())
+        ))
+        .is_false();
+    }
+
+    #[test]
+    fn should_inject_error_for_matching_profile_only() {
+        let cut = DemoFixture {
+            errors: vec![InjectedError {
+                operation: "load_parameters".to_string(),
+                profile_name: Some("broken".to_string()),
+                code: "ParameterDataLoadError".to_string(),
+                message: None,
+            }],
+            ..DemoFixture::default()
+        };
+
+        assert_that!(cut.injected_error("load_parameters", "broken")).is_some();
+        assert_that!(cut.injected_error("load_parameters", "dev")).is_none();
+        assert_that!(cut.injected_error("upsert_parameter", "broken")).is_none();
+    }
+
+    #[test]
+    fn should_fall_back_to_default_region_for_unknown_profile() {
+        let actual = DemoFixture::default().default_region("unknown");
+
+        assert_that!(actual.as_str()).is_equal_to(FALLBACK_REGION);
+    }
+}
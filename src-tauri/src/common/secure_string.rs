@@ -1,37 +1,174 @@
-use std::fmt::Formatter;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::str::Utf8Error;
+use std::sync::Mutex;
 
-use secstr::SecStr;
+use lazy_static::lazy_static;
 use serde::de::{Error, Visitor};
 use serde::{Deserializer, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Holds a secret such as a secret access key or the value of a `SecureString` parameter. The
+/// buffer is locked into memory, so it is not swapped to disk, and its bytes are wiped when the
+/// value is dropped, including the spare capacity of the buffer.
+pub struct SecureString {
+    bytes: Vec<u8>,
+    lock: Option<PageLock>,
+}
+
+lazy_static! {
+    /// How many live buffers use each locked page, keyed by the page address.
+    static ref LOCKED_PAGES: Mutex<HashMap<usize, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Keeps the pages of a buffer locked into memory. Small buffers share pages, and locks are not
+/// nested, so a page is only unlocked once the last buffer on it is gone.
+struct PageLock {
+    pages: Vec<usize>,
+}
+
+impl PageLock {
+    fn new(address: *const u8, size: usize) -> PageLock {
+        let page_size = region::page::size();
+        let first_page = region::page::floor(address) as usize;
+        let end = address as usize + size;
+        let mut locked_pages = LOCKED_PAGES.lock().unwrap();
+
+        let pages = (first_page..end)
+            .step_by(page_size)
+            .filter(|page| match locked_pages.get_mut(page) {
+                Some(count) => {
+                    *count += 1;
+                    true
+                }
+                None => {
+                    // Locking is best effort, it fails e.g. once the memlock limit of the
+                    // process is reached. The page is unlocked explicitly, not by the guard.
+                    let locked = region::lock(*page as *const u8, page_size)
+                        .map(std::mem::forget)
+                        .is_ok();
+                    if locked {
+                        locked_pages.insert(*page, 1);
+                    }
+                    locked
+                }
+            })
+            .collect();
+
+        PageLock { pages }
+    }
+}
 
-#[derive(Debug, Eq, PartialEq, Clone)]
-pub struct SecureString(SecStr);
+impl Drop for PageLock {
+    fn drop(&mut self) {
+        let page_size = region::page::size();
+        let mut locked_pages = LOCKED_PAGES.lock().unwrap();
+
+        for page in &self.pages {
+            if let Some(count) = locked_pages.get_mut(page) {
+                *count -= 1;
+                if *count == 0 {
+                    locked_pages.remove(page);
+                    let _ = region::unlock(*page as *const u8, page_size);
+                }
+            }
+        }
+    }
+}
 
 impl SecureString {
     pub fn new(bytes: Vec<u8>) -> SecureString {
-        SecureString(SecStr::from(bytes))
+        let lock = (bytes.capacity() > 0).then(|| PageLock::new(bytes.as_ptr(), bytes.capacity()));
+
+        SecureString { bytes, lock }
     }
 
-    pub fn as_str(&self) -> &str {
-        std::str::from_utf8(self.0.unsecure()).expect("should be serializable to be UTF-8 string")
+    /// Fails instead of panicking for secrets that are not valid UTF-8, e.g. binary values.
+    pub fn try_as_str(&self) -> Result<&str, Utf8Error> {
+        std::str::from_utf8(&self.bytes)
+    }
+
+    /// Hands out the plain bytes for the duration of the closure only, so that the secret does
+    /// not have to be copied to be used.
+    pub fn expose<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+        f(&self.bytes)
+    }
+
+    pub fn expose_str<R>(&self, f: impl FnOnce(&str) -> R) -> Result<R, Utf8Error> {
+        self.try_as_str().map(f)
+    }
+
+    /// Moves the buffer into a plain string without copying it, for APIs such as the AWS SDK
+    /// that only accept an owned `String`. The string is neither locked nor wiped anymore, so it
+    /// must be handed over right away. Invalid UTF-8 is wiped before the error is returned.
+    pub fn into_unsecure_string(mut self) -> Result<String, Utf8Error> {
+        self.lock.take();
+        let bytes = std::mem::take(&mut self.bytes);
+
+        String::from_utf8(bytes).map_err(|err| {
+            let utf8_error = err.utf8_error();
+            err.into_bytes().zeroize();
+            utf8_error
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
     }
 }
 
+impl Clone for SecureString {
+    fn clone(&self) -> Self {
+        SecureString::new(self.bytes.clone())
+    }
+}
+
+impl PartialEq for SecureString {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for SecureString {}
+
+impl Debug for SecureString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecureString(***SECRET***)")
+    }
+}
+
+impl Zeroize for SecureString {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl Drop for SecureString {
+    fn drop(&mut self) {
+        self.zeroize();
+        // Unlock while the buffer is still allocated.
+        self.lock.take();
+    }
+}
+
+impl ZeroizeOnDrop for SecureString {}
+
 impl From<&str> for SecureString {
     fn from(s: &str) -> Self {
-        SecureString(SecStr::from(s))
+        SecureString::new(s.as_bytes().to_vec())
     }
 }
 
 impl From<String> for SecureString {
+    /// Takes over the string's buffer, so no unwiped copy of the secret is left behind.
     fn from(value: String) -> Self {
-        SecureString(SecStr::from(value))
+        SecureString::new(value.into_bytes())
     }
 }
 
 impl From<Vec<u8>> for SecureString {
     fn from(bytes: Vec<u8>) -> Self {
-        SecureString(SecStr::new(bytes))
+        SecureString::new(bytes)
     }
 }
 
@@ -40,8 +177,9 @@ impl serde::Serialize for SecureString {
     where
         S: Serializer,
     {
-        let secure_str = &self.0.unsecure();
-        let utf8_str = std::str::from_utf8(secure_str).expect("should serialize to utf8 string");
+        let utf8_str = self.try_as_str().map_err(|_| {
+            <S::Error as serde::ser::Error>::custom("secure string should be valid UTF-8")
+        })?;
 
         serializer.serialize_str(utf8_str)
     }
@@ -60,14 +198,14 @@ impl<'de> Visitor<'de> for SecureStringVisitor {
     where
         E: Error,
     {
-        Ok(SecureString(SecStr::from(v)))
+        Ok(SecureString::from(v))
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
     where
         E: Error,
     {
-        Ok(SecureString(SecStr::from(v)))
+        Ok(SecureString::from(v))
     }
 }
 
@@ -76,7 +214,8 @@ impl<'de> serde::Deserialize<'de> for SecureString {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(SecureStringVisitor)
+        // Owning deserializers hand over their string, so it ends up in the secure string as is.
+        deserializer.deserialize_string(SecureStringVisitor)
     }
 }
 
@@ -88,7 +227,7 @@ mod tests {
 
     #[test]
     fn should_serialize_secure_string_to_string() {
-        let secure_str = SecureString(SecStr::from("super_secret_password"));
+        let secure_str = SecureString::from("super_secret_password");
 
         let serialized = serde_json::to_string(&secure_str).unwrap();
 
@@ -97,7 +236,7 @@ mod tests {
 
     #[test]
     fn should_serialize_empty_secure_string_to_string() {
-        let secure_str = SecureString(SecStr::from(""));
+        let secure_str = SecureString::from("");
 
         let serialized = serde_json::to_string(&secure_str).unwrap();
 
@@ -106,23 +245,29 @@ mod tests {
 
     #[test]
     fn should_serialize_secure_string_with_special_chars_to_string() {
-        let secure_str = SecureString(SecStr::from("s3cr3t_$tr!ng"));
+        let secure_str = SecureString::from("s3cr3t_$tr!ng");
 
         let serialized = serde_json::to_string(&secure_str).unwrap();
 
         assert_eq!(serialized, "\"s3cr3t_$tr!ng\"");
     }
 
+    #[test]
+    fn should_fail_to_serialize_secure_string_that_is_not_utf8() {
+        let secure_str = SecureString::from(vec![0, 159, 146, 150]);
+
+        let serialized = serde_json::to_string(&secure_str);
+
+        assert_that!(serialized).is_err();
+    }
+
     #[test]
     fn should_deserialize_str_to_secure_string() {
         let serialized = "\"super_secret_password\"";
 
         let deserialized: SecureString = serde_json::from_str(serialized).unwrap();
 
-        assert_eq!(
-            deserialized.0.unsecure(),
-            "super_secret_password".as_bytes()
-        );
+        assert_eq!(deserialized.bytes, "super_secret_password".as_bytes());
     }
 
     #[test]
@@ -131,7 +276,7 @@ mod tests {
 
         let deserialized: SecureString = serde_json::from_str(serialized).unwrap();
 
-        assert_eq!(deserialized.0.unsecure(), "".as_bytes());
+        assert_eq!(deserialized.bytes, "".as_bytes());
     }
 
     #[test]
@@ -140,6 +285,112 @@ mod tests {
 
         let deserialized: SecureString = serde_json::from_str(serialized).unwrap();
 
-        assert_eq!(deserialized.0.unsecure(), "s3cr3t_$tr!ng".as_bytes());
+        assert_eq!(deserialized.bytes, "s3cr3t_$tr!ng".as_bytes());
+    }
+
+    #[test]
+    fn should_take_over_buffer_when_deserializing_owned_string() {
+        let secret = "super_secret_password".to_string();
+        let buffer = secret.as_ptr();
+
+        let deserialized: SecureString =
+            serde::Deserialize::deserialize(serde_json::Value::String(secret)).unwrap();
+
+        assert_that!(deserialized.bytes.as_ptr()).is_equal_to(buffer);
+    }
+
+    #[test]
+    fn should_take_over_buffer_when_converting_from_string() {
+        let secret = "super_secret_password".to_string();
+        let buffer = secret.as_ptr();
+
+        let actual = SecureString::from(secret);
+
+        assert_that!(actual.bytes.as_ptr()).is_equal_to(buffer);
+    }
+
+    #[test]
+    fn should_return_error_instead_of_panicking_for_invalid_utf8() {
+        let secure_str = SecureString::from(vec![0, 159, 146, 150]);
+
+        assert_that!(secure_str.try_as_str()).is_err();
+        assert_that!(secure_str.expose_str(|value| value.len())).is_err();
+        assert_that!(secure_str.expose(|bytes| bytes.len())).is_equal_to(4);
+    }
+
+    #[test]
+    fn should_wipe_whole_buffer_when_zeroized() {
+        let mut secure_str = SecureString::from("super_secret_password".to_string());
+        let buffer = secure_str.bytes.as_ptr();
+        let capacity = secure_str.bytes.capacity();
+
+        secure_str.zeroize();
+
+        // The buffer is still owned by the secure string, only its length was reset.
+        assert_that!(secure_str.bytes.as_ptr()).is_equal_to(buffer);
+        let wiped = unsafe { std::slice::from_raw_parts(buffer, capacity) };
+        assert!(wiped.iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn should_move_buffer_when_converting_into_unsecure_string() {
+        let secure_str = SecureString::from("super_secret_password".to_string());
+        let buffer = secure_str.bytes.as_ptr();
+
+        let actual = secure_str.into_unsecure_string().unwrap();
+
+        assert_that!(actual.as_ptr()).is_equal_to(buffer);
+        assert_that!(actual).is_equal_to("super_secret_password".to_string());
+    }
+
+    #[test]
+    fn should_fail_to_convert_invalid_utf8_into_unsecure_string() {
+        let secure_str = SecureString::from(vec![0, 159, 146, 150]);
+
+        let actual = secure_str.into_unsecure_string();
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_copy_secret_into_separate_buffer_when_cloned() {
+        let secure_str = SecureString::from("super_secret_password");
+
+        let actual = secure_str.clone();
+
+        assert_that!(actual.bytes.as_ptr()).is_not_equal_to(secure_str.bytes.as_ptr());
+        assert_that!(actual).is_equal_to(secure_str);
+    }
+
+    #[test]
+    fn should_not_reveal_secret_in_debug_output() {
+        let secure_str = SecureString::from("super_secret_password");
+
+        let debug_output = format!("{:?}", secure_str);
+
+        assert!(!debug_output.contains("super_secret_password"));
+    }
+
+    fn lock_count(page: usize) -> Option<usize> {
+        LOCKED_PAGES.lock().unwrap().get(&page).copied()
+    }
+
+    #[test]
+    fn should_keep_shared_page_locked_until_last_secret_on_it_is_dropped() {
+        // A buffer of its own guarantees that no other test locks the page in between.
+        let page_size = region::page::size();
+        let buffer = vec![0u8; 2 * page_size];
+        let page = region::page::ceil(buffer.as_ptr()) as usize;
+        let first = PageLock::new(page as *const u8, 32);
+        let second = PageLock::new((page + 32) as *const u8, 32);
+        assert_that!(lock_count(page)).is_equal_to(Some(2));
+
+        drop(first);
+
+        assert_that!(lock_count(page)).is_equal_to(Some(1));
+
+        drop(second);
+
+        assert_that!(lock_count(page)).is_none();
     }
 }
@@ -0,0 +1,3 @@
+pub mod application;
+pub mod core;
+pub mod infrastructure;
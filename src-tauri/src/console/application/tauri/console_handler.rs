@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::console::core::api::ConsoleSignInAPI;
+use crate::console::core::domain::ConsoleDestination;
+use crate::console::core::error::ConsoleError;
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn create_console_signin_url(
+    api: tauri::State<'_, Arc<dyn ConsoleSignInAPI>>,
+    profile_name: String,
+    destination: Option<ConsoleDestination>,
+) -> Result<String, ConsoleError> {
+    api.create_signin_url(profile_name.as_str(), destination.unwrap_or_default())
+        .await
+        .map_err(ConsoleError::from)
+}
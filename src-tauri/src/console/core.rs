@@ -0,0 +1,5 @@
+pub mod api;
+pub mod console_service;
+pub mod domain;
+pub mod error;
+pub mod spi;
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::console::core::domain::ConsoleDestination;
+use crate::console::core::error::ConsoleError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ConsoleSignInAPI: Send + Sync {
+    /// Returns a federated sign-in URL that opens the given destination in the AWS Management
+    /// Console.
+    async fn create_signin_url(
+        &self,
+        profile_name: &str,
+        destination: ConsoleDestination,
+    ) -> Result<String, ConsoleError>;
+}
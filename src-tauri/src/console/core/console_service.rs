@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use error_stack::Report;
+
+use crate::console::core::api::ConsoleSignInAPI;
+use crate::console::core::domain::{
+    ConsoleDestination, ConsoleSignInConfig, Partition, SignInUrlBuilder,
+};
+use crate::console::core::error::ConsoleError;
+use crate::console::core::spi::{ConsoleSessionSPI, SignInTokenSPI};
+
+pub struct ConsoleService {
+    console_session_spi: Box<dyn ConsoleSessionSPI>,
+    signin_token_spi: Box<dyn SignInTokenSPI>,
+    config: ConsoleSignInConfig,
+}
+
+impl ConsoleService {
+    pub fn new(
+        console_session_spi: Box<dyn ConsoleSessionSPI>,
+        signin_token_spi: Box<dyn SignInTokenSPI>,
+        config: ConsoleSignInConfig,
+    ) -> Self {
+        Self {
+            console_session_spi,
+            signin_token_spi,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl ConsoleSignInAPI for ConsoleService {
+    async fn create_signin_url(
+        &self,
+        profile_name: &str,
+        destination: ConsoleDestination,
+    ) -> error_stack::Result<String, ConsoleError> {
+        let session = self
+            .console_session_spi
+            .load_console_session(profile_name)
+            .await?;
+
+        let partition = Partition::from_region(session.region.as_deref());
+        let url_builder = SignInUrlBuilder::new(&self.config, partition);
+        let signin_token_url = url_builder
+            .signin_token_url(&session)
+            .map_err(Report::new)?;
+        let signin_token = self
+            .signin_token_spi
+            .load_signin_token(profile_name, signin_token_url)
+            .await?;
+
+        let destination_url = destination.to_url(session.region.as_deref());
+
+        Ok(url_builder
+            .login_url(&signin_token, &destination_url)
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use error_stack::Report;
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+    use url::Url;
+
+    use crate::common::secure_string::SecureString;
+    use crate::console::core::domain::ConsoleSession;
+    use crate::console::core::spi::{MockConsoleSessionSPI, MockSignInTokenSPI};
+
+    use super::*;
+
+    fn config() -> ConsoleSignInConfig {
+        ConsoleSignInConfig::new(
+            Some(Url::parse("http://localhost:4000/federation").unwrap()),
+            "custodian-test".to_string(),
+            Duration::from_secs(900),
+        )
+    }
+
+    fn session() -> ConsoleSession {
+        ConsoleSession::new(
+            "ASIAEXAMPLE".to_string(),
+            SecureString::from("secret"),
+            SecureString::from("token"),
+            Some("eu-west-1".to_string()),
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_exchange_session_for_signin_token_and_return_login_url() {
+        let mut console_session_spi_mock = MockConsoleSessionSPI::new();
+        console_session_spi_mock
+            .expect_load_console_session()
+            .with(eq("prod"))
+            .times(1)
+            .returning(|_| Ok(session()));
+        let mut signin_token_spi_mock = MockSignInTokenSPI::new();
+        signin_token_spi_mock
+            .expect_load_signin_token()
+            .withf(|profile_name, url| {
+                profile_name == "prod"
+                    && url
+                        .as_str()
+                        .starts_with("http://localhost:4000/federation?Action=getSigninToken")
+            })
+            .times(1)
+            .returning(|_, _| Ok("signin-token".to_string()));
+        let cut = ConsoleService::new(
+            Box::new(console_session_spi_mock),
+            Box::new(signin_token_spi_mock),
+            config(),
+        );
+
+        let actual = cut
+            .create_signin_url(
+                "prod",
+                ConsoleDestination::ParameterStore {
+                    path: Some("/app".to_string()),
+                },
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+        let url = Url::parse(&actual.unwrap()).unwrap();
+        let destination = url
+            .query_pairs()
+            .find(|(name, _)| name == "Destination")
+            .map(|(_, value)| value.into_owned())
+            .unwrap();
+        assert_that!(destination.as_str())
+            .starts_with("https://eu-west-1.console.aws.amazon.com/systems-manager/parameters/");
+        assert_that!(url.as_str()).contains("SigninToken=signin-token");
+    }
+
+    #[tokio::test]
+    async fn should_not_request_signin_token_when_session_cannot_be_loaded() {
+        let mut console_session_spi_mock = MockConsoleSessionSPI::new();
+        console_session_spi_mock
+            .expect_load_console_session()
+            .returning(|_| Err(Report::new(ConsoleError::InvalidCredentialsError)));
+        let mut signin_token_spi_mock = MockSignInTokenSPI::new();
+        signin_token_spi_mock.expect_load_signin_token().never();
+        let cut = ConsoleService::new(
+            Box::new(console_session_spi_mock),
+            Box::new(signin_token_spi_mock),
+            config(),
+        );
+
+        let actual = cut
+            .create_signin_url("prod", ConsoleDestination::Home)
+            .await;
+
+        assert_that!(actual).is_err();
+    }
+}
@@ -0,0 +1,363 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use url::{form_urlencoded, Url};
+
+use crate::common::secure_string::SecureString;
+use crate::console::core::error::ConsoleError;
+
+pub const DEFAULT_SIGNIN_ENDPOINT: &str = "https://signin.aws.amazon.com/federation";
+pub const CHINA_SIGNIN_ENDPOINT: &str = "https://signin.amazonaws.cn/federation";
+pub const GOV_CLOUD_SIGNIN_ENDPOINT: &str = "https://signin.amazonaws-us-gov.com/federation";
+pub const DEFAULT_ISSUER: &str = "aws-custodian";
+const CONSOLE_URL: &str = "console.aws.amazon.com";
+const CHINA_CONSOLE_URL: &str = "console.amazonaws.cn";
+const GOV_CLOUD_CONSOLE_URL: &str = "console.amazonaws-us-gov.com";
+// Federated user names are limited to 32 characters of [\w+=,.@-].
+const MAX_FEDERATED_USER_NAME_LENGTH: usize = 32;
+
+/// Temporary credentials that can be exchanged for a console sign-in token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleSession {
+    pub access_key_id: String,
+    pub secret_access_key: SecureString,
+    pub session_token: SecureString,
+    pub region: Option<String>,
+    /// Sessions obtained via `GetFederationToken` keep their own duration, the sign-in endpoint
+    /// rejects an explicit one for them.
+    pub federated: bool,
+}
+
+impl ConsoleSession {
+    pub fn new(
+        access_key_id: String,
+        secret_access_key: SecureString,
+        session_token: SecureString,
+        region: Option<String>,
+        federated: bool,
+    ) -> Self {
+        Self {
+            access_key_id,
+            secret_access_key,
+            session_token,
+            region,
+            federated,
+        }
+    }
+}
+
+/// The AWS partition of a session. Each partition has its own sign-in endpoint and console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Partition {
+    Aws,
+    AwsCn,
+    AwsUsGov,
+}
+
+impl Partition {
+    pub fn from_region(region: Option<&str>) -> Self {
+        match region {
+            Some(region) if region.starts_with("cn-") => Partition::AwsCn,
+            Some(region) if region.starts_with("us-gov-") => Partition::AwsUsGov,
+            _ => Partition::Aws,
+        }
+    }
+
+    pub fn signin_endpoint(&self) -> Url {
+        let endpoint = match self {
+            Partition::Aws => DEFAULT_SIGNIN_ENDPOINT,
+            Partition::AwsCn => CHINA_SIGNIN_ENDPOINT,
+            Partition::AwsUsGov => GOV_CLOUD_SIGNIN_ENDPOINT,
+        };
+        Url::parse(endpoint).expect("partition sign-in endpoint should be valid")
+    }
+
+    fn console_url(&self, region: Option<&str>) -> String {
+        match (self, region) {
+            (Partition::Aws, Some(region)) => format!("https://{}.{}", region, CONSOLE_URL),
+            (Partition::Aws, None) => format!("https://{}", CONSOLE_URL),
+            // The other partitions do not serve regional console hosts.
+            (Partition::AwsCn, _) => format!("https://{}", CHINA_CONSOLE_URL),
+            (Partition::AwsUsGov, _) => format!("https://{}", GOV_CLOUD_CONSOLE_URL),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ConsoleDestination {
+    #[default]
+    Home,
+    ParameterStore {
+        path: Option<String>,
+    },
+    Url {
+        url: String,
+    },
+}
+
+impl ConsoleDestination {
+    pub fn to_url(&self, region: Option<&str>) -> String {
+        let host = Partition::from_region(region).console_url(region);
+
+        match self {
+            ConsoleDestination::Home => match region {
+                Some(region) => format!("{}/console/home?region={}", host, region),
+                None => format!("{}/console/home", host),
+            },
+            ConsoleDestination::ParameterStore { path } => {
+                let mut url = format!("{}/systems-manager/parameters/?tab=Table", host);
+                if let Some(region) = region {
+                    url.push_str(&format!("&region={}", region));
+                }
+                if let Some(path) = path.as_deref().filter(|path| !path.is_empty()) {
+                    url.push_str(&format!(
+                        "#list_parameter_filters=Path:Recursive:{}",
+                        form_urlencoded::byte_serialize(path.as_bytes()).collect::<String>()
+                    ));
+                }
+                url
+            }
+            ConsoleDestination::Url { url } => url.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleSignInConfig {
+    /// Replaces the sign-in endpoint of the session's partition, e.g. to sign in through a stub.
+    pub signin_endpoint: Option<Url>,
+    pub issuer: String,
+    pub session_duration: Duration,
+}
+
+impl ConsoleSignInConfig {
+    pub fn new(signin_endpoint: Option<Url>, issuer: String, session_duration: Duration) -> Self {
+        Self {
+            signin_endpoint,
+            issuer,
+            session_duration,
+        }
+    }
+}
+
+impl Default for ConsoleSignInConfig {
+    fn default() -> Self {
+        Self::new(
+            None,
+            DEFAULT_ISSUER.to_string(),
+            Duration::from_secs(60 * 60),
+        )
+    }
+}
+
+/// Builds the two federation endpoint URLs: the one exchanging a session for a sign-in token and
+/// the login URL handed to the browser.
+pub struct SignInUrlBuilder<'a> {
+    config: &'a ConsoleSignInConfig,
+    signin_endpoint: Url,
+}
+
+impl<'a> SignInUrlBuilder<'a> {
+    pub fn new(config: &'a ConsoleSignInConfig, partition: Partition) -> Self {
+        let signin_endpoint = config
+            .signin_endpoint
+            .clone()
+            .unwrap_or_else(|| partition.signin_endpoint());
+
+        Self {
+            config,
+            signin_endpoint,
+        }
+    }
+
+    pub fn signin_token_url(&self, session: &ConsoleSession) -> Result<Url, ConsoleError> {
+        let invalid_secret = |_| {
+            ConsoleError::SessionCredentialsLoadError(
+                "session credentials should be valid UTF-8".to_string(),
+            )
+        };
+        let session_json = json!({
+            "sessionId": session.access_key_id,
+            "sessionKey": session.secret_access_key.try_as_str().map_err(invalid_secret)?,
+            "sessionToken": session.session_token.try_as_str().map_err(invalid_secret)?,
+        });
+
+        let mut url = self.signin_endpoint.clone();
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("Action", "getSigninToken");
+            if !session.federated {
+                query.append_pair(
+                    "SessionDuration",
+                    &self.config.session_duration.as_secs().to_string(),
+                );
+            }
+            query.append_pair("Session", &session_json.to_string());
+        }
+        Ok(url)
+    }
+
+    pub fn login_url(&self, signin_token: &str, destination: &str) -> Url {
+        let mut url = self.signin_endpoint.clone();
+        url.query_pairs_mut()
+            .append_pair("Action", "login")
+            .append_pair("Issuer", &self.config.issuer)
+            .append_pair("Destination", destination)
+            .append_pair("SigninToken", signin_token);
+        url
+    }
+}
+
+/// Derives a valid `GetFederationToken` user name from the profile name.
+pub fn federated_user_name(profile_name: &str) -> String {
+    let name: String = profile_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "_+=,.@-".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(MAX_FEDERATED_USER_NAME_LENGTH)
+        .collect();
+
+    // The API requires at least two characters.
+    if name.len() < 2 {
+        DEFAULT_ISSUER.to_string()
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn session(federated: bool) -> ConsoleSession {
+        ConsoleSession::new(
+            "ASIAEXAMPLE".to_string(),
+            SecureString::from("secret"),
+            SecureString::from("token"),
+            Some("eu-west-1".to_string()),
+            federated,
+        )
+    }
+
+    fn query_value(url: &Url, key: &str) -> Option<String> {
+        url.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    #[test]
+    fn should_build_signin_token_url_with_session_duration_for_role_session() {
+        let config = ConsoleSignInConfig::default();
+        let cut = SignInUrlBuilder::new(&config, Partition::Aws);
+
+        let actual = cut.signin_token_url(&session(false)).unwrap();
+
+        assert_that!(actual.as_str()).starts_with(DEFAULT_SIGNIN_ENDPOINT);
+        assert_that!(query_value(&actual, "Action"))
+            .is_equal_to(Some("getSigninToken".to_string()));
+        assert_that!(query_value(&actual, "SessionDuration")).is_equal_to(Some("3600".to_string()));
+        let session_json: serde_json::Value =
+            serde_json::from_str(&query_value(&actual, "Session").unwrap()).unwrap();
+        assert_that!(session_json).is_equal_to(json!({
+            "sessionId": "ASIAEXAMPLE",
+            "sessionKey": "secret",
+            "sessionToken": "token",
+        }));
+    }
+
+    #[test]
+    fn should_omit_session_duration_for_federated_session() {
+        let config = ConsoleSignInConfig::default();
+        let cut = SignInUrlBuilder::new(&config, Partition::Aws);
+
+        let actual = cut.signin_token_url(&session(true)).unwrap();
+
+        assert_that!(query_value(&actual, "SessionDuration")).is_none();
+    }
+
+    #[test]
+    fn should_build_login_url_against_configured_endpoint() {
+        let config = ConsoleSignInConfig::new(
+            Some(Url::parse("http://localhost:8080/federation").unwrap()),
+            "custodian-test".to_string(),
+            Duration::from_secs(900),
+        );
+        let cut = SignInUrlBuilder::new(&config, Partition::AwsUsGov);
+
+        let actual = cut.login_url("signin-token", "https://console.aws.amazon.com/?a=1&b=2");
+
+        assert_that!(actual.as_str()).starts_with("http://localhost:8080/federation?Action=login");
+        assert_that!(query_value(&actual, "Issuer"))
+            .is_equal_to(Some("custodian-test".to_string()));
+        assert_that!(query_value(&actual, "Destination"))
+            .is_equal_to(Some("https://console.aws.amazon.com/?a=1&b=2".to_string()));
+        assert_that!(query_value(&actual, "SigninToken"))
+            .is_equal_to(Some("signin-token".to_string()));
+    }
+
+    #[test]
+    fn should_use_signin_endpoint_of_session_partition() {
+        let config = ConsoleSignInConfig::default();
+        let cut = SignInUrlBuilder::new(&config, Partition::from_region(Some("cn-north-1")));
+
+        let actual = cut.login_url("signin-token", "https://console.amazonaws.cn/");
+
+        assert_that!(actual.as_str()).starts_with(CHINA_SIGNIN_ENDPOINT);
+    }
+
+    #[test]
+    fn should_derive_partition_from_region() {
+        assert_that!(Partition::from_region(Some("eu-west-1"))).is_equal_to(Partition::Aws);
+        assert_that!(Partition::from_region(Some("cn-northwest-1"))).is_equal_to(Partition::AwsCn);
+        assert_that!(Partition::from_region(Some("us-gov-west-1")))
+            .is_equal_to(Partition::AwsUsGov);
+        assert_that!(Partition::from_region(None)).is_equal_to(Partition::Aws);
+    }
+
+    #[test]
+    fn should_build_gov_cloud_home_destination() {
+        let actual = ConsoleDestination::Home.to_url(Some("us-gov-west-1"));
+
+        assert_that!(actual).is_equal_to(
+            "https://console.amazonaws-us-gov.com/console/home?region=us-gov-west-1".to_string(),
+        );
+    }
+
+    #[test]
+    fn should_build_parameter_store_destination_for_path() {
+        let cut = ConsoleDestination::ParameterStore {
+            path: Some("/app/prod".to_string()),
+        };
+
+        let actual = cut.to_url(Some("eu-west-1"));
+
+        assert_that!(actual).is_equal_to(
+            "https://eu-west-1.console.aws.amazon.com/systems-manager/parameters/?tab=Table&region=eu-west-1#list_parameter_filters=Path:Recursive:%2Fapp%2Fprod"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn should_build_global_home_destination_without_region() {
+        let actual = ConsoleDestination::Home.to_url(None);
+
+        assert_that!(actual).is_equal_to("https://console.aws.amazon.com/console/home".to_string());
+    }
+
+    #[test]
+    fn should_derive_valid_federated_user_name() {
+        assert_that!(federated_user_name("dev profile/admin"))
+            .is_equal_to("dev-profile-admin".to_string());
+        assert_that!(federated_user_name(&"a".repeat(40)).len()).is_equal_to(32);
+        assert_that!(federated_user_name("x")).is_equal_to(DEFAULT_ISSUER.to_string());
+    }
+}
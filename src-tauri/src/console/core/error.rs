@@ -0,0 +1,87 @@
+use std::fmt::{Display, Formatter};
+
+use error_stack::{Context, Report};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ConsoleError {
+    InvalidCredentialsError,
+    SessionCredentialsLoadError(String),
+    SignInTokenLoadError(String),
+}
+
+impl Display for ConsoleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleError::InvalidCredentialsError => write!(f, "invalid credentials error"),
+            ConsoleError::SessionCredentialsLoadError(reason) => {
+                write!(f, "failed to load console session credentials: {}", reason)
+            }
+            ConsoleError::SignInTokenLoadError(reason) => {
+                write!(f, "failed to load console sign-in token: {}", reason)
+            }
+        }
+    }
+}
+
+impl Context for ConsoleError {}
+
+impl From<Report<ConsoleError>> for ConsoleError {
+    fn from(value: Report<ConsoleError>) -> Self {
+        let context = value.current_context();
+        context.clone()
+    }
+}
+
+impl Serialize for ConsoleError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ConsoleError", 1)?;
+        let (code, message) = match self {
+            ConsoleError::InvalidCredentialsError => ("InvalidCredentialsError", self.to_string()),
+            ConsoleError::SessionCredentialsLoadError(_) => {
+                ("SessionCredentialsLoadError", self.to_string())
+            }
+            ConsoleError::SignInTokenLoadError(_) => ("SignInTokenLoadError", self.to_string()),
+        };
+        state.serialize_field("error", &json!({ "code": code, "message": message }))?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_return_sign_in_token_load_error_when_calling_from_on_report_with_context_sign_in_token_load_error(
+    ) {
+        let error = ConsoleError::SignInTokenLoadError("503".to_string());
+        let report = Report::new(error.clone());
+
+        let result: ConsoleError = report.into();
+
+        assert_eq!(error, result);
+    }
+
+    #[test]
+    fn should_serialize_session_credentials_load_error_to_json() {
+        let error = ConsoleError::SessionCredentialsLoadError("AccessDenied".to_string());
+        let expected = json!({
+            "error": {
+                "code": "SessionCredentialsLoadError",
+                "message": "failed to load console session credentials: AccessDenied"
+            }
+        });
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected.to_string());
+    }
+}
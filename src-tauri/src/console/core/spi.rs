@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+use url::Url;
+
+use crate::console::core::domain::ConsoleSession;
+use crate::console::core::error::ConsoleError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ConsoleSessionSPI: Send + Sync {
+    /// Returns the profile's session credentials for role profiles and a federation token for
+    /// profiles with long-term IAM user credentials.
+    async fn load_console_session(
+        &self,
+        profile_name: &str,
+    ) -> Result<ConsoleSession, ConsoleError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SignInTokenSPI: Send + Sync {
+    /// Exchanges the session for a sign-in token using the HTTP settings of the profile.
+    async fn load_signin_token(
+        &self,
+        profile_name: &str,
+        signin_token_url: Url,
+    ) -> Result<String, ConsoleError>;
+}
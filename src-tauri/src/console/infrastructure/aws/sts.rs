@@ -0,0 +1 @@
+pub mod federation_adapter;
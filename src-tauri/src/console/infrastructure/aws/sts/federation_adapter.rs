@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use aws_sdk_sts::config::ProvideCredentials;
+use aws_sdk_sts::error::ProvideErrorMetadata;
+use error_stack::Report;
+
+use crate::common::aws::client_factory;
+use crate::common::secure_string::SecureString;
+use crate::console::core::domain::{federated_user_name, ConsoleSession};
+use crate::console::core::error::ConsoleError;
+use crate::console::core::spi::ConsoleSessionSPI;
+
+// The federated session is scoped down to the intersection of this policy and the IAM user's own
+// permissions, so granting everything here grants exactly what the user already has.
+const FEDERATION_POLICY: &str =
+    r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Action":"*","Resource":"*"}]}"#;
+const FEDERATION_DURATION_SECONDS: i32 = 60 * 60;
+
+pub struct FederationAdapter;
+
+#[async_trait]
+impl ConsoleSessionSPI for FederationAdapter {
+    async fn load_console_session(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<ConsoleSession, ConsoleError> {
+        let shared_config = client_factory()
+            .sdk_config(profile_name, None)
+            .await
+            .map_err(|err| Report::new(ConsoleError::SessionCredentialsLoadError(err)))?;
+        let region = shared_config.region().map(|region| region.to_string());
+
+        let credentials_provider = shared_config.credentials_provider().ok_or_else(|| {
+            Report::new(ConsoleError::SessionCredentialsLoadError(
+                "no credentials provider configured".to_string(),
+            ))
+        })?;
+        let credentials = credentials_provider
+            .provide_credentials()
+            .await
+            .map_err(|err| {
+                tracing::error!("Error: failed to resolve credentials: {}", err);
+
+                Report::new(ConsoleError::SessionCredentialsLoadError(err.to_string()))
+            })?;
+
+        // Role profiles already resolve to session credentials that the sign-in endpoint accepts.
+        // Session tokens of other profiles, e.g. from GetSessionToken with MFA, are rejected by
+        // it, so those are federated like long-term credentials.
+        let role_session_token = if Self::is_role_profile(profile_name).await {
+            credentials.session_token()
+        } else {
+            None
+        };
+        if let Some(session_token) = role_session_token {
+            return Ok(ConsoleSession::new(
+                credentials.access_key_id().to_string(),
+                SecureString::from(credentials.secret_access_key()),
+                SecureString::from(session_token),
+                region,
+                false,
+            ));
+        }
+
+        let client = client_factory()
+            .sts_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(ConsoleError::SessionCredentialsLoadError(err)))?;
+        let result = client
+            .get_federation_token()
+            .name(federated_user_name(profile_name))
+            .policy(FEDERATION_POLICY)
+            .duration_seconds(FEDERATION_DURATION_SECONDS)
+            .send()
+            .await;
+        match result {
+            Ok(output) => {
+                let federated_credentials = output.credentials().ok_or_else(|| {
+                    Report::new(ConsoleError::SessionCredentialsLoadError(
+                        "federation token should contain credentials".to_string(),
+                    ))
+                })?;
+
+                Ok(ConsoleSession::new(
+                    federated_credentials.access_key_id().to_string(),
+                    SecureString::from(federated_credentials.secret_access_key()),
+                    SecureString::from(federated_credentials.session_token()),
+                    region,
+                    true,
+                ))
+            }
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                match error_code {
+                    Some("InvalidClientTokenId") => {
+                        Err(Report::new(ConsoleError::InvalidCredentialsError))
+                    }
+                    _ => Err(Report::new(ConsoleError::SessionCredentialsLoadError(
+                        error_code.unwrap_or("Server Error").to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+}
+
+impl FederationAdapter {
+    async fn is_role_profile(profile_name: &str) -> bool {
+        let result = aws_config::profile::load(
+            &Default::default(),
+            &Default::default(),
+            &Default::default(),
+            None,
+        )
+        .await;
+
+        result
+            .ok()
+            .and_then(|profile_set| {
+                profile_set.get_profile(profile_name).map(|profile| {
+                    resolves_to_role_session(&|key| profile.get(key).map(str::to_string))
+                })
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Assumed role and SSO profiles resolve to role sessions, every other session token, e.g. one
+/// from GetSessionToken with MFA, belongs to an IAM user.
+fn resolves_to_role_session(property: &dyn Fn(&str) -> Option<String>) -> bool {
+    ["role_arn", "sso_session", "sso_start_url", "sso_role_name"]
+        .iter()
+        .any(|key| property(key).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn is_role_session(properties: &[(&str, &str)]) -> bool {
+        resolves_to_role_session(&|key| {
+            properties
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn should_treat_assumed_role_and_sso_profiles_as_role_sessions() {
+        assert_that!(is_role_session(&[(
+            "role_arn",
+            "arn:aws:iam::123456789012:role/admin"
+        )]))
+        .is_true();
+        assert_that!(is_role_session(&[("sso_session", "corp")])).is_true();
+    }
+
+    #[test]
+    fn should_federate_mfa_session_credentials_instead_of_using_them_directly() {
+        let mfa_session = [
+            ("aws_access_key_id", "ASIAEXAMPLE"),
+            ("aws_secret_access_key", "secret"),
+            ("aws_session_token", "token"),
+            ("mfa_serial", "arn:aws:iam::123456789012:mfa/user"),
+        ];
+
+        assert_that!(is_role_session(&mfa_session)).is_false();
+    }
+}
@@ -0,0 +1 @@
+pub mod signin_token_adapter;
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use error_stack::Report;
+use serde::Deserialize;
+use url::Url;
+
+use crate::common::aws::client_factory;
+use crate::common::aws::http_client::build_reqwest_client;
+use crate::console::core::error::ConsoleError;
+use crate::console::core::spi::SignInTokenSPI;
+
+#[derive(Debug, Deserialize)]
+struct SignInTokenResponse {
+    #[serde(rename = "SigninToken")]
+    signin_token: String,
+}
+
+/// Requests the sign-in token through the proxy and CA bundle configured for the profile, like
+/// the SDK clients do.
+pub struct SignInTokenAdapter;
+
+impl SignInTokenAdapter {
+    async fn client(profile_name: &str) -> error_stack::Result<reqwest::Client, ConsoleError> {
+        let client_settings = client_factory()
+            .effective_client_settings(profile_name)
+            .await;
+
+        build_reqwest_client(&client_settings).map_err(|err| {
+            tracing::error!("Error: failed to build sign-in client: {}", err);
+
+            Report::new(ConsoleError::SignInTokenLoadError(err))
+        })
+    }
+}
+
+#[async_trait]
+impl SignInTokenSPI for SignInTokenAdapter {
+    async fn load_signin_token(
+        &self,
+        profile_name: &str,
+        signin_token_url: Url,
+    ) -> error_stack::Result<String, ConsoleError> {
+        // The request URL carries the session credentials, so it must never end up in the logs.
+        let response = Self::client(profile_name)
+            .await?
+            .get(signin_token_url)
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "Error: failed to request sign-in token: {}",
+                    err.without_url()
+                );
+
+                Report::new(ConsoleError::SignInTokenLoadError(
+                    "sign-in endpoint unreachable".to_string(),
+                ))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            tracing::error!("Error: sign-in endpoint responded with {}", status);
+
+            return Err(Report::new(ConsoleError::SignInTokenLoadError(
+                status.to_string(),
+            )));
+        }
+
+        response
+            .json::<SignInTokenResponse>()
+            .await
+            .map(|response| response.signin_token)
+            .map_err(|err| {
+                tracing::error!(
+                    "Error: failed to parse sign-in token: {}",
+                    err.without_url()
+                );
+
+                Report::new(ConsoleError::SignInTokenLoadError(
+                    "invalid sign-in token response".to_string(),
+                ))
+            })
+    }
+}
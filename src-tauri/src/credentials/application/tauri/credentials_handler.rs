@@ -1,15 +1,61 @@
 use std::sync::Arc;
 
+use crate::accounts::core::api::AccountDataAPI;
 use crate::credentials::core::api::CredentialsDataAPI;
+use crate::credentials::core::credentials_refresher::CredentialsRefresher;
+use crate::credentials::core::domain::{AuthorizationDecision, TemporaryCredentials};
 use crate::credentials::core::error::CredentialsError;
 
 #[tauri::command]
 #[cfg(not(tarpaulin_include))]
 pub async fn validate_credentials(
     api: tauri::State<'_, Arc<dyn CredentialsDataAPI>>,
+    refresher: tauri::State<'_, Arc<CredentialsRefresher>>,
+    account_api: tauri::State<'_, Arc<dyn AccountDataAPI>>,
     profile_name: String,
 ) -> Result<bool, CredentialsError> {
-    api.validate_credentials(profile_name.as_str())
+    let valid = api
+        .validate_credentials(profile_name.as_str())
+        .await
+        .map_err(CredentialsError::from)?;
+
+    if valid {
+        if let Err(report) = refresher.track(profile_name.as_str()).await {
+            tracing::warn!(
+                "failed to track credentials of `{}`: {:?}",
+                profile_name,
+                report
+            );
+        }
+
+        if let Err(report) = account_api.resolve_account(profile_name.as_str()).await {
+            tracing::warn!(
+                "failed to resolve account of `{}`: {:?}",
+                profile_name,
+                report
+            );
+        }
+    }
+
+    Ok(valid)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_tracked_credentials(
+    refresher: tauri::State<'_, Arc<CredentialsRefresher>>,
+) -> Result<Vec<TemporaryCredentials>, CredentialsError> {
+    Ok(refresher.tracked_credentials().await)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn decode_authorization_message(
+    api: tauri::State<'_, Arc<dyn CredentialsDataAPI>>,
+    profile_name: String,
+    encoded_message: String,
+) -> Result<AuthorizationDecision, CredentialsError> {
+    api.decode_authorization_message(profile_name.as_str(), encoded_message.as_str())
         .await
         .map_err(CredentialsError::from)
 }
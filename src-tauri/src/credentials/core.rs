@@ -1,4 +1,6 @@
 pub mod api;
+pub mod credentials_refresher;
 pub mod credentials_service;
+pub mod domain;
 pub mod error;
 pub mod spi;
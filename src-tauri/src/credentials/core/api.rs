@@ -1,3 +1,4 @@
+use crate::credentials::core::domain::AuthorizationDecision;
 use crate::credentials::core::error::CredentialsError;
 use async_trait::async_trait;
 use error_stack::Result;
@@ -8,4 +9,10 @@ use mockall::automock;
 #[async_trait]
 pub trait CredentialsDataAPI: Send + Sync {
     async fn validate_credentials(&self, profile_name: &str) -> Result<bool, CredentialsError>;
+
+    async fn decode_authorization_message(
+        &self,
+        profile_name: &str,
+        encoded_message: &str,
+    ) -> Result<AuthorizationDecision, CredentialsError>;
 }
@@ -0,0 +1,534 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::{watch, RwLock};
+
+use crate::credentials::core::domain::{CredentialsExpiryEvent, TemporaryCredentials};
+use crate::credentials::core::error::CredentialsError;
+use crate::credentials::core::spi::{CredentialsDataSPI, CredentialsEventSPI};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CredentialsRefresherConfig {
+    /// How often the tracked credentials are checked.
+    pub check_interval: Duration,
+    /// Remaining lifetimes at which an `Expiring` event is emitted, e.g. 15, 5 and 1 minutes.
+    pub expiry_thresholds: Vec<Duration>,
+    /// Remaining lifetime below which refreshable credentials are renewed.
+    pub refresh_threshold: Duration,
+    /// Pause before a failed refresh is retried, doubled with every further failure.
+    pub refresh_retry_backoff: Duration,
+    /// Longest pause between two refresh attempts.
+    pub max_refresh_retry_backoff: Duration,
+}
+
+impl Default for CredentialsRefresherConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(30),
+            expiry_thresholds: vec![
+                Duration::from_secs(15 * 60),
+                Duration::from_secs(5 * 60),
+                Duration::from_secs(60),
+            ],
+            refresh_threshold: Duration::from_secs(5 * 60),
+            refresh_retry_backoff: Duration::from_secs(60),
+            max_refresh_retry_backoff: Duration::from_secs(4 * 60),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TrackedCredentials {
+    credentials: TemporaryCredentials,
+    notified_thresholds: Vec<Duration>,
+    expired_notified: bool,
+    /// Failed refreshes since the last successful one.
+    refresh_failures: u32,
+    next_refresh_attempt: Option<DateTime<Utc>>,
+}
+
+impl TrackedCredentials {
+    fn new(credentials: TemporaryCredentials) -> Self {
+        Self {
+            credentials,
+            notified_thresholds: vec![],
+            expired_notified: false,
+            refresh_failures: 0,
+            next_refresh_attempt: None,
+        }
+    }
+}
+
+pub struct CredentialsRefresher {
+    credentials_data_spi: Box<dyn CredentialsDataSPI>,
+    credentials_event_spi: Box<dyn CredentialsEventSPI>,
+    config: CredentialsRefresherConfig,
+    tracked: RwLock<HashMap<String, TrackedCredentials>>,
+    shutdown: watch::Sender<bool>,
+}
+
+impl CredentialsRefresher {
+    pub fn new(
+        credentials_data_spi: Box<dyn CredentialsDataSPI>,
+        credentials_event_spi: Box<dyn CredentialsEventSPI>,
+        config: CredentialsRefresherConfig,
+    ) -> Self {
+        let (shutdown, _) = watch::channel(false);
+
+        Self {
+            credentials_data_spi,
+            credentials_event_spi,
+            config,
+            tracked: RwLock::new(HashMap::new()),
+            shutdown,
+        }
+    }
+
+    /// Starts tracking the credentials of the given profile. Profiles with long-lived
+    /// credentials are ignored, as there is nothing to expire.
+    pub async fn track(&self, profile_name: &str) -> error_stack::Result<(), CredentialsError> {
+        let credentials = self
+            .credentials_data_spi
+            .load_temporary_credentials(profile_name)
+            .await?;
+
+        let mut tracked = self.tracked.write().await;
+        match credentials {
+            Some(credentials) => {
+                tracked.insert(
+                    profile_name.to_string(),
+                    TrackedCredentials::new(credentials),
+                );
+            }
+            None => {
+                tracked.remove(profile_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn untrack(&self, profile_name: &str) {
+        self.tracked.write().await.remove(profile_name);
+    }
+
+    pub async fn tracked_credentials(&self) -> Vec<TemporaryCredentials> {
+        self.tracked
+            .read()
+            .await
+            .values()
+            .map(|tracked| tracked.credentials.clone())
+            .collect()
+    }
+
+    /// Runs the refresh loop until [`CredentialsRefresher::stop`] is called.
+    pub async fn run(&self) {
+        let mut shutdown = self.shutdown.subscribe();
+        let mut interval = tokio::time::interval(self.config.check_interval);
+
+        while !*shutdown.borrow() {
+            tokio::select! {
+                _ = interval.tick() => self.check(Utc::now()).await,
+                _ = shutdown.changed() => break,
+            }
+        }
+
+        tracing::info!("credentials refresher stopped");
+    }
+
+    pub fn stop(&self) {
+        self.shutdown.send_replace(true);
+    }
+
+    /// Performs a single pass over all tracked credentials as of `now`, refreshing renewable
+    /// credentials that are about to expire and emitting events for all others.
+    pub async fn check(&self, now: DateTime<Utc>) {
+        let snapshot: Vec<TrackedCredentials> =
+            self.tracked.read().await.values().cloned().collect();
+
+        for mut tracked in snapshot {
+            let profile_name = tracked.credentials.profile_name.clone();
+
+            if self.is_refresh_due(&tracked, now) {
+                match self.refresh(&tracked.credentials).await {
+                    Ok(refreshed) => {
+                        self.credentials_data_spi
+                            .evict_cached_credentials(&profile_name);
+                        self.credentials_event_spi
+                            .emit(CredentialsExpiryEvent::Refreshed {
+                                profile_name: profile_name.clone(),
+                                expiration: refreshed.expiration,
+                            });
+                        self.replace(&profile_name, TrackedCredentials::new(refreshed))
+                            .await;
+                        continue;
+                    }
+                    Err(reason) => {
+                        tracing::error!("failed to refresh credentials of `{}`", profile_name);
+                        tracked.refresh_failures += 1;
+                        tracked.next_refresh_attempt =
+                            Some(now + self.retry_backoff(tracked.refresh_failures));
+                        // Retries stay silent, the user is told once that renewing failed.
+                        if tracked.refresh_failures == 1 {
+                            self.credentials_event_spi.emit(
+                                CredentialsExpiryEvent::RefreshFailed {
+                                    profile_name: profile_name.clone(),
+                                    expiration: tracked.credentials.expiration,
+                                    reason,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+
+            self.notify_expiry(&mut tracked, now);
+            self.replace(&profile_name, tracked).await;
+        }
+    }
+
+    /// Failed refreshes are retried with a growing pause until the credentials expire.
+    fn is_refresh_due(&self, tracked: &TrackedCredentials, now: DateTime<Utc>) -> bool {
+        tracked.credentials.refreshable
+            && !tracked.credentials.is_expired(now)
+            && !matches!(tracked.next_refresh_attempt, Some(next_attempt) if now < next_attempt)
+            && Self::remaining(&tracked.credentials, now) <= self.config.refresh_threshold
+    }
+
+    fn retry_backoff(&self, failures: u32) -> chrono::Duration {
+        let backoff = self
+            .config
+            .refresh_retry_backoff
+            .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)))
+            .min(self.config.max_refresh_retry_backoff);
+
+        chrono::Duration::milliseconds(backoff.as_millis() as i64)
+    }
+
+    async fn refresh(
+        &self,
+        credentials: &TemporaryCredentials,
+    ) -> Result<TemporaryCredentials, String> {
+        let result = self
+            .credentials_data_spi
+            .load_temporary_credentials(&credentials.profile_name)
+            .await;
+
+        match result {
+            Ok(Some(refreshed)) if refreshed.expiration > credentials.expiration => Ok(refreshed),
+            Ok(_) => Err("credentials were not renewed".to_string()),
+            Err(report) => Err(report.current_context().to_string()),
+        }
+    }
+
+    fn notify_expiry(&self, tracked: &mut TrackedCredentials, now: DateTime<Utc>) {
+        // Renewable credentials are refreshed silently unless the refresh failed.
+        if tracked.credentials.refreshable && tracked.refresh_failures == 0 {
+            return;
+        }
+
+        let credentials = &tracked.credentials;
+        if credentials.is_expired(now) {
+            if !tracked.expired_notified {
+                tracked.expired_notified = true;
+                self.credentials_event_spi
+                    .emit(CredentialsExpiryEvent::Expired {
+                        profile_name: credentials.profile_name.clone(),
+                        expiration: credentials.expiration,
+                    });
+            }
+            return;
+        }
+
+        let remaining = Self::remaining(credentials, now);
+        let crossed: Vec<Duration> = self
+            .config
+            .expiry_thresholds
+            .iter()
+            .filter(|threshold| remaining <= **threshold)
+            .filter(|threshold| !tracked.notified_thresholds.contains(threshold))
+            .copied()
+            .collect();
+
+        if crossed.is_empty() {
+            return;
+        }
+
+        tracked.notified_thresholds.extend(crossed);
+        self.credentials_event_spi
+            .emit(CredentialsExpiryEvent::Expiring {
+                profile_name: credentials.profile_name.clone(),
+                expiration: credentials.expiration,
+                remaining_seconds: credentials.remaining(now).num_seconds(),
+            });
+    }
+
+    async fn replace(&self, profile_name: &str, tracked: TrackedCredentials) {
+        let mut entries = self.tracked.write().await;
+        // Skip profiles that were untracked while the check was in progress.
+        if let Some(entry) = entries.get_mut(profile_name) {
+            *entry = tracked;
+        }
+    }
+
+    fn remaining(credentials: &TemporaryCredentials, now: DateTime<Utc>) -> Duration {
+        credentials
+            .remaining(now)
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+    use error_stack::Report;
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::credentials::core::spi::{MockCredentialsDataSPI, MockCredentialsEventSPI};
+
+    use super::*;
+
+    fn temporary_credentials(
+        now: DateTime<Utc>,
+        remaining: ChronoDuration,
+        refreshable: bool,
+    ) -> TemporaryCredentials {
+        TemporaryCredentials::new("dev".to_string(), now + remaining, refreshable)
+    }
+
+    #[tokio::test]
+    async fn should_not_track_long_lived_credentials() {
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .with(eq("dev"))
+            .returning(|_| Ok(None));
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(MockCredentialsEventSPI::new()),
+            CredentialsRefresherConfig::default(),
+        );
+
+        let result = cut.track("dev").await;
+
+        assert_that!(result).is_ok();
+        assert_that!(cut.tracked_credentials().await).is_empty();
+    }
+
+    #[tokio::test]
+    async fn should_emit_expiring_event_once_per_threshold() {
+        let now = Utc::now();
+        let credentials = temporary_credentials(now, ChronoDuration::minutes(4), false);
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .returning(move |_| Ok(Some(credentials.clone())));
+        let mut credentials_event_spi_mock = MockCredentialsEventSPI::new();
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Expiring { .. }))
+            .times(1)
+            .return_const(());
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(credentials_event_spi_mock),
+            CredentialsRefresherConfig::default(),
+        );
+        cut.track("dev").await.unwrap();
+
+        cut.check(now).await;
+        cut.check(now + ChronoDuration::seconds(30)).await;
+    }
+
+    #[tokio::test]
+    async fn should_emit_expired_event_once() {
+        let now = Utc::now();
+        let credentials = temporary_credentials(now, ChronoDuration::seconds(10), false);
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .returning(move |_| Ok(Some(credentials.clone())));
+        let mut credentials_event_spi_mock = MockCredentialsEventSPI::new();
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Expired { .. }))
+            .times(1)
+            .return_const(());
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(credentials_event_spi_mock),
+            CredentialsRefresherConfig::default(),
+        );
+        cut.track("dev").await.unwrap();
+
+        cut.check(now + ChronoDuration::minutes(1)).await;
+        cut.check(now + ChronoDuration::minutes(2)).await;
+    }
+
+    #[tokio::test]
+    async fn should_refresh_renewable_credentials_before_expiry() {
+        let now = Utc::now();
+        let expiring = temporary_credentials(now, ChronoDuration::minutes(2), true);
+        let renewed = temporary_credentials(now, ChronoDuration::hours(1), true);
+        let expected_expiration = renewed.expiration;
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        let mut sequence = mockall::Sequence::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(Some(expiring.clone())));
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(Some(renewed.clone())));
+        credentials_data_spi_mock
+            .expect_evict_cached_credentials()
+            .with(eq("dev"))
+            .times(1)
+            .return_const(());
+        let mut credentials_event_spi_mock = MockCredentialsEventSPI::new();
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Refreshed { .. }))
+            .times(1)
+            .return_const(());
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(credentials_event_spi_mock),
+            CredentialsRefresherConfig::default(),
+        );
+        cut.track("dev").await.unwrap();
+
+        cut.check(now).await;
+
+        let tracked = cut.tracked_credentials().await;
+        assert_that!(tracked).has_length(1);
+        assert_that!(tracked[0].expiration).is_equal_to(expected_expiration);
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_expiry_events_when_refresh_fails() {
+        let now = Utc::now();
+        let expiring = temporary_credentials(now, ChronoDuration::minutes(2), true);
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        let mut sequence = mockall::Sequence::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(Some(expiring.clone())));
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| {
+                Err(Report::new(CredentialsError::UnexpectedError(
+                    "ExpiredToken".to_string(),
+                )))
+            });
+        let mut credentials_event_spi_mock = MockCredentialsEventSPI::new();
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::RefreshFailed { .. }))
+            .times(1)
+            .return_const(());
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Expiring { .. }))
+            .times(1)
+            .return_const(());
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(credentials_event_spi_mock),
+            CredentialsRefresherConfig::default(),
+        );
+        cut.track("dev").await.unwrap();
+
+        cut.check(now).await;
+        cut.check(now + ChronoDuration::seconds(30)).await;
+    }
+
+    #[tokio::test]
+    async fn should_retry_failed_refresh_after_backoff_until_it_succeeds() {
+        let now = Utc::now();
+        let expiring = temporary_credentials(now, ChronoDuration::minutes(4), true);
+        let renewed = temporary_credentials(now, ChronoDuration::hours(1), true);
+        let mut credentials_data_spi_mock = MockCredentialsDataSPI::new();
+        let mut sequence = mockall::Sequence::new();
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(Some(expiring.clone())));
+        for _ in 0..2 {
+            credentials_data_spi_mock
+                .expect_load_temporary_credentials()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(|_| {
+                    Err(Report::new(CredentialsError::UnexpectedError(
+                        "network unreachable".to_string(),
+                    )))
+                });
+        }
+        credentials_data_spi_mock
+            .expect_load_temporary_credentials()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(Some(renewed.clone())));
+        credentials_data_spi_mock
+            .expect_evict_cached_credentials()
+            .times(1)
+            .return_const(());
+        let mut credentials_event_spi_mock = MockCredentialsEventSPI::new();
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::RefreshFailed { .. }))
+            .times(1)
+            .return_const(());
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Expiring { .. }))
+            .return_const(());
+        credentials_event_spi_mock
+            .expect_emit()
+            .withf(|event| matches!(event, CredentialsExpiryEvent::Refreshed { .. }))
+            .times(1)
+            .return_const(());
+        let cut = CredentialsRefresher::new(
+            Box::new(credentials_data_spi_mock),
+            Box::new(credentials_event_spi_mock),
+            CredentialsRefresherConfig::default(),
+        );
+        cut.track("dev").await.unwrap();
+
+        // Fails, then waits one minute before failing again and two more before succeeding.
+        cut.check(now).await;
+        cut.check(now + ChronoDuration::seconds(30)).await;
+        cut.check(now + ChronoDuration::seconds(60)).await;
+        cut.check(now + ChronoDuration::seconds(150)).await;
+        cut.check(now + ChronoDuration::seconds(180)).await;
+
+        let tracked = cut.tracked_credentials().await;
+        assert_that!(tracked[0].expiration).is_equal_to(now + ChronoDuration::hours(1));
+    }
+
+    #[tokio::test]
+    async fn should_stop_running_refresher() {
+        let cut = CredentialsRefresher::new(
+            Box::new(MockCredentialsDataSPI::new()),
+            Box::new(MockCredentialsEventSPI::new()),
+            CredentialsRefresherConfig::default(),
+        );
+
+        cut.stop();
+        let result = tokio::time::timeout(Duration::from_secs(1), cut.run()).await;
+
+        assert_that!(result).is_ok();
+    }
+}
@@ -4,6 +4,7 @@ use error_stack::Report;
 use mockall::predicate::*;
 
 use crate::credentials::core::api::CredentialsDataAPI;
+use crate::credentials::core::domain::AuthorizationDecision;
 use crate::credentials::core::error::CredentialsError;
 use crate::credentials::core::spi::CredentialsDataSPI;
 
@@ -37,9 +38,27 @@ impl CredentialsDataAPI for CredentialsService {
                 CredentialsError::UnexpectedError(error_code) => Err(Report::new(
                     CredentialsError::UnexpectedError(error_code.to_string()),
                 )),
+                other => Err(Report::new(other.clone())),
             },
         }
     }
+
+    async fn decode_authorization_message(
+        &self,
+        profile_name: &str,
+        encoded_message: &str,
+    ) -> error_stack::Result<AuthorizationDecision, CredentialsError> {
+        let decoded_message = self
+            .credentials_data_spi
+            .decode_authorization_message(profile_name, encoded_message.trim())
+            .await?;
+
+        AuthorizationDecision::from_decoded_message(&decoded_message).map_err(|err| {
+            Report::new(err).change_context(CredentialsError::AuthorizationMessageDecodeError(
+                "malformed decoded message".to_string(),
+            ))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -94,4 +113,47 @@ mod tests {
         let report = result.unwrap_err();
         assert!(report.contains::<CredentialsError>());
     }
+
+    #[tokio::test]
+    async fn should_decode_authorization_message() {
+        let mut credentials_data_api_mock = MockCredentialsDataSPI::new();
+        credentials_data_api_mock
+            .expect_decode_authorization_message()
+            .with(eq("dev"), eq("encoded-message"))
+            .times(1)
+            .returning(|_, _| {
+                Ok(r#"{"allowed":false,"explicitDeny":false,"context":{"action":"ssm:PutParameter"}}"#
+                    .to_string())
+            });
+        let cut = CredentialsService::new(Box::new(credentials_data_api_mock));
+
+        let result = cut
+            .decode_authorization_message("dev", " encoded-message\n")
+            .await;
+
+        assert_that!(result)
+            .is_ok()
+            .map(|decision| &decision.action)
+            .is_equal_to(Some("ssm:PutParameter".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_return_decode_error_when_decoded_message_is_malformed() {
+        let mut credentials_data_api_mock = MockCredentialsDataSPI::new();
+        credentials_data_api_mock
+            .expect_decode_authorization_message()
+            .returning(|_, _| Ok("<html>".to_string()));
+        let cut = CredentialsService::new(Box::new(credentials_data_api_mock));
+
+        let result = cut
+            .decode_authorization_message("dev", "encoded-message")
+            .await;
+
+        assert_that(&result).is_err();
+        assert_that!(CredentialsError::from(result.unwrap_err())).is_equal_to(
+            CredentialsError::AuthorizationMessageDecodeError(
+                "malformed decoded message".to_string(),
+            ),
+        );
+    }
 }
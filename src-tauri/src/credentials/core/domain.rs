@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemporaryCredentials {
+    pub profile_name: String,
+    pub expiration: DateTime<Utc>,
+    pub refreshable: bool,
+}
+
+impl TemporaryCredentials {
+    pub fn new(profile_name: String, expiration: DateTime<Utc>, refreshable: bool) -> Self {
+        Self {
+            profile_name,
+            expiration,
+            refreshable,
+        }
+    }
+
+    pub fn remaining(&self, now: DateTime<Utc>) -> chrono::Duration {
+        self.expiration - now
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expiration <= now
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CredentialsExpiryEvent {
+    Expiring {
+        profile_name: String,
+        expiration: DateTime<Utc>,
+        remaining_seconds: i64,
+    },
+    Expired {
+        profile_name: String,
+        expiration: DateTime<Utc>,
+    },
+    Refreshed {
+        profile_name: String,
+        expiration: DateTime<Utc>,
+    },
+    RefreshFailed {
+        profile_name: String,
+        expiration: DateTime<Utc>,
+        reason: String,
+    },
+}
+
+impl CredentialsExpiryEvent {
+    pub fn profile_name(&self) -> &str {
+        match self {
+            CredentialsExpiryEvent::Expiring { profile_name, .. }
+            | CredentialsExpiryEvent::Expired { profile_name, .. }
+            | CredentialsExpiryEvent::Refreshed { profile_name, .. }
+            | CredentialsExpiryEvent::RefreshFailed { profile_name, .. } => profile_name,
+        }
+    }
+}
+
+/// The decoded form of an "encoded authorization failure message" as returned by
+/// `sts:DecodeAuthorizationMessage`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationDecision {
+    pub allowed: bool,
+    pub explicit_deny: bool,
+    pub matched_statements: Vec<AuthorizationStatement>,
+    pub action: Option<String>,
+    pub resource: Option<String>,
+    pub context: AuthorizationContext,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationStatement {
+    pub statement_id: Option<String>,
+    pub effect: Option<String>,
+    pub principals: Vec<String>,
+    pub actions: Vec<String>,
+    pub resources: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AuthorizationContext {
+    pub principal_id: Option<String>,
+    pub principal_arn: Option<String>,
+    pub conditions: BTreeMap<String, Vec<String>>,
+}
+
+impl AuthorizationDecision {
+    pub fn from_decoded_message(decoded_message: &str) -> serde_json::Result<Self> {
+        let message: DecodedMessage = serde_json::from_str(decoded_message)?;
+
+        Ok(Self {
+            allowed: message.allowed,
+            explicit_deny: message.explicit_deny,
+            matched_statements: message
+                .matched_statements
+                .items
+                .into_iter()
+                .map(|statement| AuthorizationStatement {
+                    statement_id: statement.statement_id,
+                    effect: statement.effect,
+                    principals: statement.principals.values(),
+                    actions: statement.actions.values(),
+                    resources: statement.resources.values(),
+                })
+                .collect(),
+            action: message.context.action,
+            resource: message.context.resource,
+            context: AuthorizationContext {
+                principal_id: message
+                    .context
+                    .principal
+                    .as_ref()
+                    .and_then(|principal| principal.id.clone()),
+                principal_arn: message
+                    .context
+                    .principal
+                    .and_then(|principal| principal.arn),
+                conditions: message
+                    .context
+                    .conditions
+                    .items
+                    .into_iter()
+                    .map(|condition| (condition.key, condition.values.values()))
+                    .collect(),
+            },
+        })
+    }
+}
+
+// STS wraps every list of the decoded message in an `{"items": [...]}` object.
+#[derive(Debug, Deserialize)]
+struct DecodedItems<T> {
+    #[serde(default = "Vec::new")]
+    items: Vec<T>,
+}
+
+impl<T> Default for DecodedItems<T> {
+    fn default() -> Self {
+        Self { items: vec![] }
+    }
+}
+
+impl DecodedItems<DecodedValue> {
+    fn values(self) -> Vec<String> {
+        self.items.into_iter().map(|item| item.value).collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DecodedValue {
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DecodedMessage {
+    allowed: bool,
+    explicit_deny: bool,
+    #[serde(default)]
+    matched_statements: DecodedItems<DecodedStatement>,
+    #[serde(default)]
+    context: DecodedContext,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DecodedStatement {
+    statement_id: Option<String>,
+    effect: Option<String>,
+    #[serde(default)]
+    principals: DecodedItems<DecodedValue>,
+    #[serde(default)]
+    actions: DecodedItems<DecodedValue>,
+    #[serde(default)]
+    resources: DecodedItems<DecodedValue>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DecodedContext {
+    principal: Option<DecodedPrincipal>,
+    action: Option<String>,
+    resource: Option<String>,
+    #[serde(default)]
+    conditions: DecodedItems<DecodedCondition>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DecodedPrincipal {
+    id: Option<String>,
+    arn: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DecodedCondition {
+    key: String,
+    #[serde(default)]
+    values: DecodedItems<DecodedValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use serde_json::json;
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_report_remaining_time_until_expiration() {
+        let now = Utc::now();
+        let cut = TemporaryCredentials::new("dev".to_string(), now + Duration::minutes(5), false);
+
+        let actual = cut.remaining(now);
+
+        assert_that!(actual).is_equal_to(Duration::minutes(5));
+        assert_that!(cut.is_expired(now)).is_false();
+    }
+
+    #[test]
+    fn should_be_expired_when_expiration_has_passed() {
+        let now = Utc::now();
+        let cut = TemporaryCredentials::new("dev".to_string(), now - Duration::seconds(1), true);
+
+        assert_that!(cut.is_expired(now)).is_true();
+    }
+
+    #[test]
+    fn should_serialize_expiring_event_with_kind_tag() {
+        let expiration = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let event = CredentialsExpiryEvent::Expiring {
+            profile_name: "dev".to_string(),
+            expiration,
+            remaining_seconds: 300,
+        };
+
+        let serialized: serde_json::Value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(
+            serialized,
+            json!({
+                "kind": "expiring",
+                "profile_name": "dev",
+                "expiration": "2023-11-14T22:13:20Z",
+                "remaining_seconds": 300
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_decoded_authorization_message() {
+        let decoded_message = json!({
+            "allowed": false,
+            "explicitDeny": true,
+            "matchedStatements": {
+                "items": [{
+                    "statementId": "DenyProdParameters",
+                    "effect": "DENY",
+                    "principals": { "items": [{ "value": "AROAEXAMPLE" }] },
+                    "principalGroups": { "items": [] },
+                    "actions": { "items": [{ "value": "ssm:GetParameters" }] },
+                    "resources": { "items": [{ "value": "arn:aws:ssm:*:*:parameter/prod/*" }] },
+                    "conditions": { "items": [] }
+                }]
+            },
+            "failures": { "items": [] },
+            "context": {
+                "principal": { "id": "AROAEXAMPLE:dev", "arn": "arn:aws:sts::123456789012:assumed-role/dev/dev" },
+                "action": "ssm:GetParameters",
+                "resource": "arn:aws:ssm:eu-west-1:123456789012:parameter/prod/db",
+                "conditions": {
+                    "items": [{
+                        "key": "aws:Region",
+                        "values": { "items": [{ "value": "eu-west-1" }] }
+                    }]
+                }
+            }
+        })
+        .to_string();
+
+        let actual = AuthorizationDecision::from_decoded_message(&decoded_message);
+
+        assert_that!(actual).is_ok();
+        let decision = actual.unwrap();
+        assert_that!(decision.allowed).is_false();
+        assert_that!(decision.explicit_deny).is_true();
+        assert_that!(decision.action).is_equal_to(Some("ssm:GetParameters".to_string()));
+        assert_that!(decision.matched_statements).has_length(1);
+        assert_that!(decision.matched_statements[0].resources)
+            .is_equal_to(vec!["arn:aws:ssm:*:*:parameter/prod/*".to_string()]);
+        assert_that!(decision.context.principal_arn).is_equal_to(Some(
+            "arn:aws:sts::123456789012:assumed-role/dev/dev".to_string(),
+        ));
+        assert_that!(decision.context.conditions.get("aws:Region"))
+            .is_equal_to(Some(&vec!["eu-west-1".to_string()]));
+    }
+
+    #[test]
+    fn should_parse_decoded_authorization_message_without_optional_sections() {
+        let decoded_message = json!({ "allowed": false, "explicitDeny": false }).to_string();
+
+        let actual = AuthorizationDecision::from_decoded_message(&decoded_message);
+
+        assert_that!(actual).is_ok();
+        let decision = actual.unwrap();
+        assert_that!(decision.matched_statements).is_empty();
+        assert_that!(decision.resource).is_none();
+    }
+
+    #[test]
+    fn should_fail_to_parse_malformed_decoded_authorization_message() {
+        let actual = AuthorizationDecision::from_decoded_message("not json");
+
+        assert_that!(actual).is_err();
+    }
+}
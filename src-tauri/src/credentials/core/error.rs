@@ -9,6 +9,7 @@ use serde_json::json;
 pub enum CredentialsError {
     InvalidCredentialsError,
     UnexpectedError(String),
+    AuthorizationMessageDecodeError(String),
 }
 
 impl Display for CredentialsError {
@@ -18,6 +19,9 @@ impl Display for CredentialsError {
             CredentialsError::UnexpectedError(reason) => {
                 write!(f, "unexpected error: {}", reason)
             }
+            CredentialsError::AuthorizationMessageDecodeError(reason) => {
+                write!(f, "failed to decode authorization message: {}", reason)
+            }
         }
     }
 }
@@ -43,6 +47,9 @@ impl Serialize for CredentialsError {
                 CredentialsError::InvalidCredentialsError.to_string(),
             ),
             CredentialsError::UnexpectedError(reason) => ("UnexpectedError", reason.to_string()),
+            CredentialsError::AuthorizationMessageDecodeError(_) => {
+                ("AuthorizationMessageDecodeError", self.to_string())
+            }
         };
         state.serialize_field("error", &json!({ "code": code, "message": message }))?;
         state.end()
@@ -95,4 +102,16 @@ mod tests {
 
         assert_eq!(serialized, expected);
     }
+
+    #[test]
+    fn should_serialize_authorization_message_decode_error_to_json() {
+        let error = CredentialsError::AuthorizationMessageDecodeError(
+            "InvalidAuthorizationMessageException".to_string(),
+        );
+        let expected = json!({ "error": {"code": "AuthorizationMessageDecodeError", "message": "failed to decode authorization message: InvalidAuthorizationMessageException",} }).to_string();
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected);
+    }
 }
@@ -3,10 +3,34 @@ use error_stack::Result;
 #[cfg(test)]
 use mockall::automock;
 
+use crate::credentials::core::domain::{CredentialsExpiryEvent, TemporaryCredentials};
 use crate::credentials::core::error::CredentialsError;
 
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait CredentialsDataSPI: Send + Sync {
     async fn get_caller_identity(&self, profile_name: &str) -> Result<(), CredentialsError>;
+
+    /// Resolves the credentials of the given profile and returns their expiration, or `None`
+    /// when the profile uses long-lived credentials that never expire.
+    async fn load_temporary_credentials(
+        &self,
+        profile_name: &str,
+    ) -> Result<Option<TemporaryCredentials>, CredentialsError>;
+
+    /// Drops clients cached for the profile so that subsequent calls pick up renewed
+    /// credentials.
+    fn evict_cached_credentials(&self, profile_name: &str);
+
+    /// Returns the raw JSON document STS decodes the given authorization failure message into.
+    async fn decode_authorization_message(
+        &self,
+        profile_name: &str,
+        encoded_message: &str,
+    ) -> Result<String, CredentialsError>;
+}
+
+#[cfg_attr(test, automock)]
+pub trait CredentialsEventSPI: Send + Sync {
+    fn emit(&self, event: CredentialsExpiryEvent);
 }
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
+use aws_config::profile::ProfileFileCredentialsProvider;
+use aws_sdk_sts::config::ProvideCredentials;
 use aws_sdk_sts::error::ProvideErrorMetadata;
+use chrono::{DateTime, Utc};
 use error_stack::Report;
 
-use crate::common::aws::{localstack_endpoint, shared_config_loader, sts_client};
+use crate::common::aws::client_factory;
+use crate::credentials::core::domain::TemporaryCredentials;
 use crate::credentials::core::error::CredentialsError;
 use crate::credentials::core::spi::CredentialsDataSPI;
 
@@ -14,21 +18,96 @@ impl CredentialsDataSPI for STSAdapter {
         &self,
         profile_name: &str,
     ) -> error_stack::Result<(), CredentialsError> {
-        let mut shared_config_loader = shared_config_loader(profile_name).await;
+        let client = client_factory()
+            .sts_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(CredentialsError::UnexpectedError(err)))?;
 
-        if let Some(localstack_endpoint) = localstack_endpoint() {
-            shared_config_loader = shared_config_loader
-                .region("us-east-1")
-                .endpoint_url(localstack_endpoint);
+        let result = client.get_caller_identity().send().await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(sdk_error) => {
+                let error_meta = sdk_error.meta();
+                let error_code = error_meta.code();
+                let error_message = error_meta.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                match error_code {
+                    Some("InvalidClientTokenId") => {
+                        Err(Report::new(CredentialsError::InvalidCredentialsError))
+                    }
+                    _ => {
+                        let error_code = error_code.unwrap_or("Server Error");
+                        Err(Report::new(CredentialsError::UnexpectedError(
+                            error_code.to_string(),
+                        )))
+                    }
+                }
+            }
         }
+    }
+
+    async fn load_temporary_credentials(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<Option<TemporaryCredentials>, CredentialsError> {
+        let credentials_provider = ProfileFileCredentialsProvider::builder()
+            .profile_name(profile_name)
+            .build();
 
-        let shared_config = shared_config_loader.load().await;
+        let credentials = credentials_provider
+            .provide_credentials()
+            .await
+            .map_err(|err| {
+                tracing::error!("Error: failed to resolve credentials: {}", err);
 
-        let client = sts_client(&shared_config);
+                Report::new(CredentialsError::UnexpectedError(err.to_string()))
+            })?;
 
-        let result = client.get_caller_identity().send().await;
+        match credentials.expiry() {
+            Some(expiry) => {
+                let expiration: DateTime<Utc> = expiry.into();
+                let refreshable = Self::is_refreshable(profile_name).await;
+
+                Ok(Some(TemporaryCredentials::new(
+                    profile_name.to_string(),
+                    expiration,
+                    refreshable,
+                )))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn evict_cached_credentials(&self, profile_name: &str) {
+        client_factory().invalidate_profile(profile_name);
+    }
+
+    async fn decode_authorization_message(
+        &self,
+        profile_name: &str,
+        encoded_message: &str,
+    ) -> error_stack::Result<String, CredentialsError> {
+        let client = client_factory()
+            .sts_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(CredentialsError::UnexpectedError(err)))?;
+
+        let result = client
+            .decode_authorization_message()
+            .encoded_message(encoded_message)
+            .send()
+            .await;
         match result {
-            Ok(_) => Ok(()),
+            Ok(output) => output
+                .decoded_message()
+                .map(|decoded_message| decoded_message.to_string())
+                .ok_or_else(|| {
+                    Report::new(CredentialsError::AuthorizationMessageDecodeError(
+                        "empty decoded message".to_string(),
+                    ))
+                }),
             Err(sdk_error) => {
                 let error_meta = sdk_error.meta();
                 let error_code = error_meta.code();
@@ -40,6 +119,13 @@ impl CredentialsDataSPI for STSAdapter {
                     Some("InvalidClientTokenId") => {
                         Err(Report::new(CredentialsError::InvalidCredentialsError))
                     }
+                    // Decoding requires `sts:DecodeAuthorizationMessage` and a message issued for
+                    // the same account.
+                    Some("InvalidAuthorizationMessageException") | Some("AccessDenied") => Err(
+                        Report::new(CredentialsError::AuthorizationMessageDecodeError(
+                            error_code.unwrap_or_default().to_string(),
+                        )),
+                    ),
                     _ => {
                         let error_code = error_code.unwrap_or("Server Error");
                         Err(Report::new(CredentialsError::UnexpectedError(
@@ -51,3 +137,173 @@ impl CredentialsDataSPI for STSAdapter {
         }
     }
 }
+
+impl STSAdapter {
+    async fn is_refreshable(profile_name: &str) -> bool {
+        let result = aws_config::profile::load(
+            &Default::default(),
+            &Default::default(),
+            &Default::default(),
+            None,
+        )
+        .await;
+
+        match result {
+            Ok(profile_set) => {
+                let property = |profile_name: &str, key: &str| {
+                    profile_set
+                        .get_profile(profile_name)
+                        .and_then(|profile| profile.get(key))
+                        .map(str::to_string)
+                };
+                can_renew_silently(profile_name, &property, &mut vec![])
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Follows the `source_profile` chain down to the base credentials. They can be renewed
+/// without user interaction unless a profile along the way needs an MFA token, or the chain
+/// ends in a static session token that only the user can replace.
+fn can_renew_silently(
+    profile_name: &str,
+    property: &dyn Fn(&str, &str) -> Option<String>,
+    visited: &mut Vec<String>,
+) -> bool {
+    if visited.iter().any(|visited| visited == profile_name) {
+        return false;
+    }
+    visited.push(profile_name.to_string());
+
+    let has = |key: &str| property(profile_name, key).is_some();
+    if has("mfa_serial") {
+        return false;
+    }
+    // SSO credentials are renewed from the cached token of the SSO session.
+    if has("sso_session") || has("sso_start_url") || has("credential_process") {
+        return true;
+    }
+    if has("role_arn") {
+        if has("credential_source") {
+            return true;
+        }
+        return match property(profile_name, "source_profile") {
+            // A role profile may assume the role with its own static keys.
+            Some(source_profile) if source_profile == profile_name => {
+                has("aws_access_key_id") && !has("aws_session_token")
+            }
+            Some(source_profile) => can_renew_silently(&source_profile, property, visited),
+            None => false,
+        };
+    }
+
+    has("aws_access_key_id") && !has("aws_session_token")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn can_renew(profile_name: &str, profiles: &[(&str, &[(&str, &str)])]) -> bool {
+        let profiles: HashMap<&str, HashMap<&str, &str>> = profiles
+            .iter()
+            .map(|(name, properties)| (*name, properties.iter().copied().collect()))
+            .collect();
+        let property = |profile_name: &str, key: &str| {
+            profiles
+                .get(profile_name)
+                .and_then(|properties| properties.get(key))
+                .map(|value| value.to_string())
+        };
+
+        can_renew_silently(profile_name, &property, &mut vec![])
+    }
+
+    #[test]
+    fn should_renew_role_assumed_with_static_keys_of_source_profile() {
+        let actual = can_renew(
+            "dev",
+            &[
+                ("dev", &[("role_arn", "arn"), ("source_profile", "base")]),
+                ("base", &[("aws_access_key_id", "AKIA")]),
+            ],
+        );
+
+        assert_that!(actual).is_true();
+    }
+
+    #[test]
+    fn should_not_renew_role_whose_source_profile_needs_mfa() {
+        let actual = can_renew(
+            "dev",
+            &[
+                ("dev", &[("role_arn", "arn"), ("source_profile", "base")]),
+                (
+                    "base",
+                    &[
+                        ("role_arn", "arn"),
+                        ("source_profile", "keys"),
+                        ("mfa_serial", "arn"),
+                    ],
+                ),
+                ("keys", &[("aws_access_key_id", "AKIA")]),
+            ],
+        );
+
+        assert_that!(actual).is_false();
+    }
+
+    #[test]
+    fn should_not_renew_role_based_on_static_session_token() {
+        let actual = can_renew(
+            "dev",
+            &[
+                ("dev", &[("role_arn", "arn"), ("source_profile", "session")]),
+                (
+                    "session",
+                    &[
+                        ("aws_access_key_id", "ASIA"),
+                        ("aws_session_token", "token"),
+                    ],
+                ),
+            ],
+        );
+
+        assert_that!(actual).is_false();
+    }
+
+    #[test]
+    fn should_renew_role_with_credential_source_and_sso_profiles() {
+        let profiles: &[(&str, &[(&str, &str)])] = &[
+            (
+                "ec2",
+                &[
+                    ("role_arn", "arn"),
+                    ("credential_source", "Ec2InstanceMetadata"),
+                ],
+            ),
+            ("sso", &[("sso_session", "corp"), ("sso_account_id", "123")]),
+        ];
+
+        assert_that!(can_renew("ec2", profiles)).is_true();
+        assert_that!(can_renew("sso", profiles)).is_true();
+    }
+
+    #[test]
+    fn should_not_renew_profiles_with_source_profile_cycle() {
+        let actual = can_renew(
+            "a",
+            &[
+                ("a", &[("role_arn", "arn"), ("source_profile", "b")]),
+                ("b", &[("role_arn", "arn"), ("source_profile", "a")]),
+            ],
+        );
+
+        assert_that!(actual).is_false();
+    }
+}
@@ -0,0 +1,184 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use error_stack::Report;
+use serde_json::json;
+
+use crate::common::demo::DemoFixture;
+use crate::credentials::core::domain::TemporaryCredentials;
+use crate::credentials::core::error::CredentialsError;
+use crate::credentials::core::spi::CredentialsDataSPI;
+
+/// Credentials of the demo profiles, which are always valid unless the fixture says otherwise.
+pub struct InMemoryCredentialsAdapter {
+    fixture: DemoFixture,
+}
+
+impl InMemoryCredentialsAdapter {
+    pub fn new(fixture: DemoFixture) -> Self {
+        Self { fixture }
+    }
+
+    fn check_injected_error(
+        &self,
+        operation: &str,
+        profile_name: &str,
+    ) -> error_stack::Result<(), CredentialsError> {
+        match self.fixture.injected_error(operation, profile_name) {
+            Some(injected_error) => {
+                let message = injected_error.message();
+                let error = match injected_error.code.as_str() {
+                    "InvalidCredentialsError" => CredentialsError::InvalidCredentialsError,
+                    "AuthorizationMessageDecodeError" => {
+                        CredentialsError::AuthorizationMessageDecodeError(message)
+                    }
+                    _ => CredentialsError::UnexpectedError(message),
+                };
+
+                Err(Report::new(error))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn has_profile(&self, profile_name: &str) -> bool {
+        self.fixture
+            .profiles
+            .iter()
+            .any(|profile| profile.name == profile_name)
+    }
+}
+
+#[async_trait]
+impl CredentialsDataSPI for InMemoryCredentialsAdapter {
+    async fn get_caller_identity(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<(), CredentialsError> {
+        self.check_injected_error("get_caller_identity", profile_name)?;
+
+        if self.has_profile(profile_name) {
+            Ok(())
+        } else {
+            Err(Report::new(CredentialsError::InvalidCredentialsError))
+        }
+    }
+
+    async fn load_temporary_credentials(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<Option<TemporaryCredentials>, CredentialsError> {
+        self.check_injected_error("load_temporary_credentials", profile_name)?;
+
+        let session_minutes = self
+            .fixture
+            .profiles
+            .iter()
+            .find(|profile| profile.name == profile_name)
+            .and_then(|profile| profile.session_minutes);
+
+        Ok(session_minutes.map(|session_minutes| {
+            TemporaryCredentials::new(
+                profile_name.to_string(),
+                Utc::now() + Duration::minutes(session_minutes),
+                true,
+            )
+        }))
+    }
+
+    fn evict_cached_credentials(&self, _profile_name: &str) {}
+
+    async fn decode_authorization_message(
+        &self,
+        profile_name: &str,
+        encoded_message: &str,
+    ) -> error_stack::Result<String, CredentialsError> {
+        self.check_injected_error("decode_authorization_message", profile_name)?;
+
+        // Every encoded message decodes to the same implicit deny of a Parameter Store call.
+        let decoded_message = json!({
+            "allowed": false,
+            "explicitDeny": false,
+            "matchedStatements": { "items": [] },
+            "context": {
+                "principal": {
+                    "id": "AIDADEMO",
+                    "arn": format!("arn:aws:iam::000000000000:user/{}", profile_name)
+                },
+                "action": "ssm:DescribeParameters",
+                "resource": "*",
+                "conditions": {
+                    "items": [
+                        { "key": "demo:EncodedMessage", "values": { "items": [{ "value": encoded_message }] } }
+                    ]
+                }
+            }
+        });
+
+        Ok(decoded_message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::common::demo::{DemoProfile, InjectedError};
+    use crate::credentials::core::domain::AuthorizationDecision;
+
+    use super::*;
+
+    fn fixture() -> DemoFixture {
+        DemoFixture {
+            profiles: vec![DemoProfile {
+                name: "dev".to_string(),
+                region: None,
+                output_format: None,
+                access_key_id: None,
+                secret_access_key: None,
+                session_minutes: Some(30),
+                protection_level: Default::default(),
+            }],
+            errors: vec![InjectedError {
+                operation: "get_caller_identity".to_string(),
+                profile_name: Some("broken".to_string()),
+                code: "UnexpectedError".to_string(),
+                message: Some("Throttling".to_string()),
+            }],
+            ..DemoFixture::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn should_expire_session_after_configured_minutes() {
+        let cut = InMemoryCredentialsAdapter::new(fixture());
+
+        let actual = cut.load_temporary_credentials("dev").await;
+
+        assert_that!(actual).is_ok().is_some();
+        let remaining = actual.unwrap().unwrap().remaining(Utc::now());
+        assert_that!(remaining <= Duration::minutes(30)).is_true();
+    }
+
+    #[tokio::test]
+    async fn should_fail_with_injected_error() {
+        let cut = InMemoryCredentialsAdapter::new(fixture());
+
+        let actual = cut.get_caller_identity("broken").await;
+
+        assert_that!(actual).is_err();
+        let error = CredentialsError::from(actual.unwrap_err());
+        assert_that!(error)
+            .is_equal_to(CredentialsError::UnexpectedError("Throttling".to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_decode_to_parsable_message() {
+        let cut = InMemoryCredentialsAdapter::new(fixture());
+
+        let actual = cut.decode_authorization_message("dev", "abc").await;
+
+        assert_that!(actual).is_ok();
+        let decision = AuthorizationDecision::from_decoded_message(&actual.unwrap());
+        assert_that!(decision).is_ok();
+    }
+}
@@ -0,0 +1 @@
+pub mod credentials_event_adapter;
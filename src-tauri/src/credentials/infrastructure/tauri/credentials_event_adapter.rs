@@ -0,0 +1,24 @@
+use tauri::{AppHandle, Manager};
+
+use crate::credentials::core::domain::CredentialsExpiryEvent;
+use crate::credentials::core::spi::CredentialsEventSPI;
+
+pub const CREDENTIALS_EXPIRY_EVENT: &str = "credentials-expiry";
+
+pub struct TauriCredentialsEventAdapter {
+    app_handle: AppHandle,
+}
+
+impl TauriCredentialsEventAdapter {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl CredentialsEventSPI for TauriCredentialsEventAdapter {
+    fn emit(&self, event: CredentialsExpiryEvent) {
+        if let Err(err) = self.app_handle.emit_all(CREDENTIALS_EXPIRY_EVENT, event) {
+            tracing::error!("Error: failed to emit credentials expiry event: {}", err);
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod inspector_handler;
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use crate::common::aws::api_call_log::ApiCallRecord;
+use crate::inspector::core::api::ApiCallInspectorAPI;
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn get_api_calls(
+    api: tauri::State<'_, Arc<dyn ApiCallInspectorAPI>>,
+    profile_name: Option<String>,
+) -> Vec<ApiCallRecord> {
+    api.get_api_calls(profile_name)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn clear_api_calls(api: tauri::State<'_, Arc<dyn ApiCallInspectorAPI>>) {
+    api.clear_api_calls()
+}
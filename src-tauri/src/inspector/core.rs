@@ -0,0 +1,3 @@
+pub mod api;
+pub mod inspector_service;
+pub mod spi;
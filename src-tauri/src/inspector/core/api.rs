@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::common::aws::api_call_log::ApiCallRecord;
+use crate::inspector::core::spi::ApiCallEventSPI;
+
+#[cfg_attr(test, automock)]
+pub trait ApiCallInspectorAPI: Send + Sync {
+    /// Returns the recorded calls, newest first, optionally only those of a single profile.
+    fn get_api_calls(&self, profile_name: Option<String>) -> Vec<ApiCallRecord>;
+
+    fn clear_api_calls(&self);
+
+    /// Forwards every call recorded from now on to the given event SPI.
+    fn stream_api_calls(&self, event_spi: Arc<dyn ApiCallEventSPI>);
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::common::aws::api_call_log::ApiCallRecord;
+use crate::inspector::core::api::ApiCallInspectorAPI;
+use crate::inspector::core::spi::{ApiCallEventSPI, ApiCallLogSPI};
+
+pub struct InspectorService {
+    api_call_log_spi: Box<dyn ApiCallLogSPI>,
+}
+
+impl InspectorService {
+    pub fn new(api_call_log_spi: Box<dyn ApiCallLogSPI>) -> Self {
+        Self { api_call_log_spi }
+    }
+}
+
+impl ApiCallInspectorAPI for InspectorService {
+    fn get_api_calls(&self, profile_name: Option<String>) -> Vec<ApiCallRecord> {
+        self.api_call_log_spi
+            .load_api_calls()
+            .into_iter()
+            .rev()
+            .filter(|record| {
+                profile_name
+                    .as_ref()
+                    .map_or(true, |profile_name| &record.profile_name == profile_name)
+            })
+            .collect()
+    }
+
+    fn clear_api_calls(&self) {
+        self.api_call_log_spi.clear_api_calls()
+    }
+
+    fn stream_api_calls(&self, event_spi: Arc<dyn ApiCallEventSPI>) {
+        self.api_call_log_spi.subscribe(event_spi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use spectral::prelude::*;
+
+    use crate::inspector::core::spi::MockApiCallLogSPI;
+
+    use super::*;
+
+    fn record(id: u64, profile_name: &str) -> ApiCallRecord {
+        ApiCallRecord {
+            id,
+            started_at: Utc::now(),
+            service: "sts".to_string(),
+            operation: "GetCallerIdentity".to_string(),
+            profile_name: profile_name.to_string(),
+            region: None,
+            endpoint: None,
+            http_status: Some(200),
+            request_id: None,
+            retry_count: 0,
+            latency_ms: 12,
+            success: true,
+        }
+    }
+
+    #[test]
+    fn should_return_api_calls_newest_first() {
+        let mut api_call_log_spi_mock = MockApiCallLogSPI::new();
+        api_call_log_spi_mock
+            .expect_load_api_calls()
+            .returning(|| vec![record(1, "dev"), record(2, "prod")]);
+        let cut = InspectorService::new(Box::new(api_call_log_spi_mock));
+
+        let actual = cut.get_api_calls(None);
+
+        assert_that!(actual.iter().map(|record| record.id).collect::<Vec<_>>())
+            .is_equal_to(vec![2, 1]);
+    }
+
+    #[test]
+    fn should_return_api_calls_of_requested_profile_only() {
+        let mut api_call_log_spi_mock = MockApiCallLogSPI::new();
+        api_call_log_spi_mock
+            .expect_load_api_calls()
+            .returning(|| vec![record(1, "dev"), record(2, "prod"), record(3, "dev")]);
+        let cut = InspectorService::new(Box::new(api_call_log_spi_mock));
+
+        let actual = cut.get_api_calls(Some("dev".to_string()));
+
+        assert_that!(actual.iter().map(|record| record.id).collect::<Vec<_>>())
+            .is_equal_to(vec![3, 1]);
+    }
+}
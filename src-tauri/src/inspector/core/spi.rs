@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+#[cfg(test)]
+use mockall::automock;
+
+use crate::common::aws::api_call_log::ApiCallRecord;
+
+#[cfg_attr(test, automock)]
+pub trait ApiCallLogSPI: Send + Sync {
+    /// Returns the recorded calls, oldest first.
+    fn load_api_calls(&self) -> Vec<ApiCallRecord>;
+
+    fn clear_api_calls(&self);
+
+    fn subscribe(&self, event_spi: Arc<dyn ApiCallEventSPI>);
+}
+
+#[cfg_attr(test, automock)]
+pub trait ApiCallEventSPI: Send + Sync {
+    fn emit(&self, record: ApiCallRecord);
+}
@@ -0,0 +1 @@
+pub mod api_call_log_adapter;
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::common::aws::api_call_log::{api_call_log, ApiCallRecord};
+use crate::inspector::core::spi::{ApiCallEventSPI, ApiCallLogSPI};
+
+/// Reads the log the AWS clients record their calls to.
+pub struct ApiCallLogAdapter;
+
+impl ApiCallLogSPI for ApiCallLogAdapter {
+    fn load_api_calls(&self) -> Vec<ApiCallRecord> {
+        api_call_log().records()
+    }
+
+    fn clear_api_calls(&self) {
+        api_call_log().clear()
+    }
+
+    fn subscribe(&self, event_spi: Arc<dyn ApiCallEventSPI>) {
+        api_call_log().subscribe(move |record| event_spi.emit(record.clone()));
+    }
+}
@@ -0,0 +1 @@
+pub mod api_call_event_adapter;
@@ -0,0 +1,24 @@
+use tauri::{AppHandle, Manager};
+
+use crate::common::aws::api_call_log::ApiCallRecord;
+use crate::inspector::core::spi::ApiCallEventSPI;
+
+pub const API_CALL_EVENT: &str = "api-call";
+
+pub struct TauriApiCallEventAdapter {
+    app_handle: AppHandle,
+}
+
+impl TauriApiCallEventAdapter {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl ApiCallEventSPI for TauriApiCallEventAdapter {
+    fn emit(&self, record: ApiCallRecord) {
+        if let Err(err) = self.app_handle.emit_all(API_CALL_EVENT, record) {
+            tracing::error!("Error: failed to emit api call event: {}", err);
+        }
+    }
+}
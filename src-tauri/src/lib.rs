@@ -1,4 +1,9 @@
+pub mod accounts;
 pub mod common;
+pub mod console;
 pub mod credentials;
+pub mod inspector;
 pub mod parameters;
+pub mod permissions;
 pub mod profiles;
+pub mod settings;
@@ -1,32 +1,121 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use backend::__cmd__add_parameter_tags;
+use backend::__cmd__check_permissions;
+use backend::__cmd__clear_api_calls;
+use backend::__cmd__compare_parameter_versions;
+use backend::__cmd__create_console_signin_url;
 use backend::__cmd__create_profile;
+use backend::__cmd__decode_authorization_message;
+use backend::__cmd__delete_parameter;
+use backend::__cmd__delete_parameters;
 use backend::__cmd__delete_profile;
 use backend::__cmd__delete_profiles;
 use backend::__cmd__edit_profile;
+use backend::__cmd__export_parameters;
+use backend::__cmd__get_action_bundles;
+use backend::__cmd__get_api_calls;
 use backend::__cmd__get_available_parameters;
+use backend::__cmd__get_available_parameters_page;
+use backend::__cmd__get_client_defaults;
+use backend::__cmd__get_effective_client_settings;
+use backend::__cmd__get_parameter_across_regions;
+use backend::__cmd__get_parameter_history;
+use backend::__cmd__get_parameter_labels;
+use backend::__cmd__get_parameter_metadata;
+use backend::__cmd__get_parameter_tags;
+use backend::__cmd__get_parameter_tree;
 use backend::__cmd__get_parameters;
+use backend::__cmd__get_profile_accounts;
 use backend::__cmd__get_profiles;
+use backend::__cmd__get_protection_levels;
+use backend::__cmd__get_tracked_credentials;
+use backend::__cmd__import_parameters;
+use backend::__cmd__label_parameter_version;
+use backend::__cmd__plan_import;
+use backend::__cmd__remove_parameter_tags;
+use backend::__cmd__resolve_account;
+use backend::__cmd__restore_parameter_version;
+use backend::__cmd__set_client_defaults;
 use backend::__cmd__set_parameter;
+use backend::__cmd__set_protection_level;
+use backend::__cmd__unlabel_parameter_version;
 use backend::__cmd__validate_credentials;
-use backend::credentials::application::tauri::credentials_handler::validate_credentials;
+use backend::accounts::application::tauri::account_handler::{
+    get_profile_accounts, resolve_account,
+};
+use backend::accounts::core::account_service::AccountService;
+use backend::accounts::core::api::AccountDataAPI;
+use backend::accounts::infrastructure::aws::account::account_adapter::AccountAdapter;
+use backend::accounts::infrastructure::json::json_account_cache_adapter::JsonAccountCacheAdapter;
+use backend::common::demo::{demo_mode_requested, load_demo_fixture, DemoFixture, DEMO_ENV_VAR};
+use backend::console::application::tauri::console_handler::create_console_signin_url;
+use backend::console::core::api::ConsoleSignInAPI;
+use backend::console::core::console_service::ConsoleService;
+use backend::console::core::domain::ConsoleSignInConfig;
+use backend::console::infrastructure::aws::sts::federation_adapter::FederationAdapter;
+use backend::console::infrastructure::http::signin_token_adapter::SignInTokenAdapter;
+use backend::credentials::application::tauri::credentials_handler::{
+    decode_authorization_message, get_tracked_credentials, validate_credentials,
+};
 use backend::credentials::core::api::CredentialsDataAPI;
+use backend::credentials::core::credentials_refresher::{
+    CredentialsRefresher, CredentialsRefresherConfig,
+};
 use backend::credentials::core::credentials_service::CredentialsService;
+use backend::credentials::core::spi::CredentialsDataSPI;
 use backend::credentials::infrastructure::aws::sts::sts_adapter::STSAdapter;
+use backend::credentials::infrastructure::memory::in_memory_credentials_adapter::InMemoryCredentialsAdapter;
+use backend::credentials::infrastructure::tauri::credentials_event_adapter::TauriCredentialsEventAdapter;
+use backend::inspector::application::tauri::inspector_handler::{clear_api_calls, get_api_calls};
+use backend::inspector::core::api::ApiCallInspectorAPI;
+use backend::inspector::core::inspector_service::InspectorService;
+use backend::inspector::infrastructure::memory::api_call_log_adapter::ApiCallLogAdapter;
+use backend::inspector::infrastructure::tauri::api_call_event_adapter::TauriApiCallEventAdapter;
 use backend::parameters::application::tauri::parameters_handler::{
-    get_available_parameters, get_parameters, set_parameter,
+    add_parameter_tags, compare_parameter_versions, delete_parameter, delete_parameters,
+    export_parameters, get_available_parameters, get_available_parameters_page,
+    get_parameter_across_regions, get_parameter_history, get_parameter_labels,
+    get_parameter_metadata, get_parameter_tags, get_parameter_tree, get_parameters,
+    import_parameters, label_parameter_version, plan_import, remove_parameter_tags,
+    restore_parameter_version, set_parameter, unlabel_parameter_version,
 };
-use backend::parameters::core::api::ParameterDataAPI;
+use backend::parameters::core::api::{ParameterDataAPI, ParameterExportAPI, ParameterImportAPI};
+use backend::parameters::core::export_service::ExportService;
+use backend::parameters::core::import_service::{ImportConfig, ImportService};
 use backend::parameters::core::parameter_service::ParameterService;
+use backend::parameters::core::spi::ParameterDataSPI;
 use backend::parameters::infrastructure::aws::ssm::parameter_store_adapter::ParameterStoreAdapter;
+use backend::parameters::infrastructure::file::local_file_adapter::LocalFileAdapter;
+use backend::parameters::infrastructure::memory::in_memory_parameter_adapter::InMemoryParameterAdapter;
+use backend::permissions::application::tauri::permissions_handler::{
+    check_permissions, get_action_bundles,
+};
+use backend::permissions::core::api::PermissionDataAPI;
+use backend::permissions::core::permission_service::PermissionService;
+use backend::permissions::infrastructure::aws::iam::policy_simulation_adapter::PolicySimulationAdapter;
 use backend::profiles::application::tauri::profile_handler::{
     create_profile, delete_profile, delete_profiles, edit_profile, get_profiles,
+    get_protection_levels, set_protection_level,
 };
 use backend::profiles::core::api::ProfileDataAPI;
 use backend::profiles::core::profile_service::ProfileService;
+use backend::profiles::core::protection_guard::ProfileProtectionGuard;
+use backend::profiles::core::spi::{ProfileDataSPI, ProfileProtectionSPI};
 use backend::profiles::infrastructure::aws::sdk_config::sdk_config_adapter::SdkConfigAdapter;
+use backend::profiles::infrastructure::json::json_profile_protection_adapter::JsonProfileProtectionAdapter;
+use backend::profiles::infrastructure::memory::in_memory_profile_adapter::InMemoryProfileAdapter;
+use backend::profiles::infrastructure::memory::in_memory_profile_protection_adapter::InMemoryProfileProtectionAdapter;
+use backend::settings::application::tauri::settings_handler::{
+    get_client_defaults, get_effective_client_settings, set_client_defaults,
+};
+use backend::settings::core::api::SettingsAPI;
+use backend::settings::core::settings_service::SettingsService;
+use backend::settings::infrastructure::aws::sdk_config::client_settings_adapter::ClientSettingsAdapter;
+use backend::settings::infrastructure::json::json_settings_adapter::JsonSettingsAdapter;
 use std::sync::Arc;
+use tauri::{Manager, RunEvent};
 
 #[allow(unused_assignments)]
 #[cfg(not(tarpaulin_include))]
@@ -55,27 +144,164 @@ fn main() {
         builder = builder.plugin(log_plugin);
     }
 
-    let profile_data_spi = SdkConfigAdapter;
-    let profile_data_api = ProfileService::new(Box::new(profile_data_spi));
-    let credentials_data_api = CredentialsService::new(Box::new(STSAdapter));
-    let parameter_data_spi = ParameterStoreAdapter;
-    let parameter_data_api = ParameterService::new(Box::new(parameter_data_spi));
+    let settings_api = SettingsService::new(
+        Box::new(JsonSettingsAdapter::default()),
+        Box::new(ClientSettingsAdapter),
+    );
+    // Clients must not be created before the stored defaults are in place.
+    if let Err(report) = settings_api.apply_settings() {
+        tracing::error!("Error: failed to apply stored settings: {:?}", report);
+    }
+
+    let demo_fixture = demo_mode_requested(std::env::args(), std::env::var(DEMO_ENV_VAR).ok())
+        .then(|| load_demo_fixture().expect("demo fixture should be valid"));
+
+    // Shared, so that confirmation tokens and protection levels are the same for all services.
+    let protection_guard = Arc::new(ProfileProtectionGuard::new(profile_protection_spi(
+        &demo_fixture,
+    )));
+    let profile_data_api =
+        ProfileService::new(profile_data_spi(&demo_fixture), protection_guard.clone());
+    let credentials_data_api = CredentialsService::new(credentials_data_spi(&demo_fixture));
+    // Clones share their parameters, so demo changes show up in every service.
+    let demo_parameters = demo_fixture
+        .as_ref()
+        .map(|demo_fixture| InMemoryParameterAdapter::new(demo_fixture.clone()));
+    let parameter_data_api = ParameterService::new(
+        parameter_data_spi(&demo_parameters),
+        protection_guard.clone(),
+    );
+    let parameter_export_api = ExportService::new(
+        parameter_data_spi(&demo_parameters),
+        Box::new(LocalFileAdapter),
+    );
+    let parameter_import_api = ImportService::new(
+        parameter_data_spi(&demo_parameters),
+        Box::new(LocalFileAdapter),
+        protection_guard,
+        ImportConfig::default(),
+    );
+    let account_data_api = AccountService::new(
+        Box::new(AccountAdapter),
+        Box::new(JsonAccountCacheAdapter::default()),
+    );
+    let permission_data_api = PermissionService::new(Box::new(PolicySimulationAdapter));
+    let api_call_inspector_api: Arc<dyn ApiCallInspectorAPI> =
+        Arc::new(InspectorService::new(Box::new(ApiCallLogAdapter)));
+    let console_signin_api = ConsoleService::new(
+        Box::new(FederationAdapter),
+        Box::new(SignInTokenAdapter),
+        ConsoleSignInConfig::default(),
+    );
 
     builder
         .manage(Arc::new(profile_data_api) as Arc<dyn ProfileDataAPI>)
         .manage(Arc::new(credentials_data_api) as Arc<dyn CredentialsDataAPI>)
         .manage(Arc::new(parameter_data_api) as Arc<dyn ParameterDataAPI>)
+        .manage(Arc::new(parameter_export_api) as Arc<dyn ParameterExportAPI>)
+        .manage(Arc::new(parameter_import_api) as Arc<dyn ParameterImportAPI>)
+        .manage(Arc::new(account_data_api) as Arc<dyn AccountDataAPI>)
+        .manage(Arc::new(permission_data_api) as Arc<dyn PermissionDataAPI>)
+        .manage(Arc::new(console_signin_api) as Arc<dyn ConsoleSignInAPI>)
+        .manage(Arc::new(settings_api) as Arc<dyn SettingsAPI>)
+        .manage(api_call_inspector_api.clone())
+        .setup(move |app| {
+            let credentials_refresher = Arc::new(CredentialsRefresher::new(
+                credentials_data_spi(&demo_fixture),
+                Box::new(TauriCredentialsEventAdapter::new(app.handle())),
+                CredentialsRefresherConfig::default(),
+            ));
+            app.manage(credentials_refresher.clone());
+
+            tauri::async_runtime::spawn(async move { credentials_refresher.run().await });
+
+            api_call_inspector_api
+                .stream_api_calls(Arc::new(TauriApiCallEventAdapter::new(app.handle())));
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_profiles,
             create_profile,
             edit_profile,
             delete_profile,
+            get_protection_levels,
+            set_protection_level,
             validate_credentials,
+            get_tracked_credentials,
+            decode_authorization_message,
             delete_profiles,
             get_available_parameters,
+            get_available_parameters_page,
             get_parameters,
+            get_parameter_metadata,
+            get_parameter_history,
+            get_parameter_labels,
+            compare_parameter_versions,
             set_parameter,
+            restore_parameter_version,
+            label_parameter_version,
+            unlabel_parameter_version,
+            get_parameter_tags,
+            add_parameter_tags,
+            remove_parameter_tags,
+            delete_parameter,
+            delete_parameters,
+            get_parameter_across_regions,
+            get_parameter_tree,
+            export_parameters,
+            plan_import,
+            import_parameters,
+            resolve_account,
+            get_profile_accounts,
+            check_permissions,
+            get_action_bundles,
+            create_console_signin_url,
+            get_client_defaults,
+            set_client_defaults,
+            get_effective_client_settings,
+            get_api_calls,
+            clear_api_calls,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let RunEvent::Exit = event {
+                app_handle.state::<Arc<CredentialsRefresher>>().stop();
+            }
+        });
+}
+
+#[cfg(not(tarpaulin_include))]
+fn profile_data_spi(demo_fixture: &Option<DemoFixture>) -> Box<dyn ProfileDataSPI> {
+    match demo_fixture {
+        Some(demo_fixture) => Box::new(InMemoryProfileAdapter::new(demo_fixture.clone())),
+        None => Box::new(SdkConfigAdapter),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn profile_protection_spi(demo_fixture: &Option<DemoFixture>) -> Box<dyn ProfileProtectionSPI> {
+    match demo_fixture {
+        Some(demo_fixture) => Box::new(InMemoryProfileProtectionAdapter::new(demo_fixture)),
+        None => Box::new(JsonProfileProtectionAdapter::default()),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn credentials_data_spi(demo_fixture: &Option<DemoFixture>) -> Box<dyn CredentialsDataSPI> {
+    match demo_fixture {
+        Some(demo_fixture) => Box::new(InMemoryCredentialsAdapter::new(demo_fixture.clone())),
+        None => Box::new(STSAdapter),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+fn parameter_data_spi(
+    demo_parameters: &Option<InMemoryParameterAdapter>,
+) -> Box<dyn ParameterDataSPI> {
+    match demo_parameters {
+        Some(demo_parameters) => Box::new(demo_parameters.clone()),
+        None => Box::new(ParameterStoreAdapter),
+    }
 }
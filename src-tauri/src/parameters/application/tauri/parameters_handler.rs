@@ -1,8 +1,17 @@
 use std::sync::Arc;
 
-use crate::parameters::core::api::{ParameterDataAPI, SetParameterRequest};
-use crate::parameters::core::domain::ParameterSet;
+use crate::parameters::core::api::{
+    ParameterDataAPI, ParameterExportAPI, ParameterImportAPI, SetParameterRequest,
+};
+use crate::parameters::core::diff_service::ParameterVersionDiff;
+use crate::parameters::core::domain::{
+    ParameterDeletion, ParameterFilters, ParameterHistory, ParameterInfo, ParameterLabel,
+    ParameterLabelUpdate, ParameterNamePage, ParameterRegionComparison, ParameterSet, ParameterTag,
+    ParameterTree,
+};
 use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::export_service::{ExportRequest, ExportSummary};
+use crate::parameters::core::import_service::{ImportItemResult, ImportPlan, ImportRequest};
 
 #[derive(serde::Serialize)]
 pub struct GetAvailableParametersResponse {
@@ -14,23 +23,120 @@ pub struct GetAvailableParametersResponse {
 pub async fn get_available_parameters(
     api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
     profile_name: String,
+    region: Option<String>,
 ) -> Result<GetAvailableParametersResponse, ParameterDataError> {
-    let result = api.get_available_parameters(profile_name.as_str()).await;
+    let result = api
+        .get_available_parameters(profile_name.as_str(), region)
+        .await;
 
     result
         .map(|names| GetAvailableParametersResponse { names })
         .map_err(ParameterDataError::from)
 }
 
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_available_parameters_page(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    region: Option<String>,
+    filters: Option<ParameterFilters>,
+    cursor: Option<String>,
+    page_size: Option<i32>,
+) -> Result<ParameterNamePage, ParameterDataError> {
+    let result = api
+        .get_available_parameters_page(
+            profile_name.as_str(),
+            region,
+            filters.unwrap_or_default(),
+            cursor,
+            page_size,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
 #[tauri::command]
 #[cfg(not(tarpaulin_include))]
 pub async fn get_parameters(
     api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
     profile_name: String,
     parameter_names: Vec<String>,
+    region: Option<String>,
 ) -> Result<ParameterSet, ParameterDataError> {
     let result = api
-        .get_parameters(profile_name.as_str(), parameter_names)
+        .get_parameters(profile_name.as_str(), region, parameter_names)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_metadata(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_names: Vec<String>,
+    region: Option<String>,
+) -> Result<Vec<ParameterInfo>, ParameterDataError> {
+    let result = api
+        .get_parameter_metadata(profile_name.as_str(), region, parameter_names)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_history(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    region: Option<String>,
+) -> Result<ParameterHistory, ParameterDataError> {
+    let result = api
+        .get_parameter_history(profile_name.as_str(), region, parameter_name)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_labels(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    region: Option<String>,
+) -> Result<Vec<ParameterLabel>, ParameterDataError> {
+    let result = api
+        .get_parameter_labels(profile_name.as_str(), region, parameter_name)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn compare_parameter_versions(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    from_version: i64,
+    to_version: i64,
+    region: Option<String>,
+    reveal_secure_values: Option<bool>,
+) -> Result<ParameterVersionDiff, ParameterDataError> {
+    let result = api
+        .compare_parameter_versions(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            from_version,
+            to_version,
+            reveal_secure_values.unwrap_or(false),
+        )
         .await;
 
     result.map_err(ParameterDataError::from)
@@ -42,10 +148,279 @@ pub async fn set_parameter(
     api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
     profile_name: String,
     request: SetParameterRequest,
+    region: Option<String>,
+    confirmation_token: Option<String>,
 ) -> Result<(), ParameterDataError> {
     let result = api
-        .set_parameter(profile_name.as_str(), request)
+        .set_parameter(profile_name.as_str(), region, request, confirmation_token)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn restore_parameter_version(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    version: i64,
+    expected_version: i64,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<(), ParameterDataError> {
+    let result = api
+        .restore_parameter_version(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            version,
+            expected_version,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn label_parameter_version(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    version: i64,
+    labels: Vec<String>,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<ParameterLabelUpdate, ParameterDataError> {
+    let result = api
+        .label_parameter_version(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            version,
+            labels,
+            confirmation_token,
+        )
         .await;
 
     result.map_err(ParameterDataError::from)
-}
\ No newline at end of file
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn unlabel_parameter_version(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    version: i64,
+    labels: Vec<String>,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<ParameterLabelUpdate, ParameterDataError> {
+    let result = api
+        .unlabel_parameter_version(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            version,
+            labels,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_tags(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    region: Option<String>,
+) -> Result<Vec<ParameterTag>, ParameterDataError> {
+    let result = api
+        .get_parameter_tags(profile_name.as_str(), region, parameter_name)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn add_parameter_tags(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    tags: Vec<ParameterTag>,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<(), ParameterDataError> {
+    let result = api
+        .add_parameter_tags(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            tags,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn remove_parameter_tags(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    tag_keys: Vec<String>,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<(), ParameterDataError> {
+    let result = api
+        .remove_parameter_tags(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            tag_keys,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn delete_parameter(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<ParameterDeletion, ParameterDataError> {
+    let result = api
+        .delete_parameter(
+            profile_name.as_str(),
+            region,
+            parameter_name,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn delete_parameters(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_names: Vec<String>,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<Vec<ParameterDeletion>, ParameterDataError> {
+    let result = api
+        .delete_parameters(
+            profile_name.as_str(),
+            region,
+            parameter_names,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_across_regions(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    parameter_name: String,
+    regions: Vec<String>,
+) -> Result<ParameterRegionComparison, ParameterDataError> {
+    let result = api
+        .get_parameter_across_regions(profile_name.as_str(), parameter_name, regions)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_parameter_tree(
+    api: tauri::State<'_, Arc<dyn ParameterDataAPI>>,
+    profile_name: String,
+    path: String,
+    recursive: Option<bool>,
+    region: Option<String>,
+) -> Result<ParameterTree, ParameterDataError> {
+    let result = api
+        .get_parameter_tree(
+            profile_name.as_str(),
+            region,
+            path,
+            recursive.unwrap_or(false),
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn export_parameters(
+    api: tauri::State<'_, Arc<dyn ParameterExportAPI>>,
+    profile_name: String,
+    request: ExportRequest,
+    region: Option<String>,
+) -> Result<ExportSummary, ParameterDataError> {
+    let result = api
+        .export_parameters(profile_name.as_str(), region, request)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn plan_import(
+    api: tauri::State<'_, Arc<dyn ParameterImportAPI>>,
+    profile_name: String,
+    request: ImportRequest,
+    region: Option<String>,
+) -> Result<ImportPlan, ParameterDataError> {
+    let result = api
+        .plan_import(profile_name.as_str(), region, request)
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn import_parameters(
+    api: tauri::State<'_, Arc<dyn ParameterImportAPI>>,
+    profile_name: String,
+    request: ImportRequest,
+    plan_fingerprint: String,
+    region: Option<String>,
+    confirmation_token: Option<String>,
+) -> Result<Vec<ImportItemResult>, ParameterDataError> {
+    let result = api
+        .import_parameters(
+            profile_name.as_str(),
+            region,
+            request,
+            plan_fingerprint,
+            confirmation_token,
+        )
+        .await;
+
+    result.map_err(ParameterDataError::from)
+}
@@ -1,5 +1,8 @@
 pub mod api;
+pub mod diff_service;
 pub mod domain;
 pub mod error;
+pub mod export_service;
+pub mod import_service;
 pub mod parameter_service;
 pub mod spi;
@@ -1,17 +1,44 @@
-use crate::parameters::core::domain::ParameterSet;
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::diff_service::ParameterVersionDiff;
+use crate::parameters::core::domain::{
+    ParameterDeletion, ParameterFilters, ParameterHistory, ParameterInfo, ParameterLabel,
+    ParameterLabelUpdate, ParameterNamePage, ParameterRegionComparison, ParameterSet, ParameterTag,
+    ParameterTier, ParameterTree, WriteMode,
+};
 use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::export_service::{ExportRequest, ExportSummary};
+use crate::parameters::core::import_service::{ImportItemResult, ImportPlan, ImportRequest};
 use async_trait::async_trait;
 #[cfg(test)]
 use mockall::automock;
-use serde::{Deserialize, Serialize};
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::Formatter;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SetParameterRequest {
     pub name: String,
-    #[serde(flatten)]
     pub value: Value,
     #[serde(default)]
     pub secure: Option<bool>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tier: Option<ParameterTier>,
+    /// KMS key for secure parameters, the AWS managed key is used if not set.
+    #[serde(default)]
+    pub key_id: Option<String>,
+    #[serde(default)]
+    pub allowed_pattern: Option<String>,
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub mode: WriteMode,
+    /// The version the user edited. If the parameter has moved on since, the write is refused.
+    #[serde(default)]
+    pub expected_version: Option<i64>,
+    #[serde(default)]
+    pub tags: Vec<ParameterTag>,
 }
 
 impl SetParameterRequest {
@@ -20,6 +47,14 @@ impl SetParameterRequest {
             name,
             value,
             secure,
+            description: None,
+            tier: None,
+            key_id: None,
+            allowed_pattern: None,
+            data_type: None,
+            mode: WriteMode::default(),
+            expected_version: None,
+            tags: Vec::new(),
         }
     }
 }
@@ -30,30 +65,320 @@ impl From<(String, Value)> for SetParameterRequest {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Single values are kept in a secure string, as whether they are secret is only known once the
+/// whole request has been read.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Value {
-    Single(String),
+    Single(SecureString),
     Multiple(Vec<String>),
 }
 
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a string or a list of strings")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Value::Single(SecureString::from(v)))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Value::Single(SecureString::from(v)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element::<String>()? {
+            values.push(value);
+        }
+
+        Ok(Value::Multiple(values))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Not derived, since untagged enums buffer the content and thereby copy the secret.
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait ParameterDataAPI: Send + Sync {
+    /// Names may select a version or label, like `/app/db-url:3` or `/app/db-url:live`.
     async fn get_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter_names: Vec<String>,
     ) -> error_stack::Result<ParameterSet, ParameterDataError>;
 
+    async fn get_parameter_metadata(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError>;
+
     async fn get_available_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
     ) -> error_stack::Result<Vec<String>, ParameterDataError>;
 
+    /// Pages through the names matching the filters, so results can be shown before the whole
+    /// account has been scanned.
+    async fn get_available_parameters_page(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError>;
+
+    async fn get_parameter_history(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterHistory, ParameterDataError>;
+
+    /// Which version each label of the parameter points to.
+    async fn get_parameter_labels(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterLabel>, ParameterDataError>;
+
+    /// Compares two versions from the history of the parameter. Secure values are redacted
+    /// unless `reveal_secure_values` is set.
+    async fn compare_parameter_versions(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        from_version: i64,
+        to_version: i64,
+        reveal_secure_values: bool,
+    ) -> error_stack::Result<ParameterVersionDiff, ParameterDataError>;
+
+    /// Refused for protected profiles, unless the profile only asks for confirmation and the
+    /// token handed out with the refusal is passed. In create-only mode, existing parameters are
+    /// left untouched and reported as already existing. With an expected version, a parameter
+    /// that has been changed or deleted in the meantime is not overwritten. Tags are added once
+    /// the value has been written.
     async fn set_parameter(
         &self,
         profile_name: &str,
+        region: Option<String>,
         request: SetParameterRequest,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    /// Puts the value of an earlier version again, with the type, KMS key and tier it had back
+    /// then, which makes it the new latest version. Guarded like `set_parameter`, and refused
+    /// with the newer version if the latest version is no longer `expected_version`.
+    async fn restore_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        expected_version: i64,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    /// Moves the labels to the version. Refused for protected profiles like writes.
+    async fn label_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError>;
+
+    async fn unlabel_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError>;
+
+    async fn get_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterTag>, ParameterDataError>;
+
+    /// Adds the tags or updates their values. Refused for protected profiles like writes.
+    async fn add_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tags: Vec<ParameterTag>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    async fn remove_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tag_keys: Vec<String>,
+        confirmation_token: Option<String>,
     ) -> error_stack::Result<(), ParameterDataError>;
+
+    /// Guarded like `set_parameter`.
+    async fn delete_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterDeletion, ParameterDataError>;
+
+    async fn delete_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<Vec<ParameterDeletion>, ParameterDataError>;
+
+    /// Loads the parameter from each of the regions, so they can be compared side by side.
+    async fn get_parameter_across_regions(
+        &self,
+        profile_name: &str,
+        parameter_name: String,
+        regions: Vec<String>,
+    ) -> error_stack::Result<ParameterRegionComparison, ParameterDataError>;
+
+    /// Returns the sub-paths and parameters directly below `path`, without decrypting any value.
+    /// Without `recursive`, the sub-paths are left unexpanded, so large hierarchies can be
+    /// browsed one level at a time.
+    async fn get_parameter_tree(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<ParameterTree, ParameterDataError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ParameterExportAPI: Send + Sync {
+    /// Writes the parameters below `request.path` to `request.file_path`, secure values are
+    /// redacted unless the request includes them.
+    async fn export_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ExportRequest,
+    ) -> error_stack::Result<ExportSummary, ParameterDataError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ParameterImportAPI: Send + Sync {
+    /// Shows what importing the file would change, without writing anything.
+    async fn plan_import(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ImportRequest,
+    ) -> error_stack::Result<ImportPlan, ParameterDataError>;
+
+    /// Plans the import again and applies it, unless the plan no longer has the fingerprint of
+    /// the reviewed one. Failing items do not stop the others, each one is reported with its
+    /// outcome.
+    async fn import_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ImportRequest,
+        plan_fingerprint: String,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<Vec<ImportItemResult>, ParameterDataError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_deserialize_secure_set_parameter_request_without_revealing_secret_in_debug_output() {
+        let payload = serde_json::json!({
+            "name": "/app/db-password",
+            "value": "super_secret_password",
+            "secure": true
+        });
+
+        let actual: SetParameterRequest = serde_json::from_value(payload).unwrap();
+
+        assert_that!(actual.value)
+            .is_equal_to(Value::Single(SecureString::from("super_secret_password")));
+        assert!(!format!("{:?}", actual).contains("super_secret_password"));
+    }
+
+    #[test]
+    fn should_deserialize_set_parameter_request_with_string_list() {
+        let payload = r#"{"name": "/app/hosts", "value": ["a", "b"]}"#;
+
+        let actual: SetParameterRequest = serde_json::from_str(payload).unwrap();
+
+        assert_that!(actual.value)
+            .is_equal_to(Value::Multiple(vec!["a".to_string(), "b".to_string()]));
+        assert_that!(actual.secure).is_none();
+        assert_that!(actual.mode).is_equal_to(WriteMode::Overwrite);
+    }
+
+    #[test]
+    fn should_deserialize_set_parameter_request_with_options() {
+        let payload = r#"{
+            "name": "/app/db-password",
+            "value": "secret",
+            "secure": true,
+            "tier": "Advanced",
+            "key_id": "alias/app",
+            "mode": "createOnly",
+            "expected_version": 4
+        }"#;
+
+        let actual: SetParameterRequest = serde_json::from_str(payload).unwrap();
+
+        assert_that!(actual.tier).is_equal_to(Some(ParameterTier::Advanced));
+        assert_that!(actual.key_id).is_equal_to(Some("alias/app".to_string()));
+        assert_that!(actual.mode).is_equal_to(WriteMode::CreateOnly);
+        assert_that!(actual.expected_version).is_equal_to(Some(4));
+    }
 }
@@ -0,0 +1,420 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use similar::{ChangeTag, TextDiff};
+
+use crate::parameters::core::domain::{ParameterValue, ParameterVersion};
+use crate::parameters::core::error::ParameterDataError;
+
+const REDACTED_LINE: &str = "<redacted>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LineChangeKind {
+    Unchanged,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LineChange {
+    pub kind: LineChangeKind,
+    pub line: String,
+    /// Line numbers start at 1 and are missing on the side that does not have the line.
+    pub old_line_number: Option<usize>,
+    pub new_line_number: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonChange {
+    /// JSON pointer to the value, e.g. `/database/port`. The whole document is an empty pointer.
+    pub path: String,
+    pub kind: JsonChangeKind,
+    pub old_value: Option<JsonValue>,
+    pub new_value: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "format", rename_all = "camelCase")]
+pub enum ValueDiff {
+    Text { lines: Vec<LineChange> },
+    Json { changes: Vec<JsonChange> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParameterVersionDiff {
+    pub name: String,
+    pub from_version: i64,
+    pub to_version: i64,
+    pub value: ValueDiff,
+    /// Names of the metadata fields that differ between the versions, e.g. `description`.
+    pub changed_metadata: Vec<String>,
+    /// Whether the lines of secure values were replaced, so only their positions are shown.
+    pub redacted: bool,
+}
+
+/// Secure values are redacted unless `reveal_secure_values` is set.
+pub fn diff_versions(
+    name: &str,
+    from: &ParameterVersion,
+    to: &ParameterVersion,
+    reveal_secure_values: bool,
+) -> Result<ParameterVersionDiff, ParameterDataError> {
+    let old_metadata = &from.metadata;
+    let new_metadata = &to.metadata;
+    let changed_metadata = [
+        (
+            "description",
+            old_metadata.description != new_metadata.description,
+        ),
+        ("tier", old_metadata.tier != new_metadata.tier),
+        ("dataType", old_metadata.data_type != new_metadata.data_type),
+        ("keyId", old_metadata.key_id != new_metadata.key_id),
+        (
+            "allowedPattern",
+            old_metadata.allowed_pattern != new_metadata.allowed_pattern,
+        ),
+        ("policies", old_metadata.policies != new_metadata.policies),
+    ]
+    .into_iter()
+    .filter(|(_, changed)| *changed)
+    .map(|(field, _)| field.to_string())
+    .collect();
+
+    let redacted = !reveal_secure_values && (is_secure(&from.value) || is_secure(&to.value));
+
+    Ok(ParameterVersionDiff {
+        name: name.to_string(),
+        from_version: from.version,
+        to_version: to.version,
+        value: diff_values(&from.value, &to.value, redacted)?,
+        changed_metadata,
+        redacted,
+    })
+}
+
+fn is_secure(value: &ParameterValue) -> bool {
+    matches!(value, ParameterValue::SecureString(_))
+}
+
+/// Compares JSON objects and arrays structurally, so reformatting or reordering keys does not
+/// show up as a change. Everything else is compared line by line. Redacted values are always
+/// compared line by line and no line is copied into the diff.
+pub fn diff_values(
+    old: &ParameterValue,
+    new: &ParameterValue,
+    redact: bool,
+) -> Result<ValueDiff, ParameterDataError> {
+    let old_text = value_text(old)?;
+    let new_text = value_text(new)?;
+
+    if redact {
+        return Ok(ValueDiff::Text {
+            lines: diff_lines(&old_text, &new_text, true),
+        });
+    }
+
+    match (
+        parse_structured_json(&old_text),
+        parse_structured_json(&new_text),
+    ) {
+        (Some(old_json), Some(new_json)) => {
+            let mut changes = vec![];
+            diff_json("", &old_json, &new_json, &mut changes);
+            Ok(ValueDiff::Json { changes })
+        }
+        _ => Ok(ValueDiff::Text {
+            lines: diff_lines(&old_text, &new_text, false),
+        }),
+    }
+}
+
+/// String lists are compared item by item, one item per line.
+fn value_text(value: &ParameterValue) -> Result<Cow<'_, str>, ParameterDataError> {
+    match value {
+        ParameterValue::String(value) => Ok(Cow::Borrowed(value)),
+        ParameterValue::StringList(values) => Ok(Cow::Owned(values.join("\n"))),
+        ParameterValue::SecureString(value) => {
+            value.try_as_str().map(Cow::Borrowed).map_err(|_| {
+                ParameterDataError::InvalidParameter(
+                    "secure string values should be valid UTF-8".to_string(),
+                )
+            })
+        }
+    }
+}
+
+fn parse_structured_json(text: &str) -> Option<JsonValue> {
+    serde_json::from_str::<JsonValue>(text)
+        .ok()
+        .filter(|json| json.is_object() || json.is_array())
+}
+
+fn diff_lines(old: &str, new: &str, redact: bool) -> Vec<LineChange> {
+    TextDiff::from_lines(old, new)
+        .iter_all_changes()
+        .map(|change| LineChange {
+            kind: match change.tag() {
+                ChangeTag::Equal => LineChangeKind::Unchanged,
+                ChangeTag::Delete => LineChangeKind::Removed,
+                ChangeTag::Insert => LineChangeKind::Added,
+            },
+            line: if redact {
+                REDACTED_LINE.to_string()
+            } else {
+                change
+                    .value()
+                    .trim_end_matches(|c| c == '\n' || c == '\r')
+                    .to_string()
+            },
+            old_line_number: change.old_index().map(|index| index + 1),
+            new_line_number: change.new_index().map(|index| index + 1),
+        })
+        .collect()
+}
+
+fn diff_json(path: &str, old: &JsonValue, new: &JsonValue, changes: &mut Vec<JsonChange>) {
+    match (old, new) {
+        (JsonValue::Object(old_object), JsonValue::Object(new_object)) => {
+            let keys: BTreeSet<&String> = old_object.keys().chain(new_object.keys()).collect();
+            for key in keys {
+                let child_path = format!("{}/{}", path, escape_pointer_token(key));
+                diff_json_child(
+                    child_path,
+                    old_object.get(key),
+                    new_object.get(key),
+                    changes,
+                );
+            }
+        }
+        (JsonValue::Array(old_array), JsonValue::Array(new_array)) => {
+            for index in 0..old_array.len().max(new_array.len()) {
+                let child_path = format!("{}/{}", path, index);
+                diff_json_child(
+                    child_path,
+                    old_array.get(index),
+                    new_array.get(index),
+                    changes,
+                );
+            }
+        }
+        _ if old != new => changes.push(JsonChange {
+            path: path.to_string(),
+            kind: JsonChangeKind::Changed,
+            old_value: Some(old.clone()),
+            new_value: Some(new.clone()),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_json_child(
+    path: String,
+    old: Option<&JsonValue>,
+    new: Option<&JsonValue>,
+    changes: &mut Vec<JsonChange>,
+) {
+    match (old, new) {
+        (Some(old), Some(new)) => diff_json(&path, old, new, changes),
+        (Some(old), None) => changes.push(JsonChange {
+            path,
+            kind: JsonChangeKind::Removed,
+            old_value: Some(old.clone()),
+            new_value: None,
+        }),
+        (None, Some(new)) => changes.push(JsonChange {
+            path,
+            kind: JsonChangeKind::Added,
+            old_value: None,
+            new_value: Some(new.clone()),
+        }),
+        (None, None) => {}
+    }
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use spectral::prelude::*;
+
+    use crate::common::secure_string::SecureString;
+    use crate::parameters::core::domain::{ParameterMetadata, ParameterTier};
+
+    use super::*;
+
+    fn version(version: i64, value: ParameterValue) -> ParameterVersion {
+        ParameterVersion {
+            version,
+            value,
+            last_modified_date: None,
+            labels: vec![],
+            metadata: ParameterMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn should_diff_text_line_by_line() {
+        let old = ParameterValue::String("host=db\nport=5432\n".to_string());
+        let new = ParameterValue::String("host=db\nport=6432\n".to_string());
+
+        let actual = diff_values(&old, &new, false).unwrap();
+
+        let ValueDiff::Text { lines } = actual else {
+            panic!("expected a text diff");
+        };
+        let kinds: Vec<LineChangeKind> = lines.iter().map(|line| line.kind).collect();
+        assert_that!(kinds).is_equal_to(vec![
+            LineChangeKind::Unchanged,
+            LineChangeKind::Removed,
+            LineChangeKind::Added,
+        ]);
+        assert_that!(lines[1].line).is_equal_to("port=5432".to_string());
+        assert_that!(lines[2].new_line_number).is_equal_to(Some(2));
+    }
+
+    #[test]
+    fn should_diff_json_structurally() {
+        let old =
+            ParameterValue::String(r#"{"port": 5432, "hosts": ["a"], "ssl": true}"#.to_string());
+        let new = ParameterValue::String(
+            "{\n  \"hosts\": [\"a\", \"b\"],\n  \"port\": 6432,\n  \"user/name\": \"app\"\n}"
+                .to_string(),
+        );
+
+        let actual = diff_values(&old, &new, false).unwrap();
+
+        assert_that!(actual).is_equal_to(ValueDiff::Json {
+            changes: vec![
+                JsonChange {
+                    path: "/hosts/1".to_string(),
+                    kind: JsonChangeKind::Added,
+                    old_value: None,
+                    new_value: Some(json!("b")),
+                },
+                JsonChange {
+                    path: "/port".to_string(),
+                    kind: JsonChangeKind::Changed,
+                    old_value: Some(json!(5432)),
+                    new_value: Some(json!(6432)),
+                },
+                JsonChange {
+                    path: "/ssl".to_string(),
+                    kind: JsonChangeKind::Removed,
+                    old_value: Some(json!(true)),
+                    new_value: None,
+                },
+                JsonChange {
+                    path: "/user~1name".to_string(),
+                    kind: JsonChangeKind::Added,
+                    old_value: None,
+                    new_value: Some(json!("app")),
+                },
+            ],
+        });
+    }
+
+    #[test]
+    fn should_fall_back_to_line_diff_when_only_one_value_is_json() {
+        let old = ParameterValue::String("42".to_string());
+        let new = ParameterValue::String(r#"{"answer": 42}"#.to_string());
+
+        let actual = diff_values(&old, &new, false).unwrap();
+
+        assert!(matches!(actual, ValueDiff::Text { .. }));
+    }
+
+    #[test]
+    fn should_list_changed_metadata_of_versions() {
+        let from = version(1, ParameterValue::StringList(vec!["a".to_string()]));
+        let mut to = version(
+            2,
+            ParameterValue::StringList(vec!["a".to_string(), "b".to_string()]),
+        );
+        to.metadata = ParameterMetadata {
+            description: Some("Hosts".to_string()),
+            tier: Some(ParameterTier::Advanced),
+            ..ParameterMetadata::default()
+        };
+
+        let actual = diff_versions("/app/hosts", &from, &to, false).unwrap();
+
+        assert_that!(actual.changed_metadata)
+            .is_equal_to(vec!["description".to_string(), "tier".to_string()]);
+        assert_that!(actual.from_version).is_equal_to(1);
+        assert_that!(actual.to_version).is_equal_to(2);
+    }
+
+    #[test]
+    fn should_redact_secure_values_unless_revealed() {
+        let from = version(
+            1,
+            ParameterValue::SecureString(SecureString::from("user=app\npassword=old")),
+        );
+        let to = version(
+            2,
+            ParameterValue::SecureString(SecureString::from("user=app\npassword=new")),
+        );
+
+        let actual = diff_versions("/app/db", &from, &to, false).unwrap();
+
+        assert_that!(actual.redacted).is_true();
+        let ValueDiff::Text { lines } = actual.value else {
+            panic!("expected a text diff");
+        };
+        assert_that!(lines).has_length(3);
+        assert!(lines.iter().all(|line| line.line == "<redacted>"));
+        assert_that!(lines[1].kind).is_equal_to(LineChangeKind::Removed);
+    }
+
+    #[test]
+    fn should_redact_secure_json_values_instead_of_diffing_them_structurally() {
+        let from = version(
+            1,
+            ParameterValue::String(r#"{"password": "old"}"#.to_string()),
+        );
+        let to = version(
+            2,
+            ParameterValue::SecureString(SecureString::from(r#"{"password": "new"}"#)),
+        );
+
+        let actual = diff_versions("/app/db", &from, &to, false).unwrap();
+
+        assert_that!(actual.redacted).is_true();
+        assert!(!format!("{:?}", actual.value).contains("old"));
+        assert!(!format!("{:?}", actual.value).contains("new"));
+    }
+
+    #[test]
+    fn should_reveal_secure_values_when_requested() {
+        let from = version(
+            1,
+            ParameterValue::SecureString(SecureString::from("password=old")),
+        );
+        let to = version(
+            2,
+            ParameterValue::SecureString(SecureString::from("password=new")),
+        );
+
+        let actual = diff_versions("/app/db", &from, &to, true).unwrap();
+
+        assert_that!(actual.redacted).is_false();
+        let ValueDiff::Text { lines } = actual.value else {
+            panic!("expected a text diff");
+        };
+        assert_that!(lines[0].line).is_equal_to("password=old".to_string());
+    }
+}
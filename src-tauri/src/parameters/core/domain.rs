@@ -1,7 +1,10 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::common::secure_string::SecureString;
+use crate::parameters::core::error::ParameterDataError;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -11,6 +14,92 @@ pub enum ParameterValue {
     SecureString(SecureString),
 }
 
+impl ParameterValue {
+    /// Size in bytes as counted by Parameter Store, string lists are stored comma separated.
+    pub fn size(&self) -> usize {
+        match self {
+            ParameterValue::String(value) => value.len(),
+            ParameterValue::StringList(values) => {
+                values.iter().map(String::len).sum::<usize>() + values.len().saturating_sub(1)
+            }
+            ParameterValue::SecureString(value) => value.expose(|bytes| bytes.len()),
+        }
+    }
+
+    pub fn parameter_type(&self) -> ParameterType {
+        match self {
+            ParameterValue::String(_) => ParameterType::String,
+            ParameterValue::StringList(_) => ParameterType::StringList,
+            ParameterValue::SecureString(_) => ParameterType::SecureString,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParameterType {
+    String,
+    StringList,
+    SecureString,
+}
+
+impl ParameterType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterType::String => "String",
+            ParameterType::StringList => "StringList",
+            ParameterType::SecureString => "SecureString",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParameterTier {
+    Standard,
+    Advanced,
+    #[serde(rename = "Intelligent-Tiering")]
+    IntelligentTiering,
+}
+
+impl ParameterTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterTier::Standard => "Standard",
+            ParameterTier::Advanced => "Advanced",
+            ParameterTier::IntelligentTiering => "Intelligent-Tiering",
+        }
+    }
+}
+
+/// Whether writing a parameter may replace an existing one with a new version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WriteMode {
+    CreateOnly,
+    #[default]
+    Overwrite,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterPolicy {
+    pub policy_type: Option<String>,
+    pub policy_text: Option<String>,
+    pub policy_status: Option<String>,
+}
+
+/// Everything Parameter Store knows about a parameter besides its value. GetParameters only
+/// reports the data type, the remaining fields come from DescribeParameters.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParameterMetadata {
+    pub description: Option<String>,
+    pub tier: Option<ParameterTier>,
+    pub data_type: Option<String>,
+    pub key_id: Option<String>,
+    pub allowed_pattern: Option<String>,
+    pub policies: Vec<ParameterPolicy>,
+    pub last_modified_user: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
@@ -19,6 +108,11 @@ pub struct Parameter {
     // #[serde(with = "ts_milliseconds_option")]
     pub last_modified_date: Option<DateTime<Utc>>,
     pub identifier: Option<String>,
+    /// The version or label the value was requested with, e.g. `live` for `/app/db-url:live`.
+    #[serde(default)]
+    pub selector: Option<String>,
+    #[serde(default)]
+    pub metadata: ParameterMetadata,
 }
 
 impl Parameter {
@@ -35,8 +129,15 @@ impl Parameter {
             version,
             last_modified_date,
             identifier,
+            selector: None,
+            metadata: ParameterMetadata::default(),
         }
     }
+
+    pub fn with_metadata(mut self, metadata: ParameterMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
 }
 
 impl From<(String, ParameterValue)> for Parameter {
@@ -47,6 +148,30 @@ impl From<(String, ParameterValue)> for Parameter {
             version: None,
             last_modified_date: None,
             identifier: None,
+            selector: None,
+            metadata: ParameterMetadata::default(),
+        }
+    }
+}
+
+/// A parameter without its value, for showing who changed what without decrypting anything.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterInfo {
+    pub name: String,
+    pub parameter_type: ParameterType,
+    pub version: Option<i64>,
+    pub last_modified_date: Option<DateTime<Utc>>,
+    pub metadata: ParameterMetadata,
+}
+
+impl From<&Parameter> for ParameterInfo {
+    fn from(parameter: &Parameter) -> Self {
+        ParameterInfo {
+            name: parameter.name.clone(),
+            parameter_type: parameter.value.parameter_type(),
+            version: parameter.version,
+            last_modified_date: parameter.last_modified_date,
+            metadata: parameter.metadata.clone(),
         }
     }
 }
@@ -79,13 +204,408 @@ impl ParameterSet {
     }
 }
 
+/// A single version from the history of a parameter, with the metadata it was written with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterVersion {
+    pub version: i64,
+    pub value: ParameterValue,
+    pub last_modified_date: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub metadata: ParameterMetadata,
+}
+
+impl ParameterVersion {
+    pub fn info(&self, name: &str) -> ParameterInfo {
+        ParameterInfo {
+            name: name.to_string(),
+            parameter_type: self.value.parameter_type(),
+            version: Some(self.version),
+            last_modified_date: self.last_modified_date,
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+impl From<&Parameter> for ParameterVersion {
+    fn from(parameter: &Parameter) -> Self {
+        ParameterVersion {
+            version: parameter.version.unwrap_or_default(),
+            value: parameter.value.clone(),
+            last_modified_date: parameter.last_modified_date,
+            labels: Vec::new(),
+            metadata: parameter.metadata.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterHistory {
+    pub name: String,
+    /// Oldest version first.
+    pub versions: Vec<ParameterVersion>,
+}
+
+impl ParameterHistory {
+    pub fn new(name: String, mut versions: Vec<ParameterVersion>) -> Self {
+        versions.sort_by_key(|version| version.version);
+        Self { name, versions }
+    }
+
+    pub fn version(&self, version: i64) -> Option<&ParameterVersion> {
+        self.versions
+            .iter()
+            .find(|parameter_version| parameter_version.version == version)
+    }
+
+    pub fn latest(&self) -> Option<&ParameterVersion> {
+        self.versions.last()
+    }
+
+    /// Which version each label points to, ordered by label.
+    pub fn labels(&self) -> Vec<ParameterLabel> {
+        let mut labels: Vec<ParameterLabel> = self
+            .versions
+            .iter()
+            .flat_map(|version| {
+                version.labels.iter().map(|label| ParameterLabel {
+                    label: label.clone(),
+                    version: version.version,
+                })
+            })
+            .collect();
+        labels.sort_by(|a, b| a.label.cmp(&b.label));
+        labels
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterLabel {
+    pub label: String,
+    pub version: i64,
+}
+
+/// Outcome of attaching labels to a version or detaching them from it. `labels` are the ones
+/// that were changed, Parameter Store reports the rest as invalid instead of failing the call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterLabelUpdate {
+    pub name: String,
+    pub version: i64,
+    pub labels: Vec<String>,
+    pub invalid_labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSelector {
+    Version(i64),
+    Label(String),
+}
+
+impl VersionSelector {
+    /// Labels may not start with a digit, so numeric selectors always refer to versions.
+    pub fn parse(selector: &str) -> Option<Self> {
+        if !selector.is_empty() && selector.chars().all(|c| c.is_ascii_digit()) {
+            selector.parse().ok().map(VersionSelector::Version)
+        } else if Self::is_valid_label(selector) {
+            Some(VersionSelector::Label(selector.to_string()))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid_label(label: &str) -> bool {
+        let lowercase_label = label.to_ascii_lowercase();
+
+        (1..=100).contains(&label.len())
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+            && !label.starts_with(|c: char| c.is_ascii_digit())
+            && !lowercase_label.starts_with("aws")
+            && !lowercase_label.starts_with("ssm")
+    }
+}
+
+/// A parameter name with an optional `:version` or `:label` suffix, as GetParameters accepts it.
+/// Parameter names cannot contain a colon, so the first one starts the selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterSelector {
+    pub name: String,
+    pub selector: Option<VersionSelector>,
+}
+
+impl ParameterSelector {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.split_once(':') {
+            Some((name, selector)) if !name.is_empty() => Some(Self {
+                name: name.to_string(),
+                selector: Some(VersionSelector::parse(selector)?),
+            }),
+            Some(_) => None,
+            None => Some(Self {
+                name: value.to_string(),
+                selector: None,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DeletionStatus {
+    Deleted,
+    NotFound,
+    Failed { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterDeletion {
+    pub name: String,
+    pub status: DeletionStatus,
+}
+
+impl ParameterDeletion {
+    pub fn new(name: String, status: DeletionStatus) -> Self {
+        Self { name, status }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterTag {
+    pub key: String,
+    pub value: String,
+}
+
+impl ParameterTag {
+    pub fn new(key: String, value: String) -> Self {
+        Self { key, value }
+    }
+}
+
+/// Narrows down the listed parameters. Every filter that is set has to match.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParameterFilters {
+    pub name_prefix: Option<String>,
+    pub name_contains: Option<String>,
+    pub parameter_type: Option<ParameterType>,
+    pub tier: Option<ParameterTier>,
+    pub key_id: Option<String>,
+    pub data_type: Option<String>,
+    pub label: Option<String>,
+    pub tag_key: Option<String>,
+    /// Only used together with `tag_key`. Without it, every parameter with the tag matches.
+    pub tag_value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ParameterNamePage {
+    pub names: Vec<String>,
+    /// Passed back to load the next page, `None` once the last page has been loaded.
+    pub next_cursor: Option<String>,
+}
+
+/// One level of the parameter hierarchy. Sub-paths that have not been loaded yet are not
+/// `expanded`; they are expanded by requesting the tree of the sub-path itself. Parameters are
+/// listed without their values, which are loaded only once a parameter is opened.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterTree {
+    pub path: String,
+    pub sub_paths: Vec<ParameterTree>,
+    pub parameters: Vec<ParameterInfo>,
+    pub expanded: bool,
+}
+
+impl ParameterTree {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: Self::normalize_path(path),
+            sub_paths: Vec::new(),
+            parameters: Vec::new(),
+            expanded: false,
+        }
+    }
+
+    /// Paths always start with a slash and never end with one, except for the root path.
+    pub fn normalize_path(path: &str) -> String {
+        let trimmed = path.trim_matches('/');
+        format!("/{}", trimmed)
+    }
+
+    /// Builds the fully expanded hierarchy below `path` from the parameters of a recursive load.
+    pub fn from_parameters(path: &str, parameters: Vec<ParameterInfo>) -> Self {
+        let mut tree = Self::new(path);
+        let mut descendants: BTreeMap<String, Vec<ParameterInfo>> = BTreeMap::new();
+
+        for parameter in parameters {
+            match child_of(&tree.path, &parameter.name) {
+                Some(PathChild::Parameter) => tree.parameters.push(parameter),
+                Some(PathChild::SubPath(sub_path)) => {
+                    descendants.entry(sub_path).or_default().push(parameter)
+                }
+                None => {}
+            }
+        }
+
+        tree.sub_paths = descendants
+            .into_iter()
+            .map(|(sub_path, parameters)| Self::from_parameters(&sub_path, parameters))
+            .collect();
+        tree.parameters.sort_by(|a, b| a.name.cmp(&b.name));
+        tree.expanded = true;
+
+        tree
+    }
+
+    /// Builds a single level below `path`. The sub-paths are derived from the names in the index
+    /// and are left unexpanded.
+    pub fn from_level(
+        path: &str,
+        parameters: Vec<ParameterInfo>,
+        index: &ParameterPathIndex,
+    ) -> Self {
+        let mut tree = Self::new(path);
+
+        tree.parameters = parameters
+            .into_iter()
+            .filter(|parameter| child_of(&tree.path, &parameter.name) == Some(PathChild::Parameter))
+            .collect();
+        tree.parameters.sort_by(|a, b| a.name.cmp(&b.name));
+        tree.sub_paths = index
+            .sub_paths_of(&tree.path)
+            .iter()
+            .map(|sub_path| Self::new(sub_path))
+            .collect();
+        tree.expanded = true;
+
+        tree
+    }
+}
+
+/// The names of all parameters below `path`, from which the sub-paths of a level are derived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterPathIndex {
+    pub path: String,
+    names: BTreeSet<String>,
+}
+
+impl ParameterPathIndex {
+    pub fn new(path: &str, names: Vec<String>) -> Self {
+        Self {
+            path: ParameterTree::normalize_path(path),
+            names: names.into_iter().collect(),
+        }
+    }
+
+    pub fn sub_paths_of(&self, path: &str) -> BTreeSet<String> {
+        let prefix = path_prefix(path);
+
+        self.names
+            .range(prefix.clone()..)
+            .take_while(|name| name.starts_with(&prefix))
+            .filter_map(|name| match child_of(path, name) {
+                Some(PathChild::SubPath(sub_path)) => Some(sub_path),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn path_prefix(path: &str) -> String {
+    if path == "/" {
+        "/".to_string()
+    } else {
+        format!("{}/", path)
+    }
+}
+
+fn child_of(path: &str, name: &str) -> Option<PathChild> {
+    let prefix = path_prefix(path);
+    let relative = name.strip_prefix(&prefix)?;
+
+    match relative.split_once('/') {
+        Some((segment, rest)) if !segment.is_empty() && !rest.is_empty() => {
+            Some(PathChild::SubPath(format!("{}{}", prefix, segment)))
+        }
+        _ => Some(PathChild::Parameter),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum PathChild {
+    Parameter,
+    SubPath(String),
+}
+
+/// The parameter as found in a single region, `None` if the region does not have it or could not
+/// be queried. The latter is told apart by the error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegionalParameter {
+    pub region: String,
+    pub parameter: Option<Parameter>,
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ParameterDataError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterRegionComparison {
+    pub name: String,
+    pub regions: Vec<RegionalParameter>,
+}
+
+impl ParameterRegionComparison {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            regions: Vec::new(),
+        }
+    }
+
+    pub fn add_region(&mut self, region: String, parameter: Option<Parameter>) {
+        self.regions.push(RegionalParameter {
+            region,
+            parameter,
+            error: None,
+        });
+    }
+
+    pub fn add_failed_region(&mut self, region: String, error: ParameterDataError) {
+        self.regions.push(RegionalParameter {
+            region,
+            parameter: None,
+            error: Some(error),
+        });
+    }
+
+    /// Whether every region has the parameter with the same value. Versions are ignored as they
+    /// are counted per region.
+    pub fn is_in_sync(&self) -> bool {
+        let mut values = self.regions.iter().map(|regional| {
+            regional
+                .parameter
+                .as_ref()
+                .map(|parameter| &parameter.value)
+        });
+
+        match values.next() {
+            Some(Some(first)) => values.all(|value| value == Some(first)),
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::lorem::en::Word;
     use fake::Fake;
     use spectral::prelude::*;
 
-    use crate::parameters::core::domain::{Parameter, ParameterSet, ParameterValue};
+    use crate::common::secure_string::SecureString;
+    use crate::parameters::core::domain::{
+        DeletionStatus, Parameter, ParameterDeletion, ParameterFilters, ParameterHistory,
+        ParameterInfo, ParameterMetadata, ParameterPathIndex, ParameterRegionComparison,
+        ParameterSet, ParameterTier, ParameterTree, ParameterType, ParameterValue,
+        ParameterVersion,
+    };
 
     #[test]
     fn should_create_empty_parameters() {
@@ -214,4 +734,238 @@ mod tests {
         assert_eq!(param.last_modified_date, None);
         assert_eq!(param.identifier, None);
     }
+
+    #[test]
+    fn should_be_in_sync_when_all_regions_have_the_same_value() {
+        let mut cut = ParameterRegionComparison::new("param".to_string());
+        let parameter_in_region = |version| {
+            Parameter::new(
+                "param".to_string(),
+                ParameterValue::String("value".to_string()),
+                Some(version),
+                None,
+                None,
+            )
+        };
+        cut.add_region("eu-west-1".to_string(), Some(parameter_in_region(3)));
+        cut.add_region("eu-central-1".to_string(), Some(parameter_in_region(1)));
+
+        assert_that!(cut.is_in_sync()).is_true();
+    }
+
+    #[test]
+    fn should_not_be_in_sync_when_a_region_misses_the_parameter() {
+        let mut cut = ParameterRegionComparison::new("param".to_string());
+        cut.add_region(
+            "eu-west-1".to_string(),
+            Some(
+                (
+                    "param".to_string(),
+                    ParameterValue::String("value".to_string()),
+                )
+                    .into(),
+            ),
+        );
+        cut.add_region("us-east-1".to_string(), None);
+
+        assert_that!(cut.is_in_sync()).is_false();
+    }
+
+    fn string_parameter(name: &str) -> Parameter {
+        (
+            name.to_string(),
+            ParameterValue::String("value".to_string()),
+        )
+            .into()
+    }
+
+    #[test]
+    fn should_normalize_path() {
+        assert_that!(ParameterTree::normalize_path("app/db/")).is_equal_to("/app/db".to_string());
+        assert_that!(ParameterTree::normalize_path("")).is_equal_to("/".to_string());
+    }
+
+    fn parameter_info(name: &str) -> ParameterInfo {
+        ParameterInfo::from(&string_parameter(name))
+    }
+
+    #[test]
+    fn should_build_expanded_tree_from_recursively_loaded_parameters() {
+        let parameters = vec![
+            parameter_info("/app/db/url"),
+            parameter_info("/app/name"),
+            parameter_info("/app/db/pool/size"),
+        ];
+
+        let actual = ParameterTree::from_parameters("/app", parameters);
+
+        assert_that!(actual.expanded).is_true();
+        assert_that!(actual.parameters).is_equal_to(vec![parameter_info("/app/name")]);
+        assert_that!(actual.sub_paths).has_length(1);
+        let db = &actual.sub_paths[0];
+        assert_that!(db.path).is_equal_to("/app/db".to_string());
+        assert_that!(db.parameters).is_equal_to(vec![parameter_info("/app/db/url")]);
+        assert_that!(db.sub_paths[0].path).is_equal_to("/app/db/pool".to_string());
+        assert_that!(db.sub_paths[0].expanded).is_true();
+    }
+
+    #[test]
+    fn should_build_single_level_with_unexpanded_sub_paths() {
+        let index = ParameterPathIndex::new(
+            "/",
+            vec![
+                "/name".to_string(),
+                "/app/db/url".to_string(),
+                "/app/name".to_string(),
+                "/infra/vpc".to_string(),
+            ],
+        );
+
+        let actual = ParameterTree::from_level("/", vec![parameter_info("/name")], &index);
+
+        assert_that!(actual.parameters).is_equal_to(vec![parameter_info("/name")]);
+        assert_that!(actual.sub_paths).is_equal_to(vec![
+            ParameterTree::new("/app"),
+            ParameterTree::new("/infra"),
+        ]);
+    }
+
+    #[test]
+    fn should_derive_sub_paths_of_nested_level_from_index() {
+        let index = ParameterPathIndex::new(
+            "/",
+            vec![
+                "/app/db/url".to_string(),
+                "/app/db/pool/size".to_string(),
+                "/app/cache/host".to_string(),
+                "/application/name".to_string(),
+            ],
+        );
+
+        let actual = index.sub_paths_of("/app/db");
+
+        assert_that!(actual.into_iter().collect::<Vec<_>>())
+            .is_equal_to(vec!["/app/db/pool".to_string()]);
+    }
+
+    #[test]
+    fn should_ignore_parameters_outside_of_path() {
+        let actual = ParameterTree::from_parameters(
+            "/app",
+            vec![parameter_info("/application/name"), parameter_info("name")],
+        );
+
+        assert_that!(actual.parameters).is_empty();
+        assert_that!(actual.sub_paths).is_empty();
+    }
+
+    #[test]
+    fn should_serialize_failed_deletion_with_reason() {
+        let cut = ParameterDeletion::new(
+            "/app/name".to_string(),
+            DeletionStatus::Failed {
+                reason: "access denied".to_string(),
+            },
+        );
+
+        let actual = serde_json::to_value(&cut).unwrap();
+
+        assert_that!(actual).is_equal_to(serde_json::json!({
+            "name": "/app/name",
+            "status": { "failed": { "reason": "access denied" } }
+        }));
+    }
+
+    #[test]
+    fn should_deserialize_partial_parameter_filters() {
+        let payload = r#"{"name_prefix": "/app", "parameter_type": "SecureString", "tier": "Intelligent-Tiering"}"#;
+
+        let actual: ParameterFilters = serde_json::from_str(payload).unwrap();
+
+        assert_that!(actual).is_equal_to(ParameterFilters {
+            name_prefix: Some("/app".to_string()),
+            parameter_type: Some(ParameterType::SecureString),
+            tier: Some(ParameterTier::IntelligentTiering),
+            ..ParameterFilters::default()
+        });
+    }
+
+    #[test]
+    fn should_describe_parameter_without_value() {
+        let metadata = ParameterMetadata {
+            tier: Some(ParameterTier::Advanced),
+            last_modified_user: Some("arn:aws:iam::000000000000:user/alice".to_string()),
+            ..ParameterMetadata::default()
+        };
+        let parameter = Parameter::new(
+            "/app/password".to_string(),
+            ParameterValue::SecureString(SecureString::from("secret")),
+            Some(3),
+            None,
+            None,
+        )
+        .with_metadata(metadata.clone());
+
+        let actual = ParameterInfo::from(&parameter);
+
+        assert_that!(actual.parameter_type).is_equal_to(ParameterType::SecureString);
+        assert_that!(actual.version).is_equal_to(Some(3));
+        assert_that!(actual.metadata).is_equal_to(metadata);
+        assert!(!serde_json::to_string(&actual).unwrap().contains("secret"));
+    }
+
+    #[test]
+    fn should_order_history_by_version() {
+        let parameter = string_parameter("/app/name");
+        let version = |number: i64| ParameterVersion {
+            version: number,
+            ..ParameterVersion::from(&parameter)
+        };
+
+        let actual = ParameterHistory::new("/app/name".to_string(), vec![version(2), version(1)]);
+
+        assert_that!(actual.versions[0].version).is_equal_to(1);
+        assert_that!(actual.latest().map(|latest| latest.version)).is_equal_to(Some(2));
+        assert_that!(actual.version(3)).is_none();
+    }
+
+    #[test]
+    fn should_list_labels_with_their_versions() {
+        let parameter = string_parameter("/app/name");
+        let version = |number: i64, labels: Vec<&str>| ParameterVersion {
+            version: number,
+            labels: labels.into_iter().map(String::from).collect(),
+            ..ParameterVersion::from(&parameter)
+        };
+        let history = ParameterHistory::new(
+            "/app/name".to_string(),
+            vec![
+                version(1, vec!["stable"]),
+                version(2, vec!["live", "canary"]),
+            ],
+        );
+
+        let actual = history.labels();
+
+        let labels: Vec<(&str, i64)> = actual
+            .iter()
+            .map(|label| (label.label.as_str(), label.version))
+            .collect();
+        assert_that!(labels).is_equal_to(vec![("canary", 2), ("live", 2), ("stable", 1)]);
+    }
+
+    #[test]
+    fn should_parse_parameter_selectors() {
+        let selector = |value: &str| ParameterSelector::parse(value).map(|parsed| parsed.selector);
+
+        assert_that!(selector("/app/db-url")).is_equal_to(Some(None));
+        assert_that!(selector("/app/db-url:3"))
+            .is_equal_to(Some(Some(VersionSelector::Version(3))));
+        assert_that!(selector("/app/db-url:live"))
+            .is_equal_to(Some(Some(VersionSelector::Label("live".to_string()))));
+        assert_that!(selector("/app/db-url:")).is_none();
+        assert_that!(selector("/app/db-url:3rd")).is_none();
+        assert_that!(selector("/app/db-url:aws-live")).is_none();
+        assert_that!(selector(":live")).is_none();
+    }
 }
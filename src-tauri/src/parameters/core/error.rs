@@ -5,6 +5,9 @@ use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 use serde_json::json;
 
+use crate::parameters::core::domain::ParameterInfo;
+use crate::profiles::core::domain::ProtectionDenial;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ParameterDataError {
     ParameterMetaDataLoadError,
@@ -13,6 +16,22 @@ pub enum ParameterDataError {
     UnsupportedParameterType(String),
     UnknownParameterType,
     ParameterDataWriteError(String),
+    ParameterNotFound(String),
+    ParameterAlreadyExists(String),
+    ParameterPatternMismatch(String),
+    ParameterTooLarge(String),
+    ParameterTierMismatch(String),
+    /// Carries the version the parameter has been changed to in the meantime.
+    ConcurrentModification(Box<ParameterInfo>),
+    ParameterFileError(String),
+    /// The file or the parameters changed after the import was planned.
+    ImportPlanChanged,
+    /// Parameter Store kept refusing the request for its rate, even after retrying.
+    Throttled(String),
+    /// The proxy or CA bundle settings of the profile are unusable.
+    ClientConfigurationError(String),
+    EncodedAuthorizationFailure(String),
+    ProfileProtected(ProtectionDenial),
 }
 
 impl Context for ParameterDataError {}
@@ -43,6 +62,45 @@ impl Display for ParameterDataError {
             ParameterDataError::ParameterDataWriteError(reason) => {
                 write!(f, "failed to write parameter data: {}", reason)
             }
+            ParameterDataError::ParameterNotFound(name) => {
+                write!(f, "parameter '{}' not found", name)
+            }
+            ParameterDataError::ParameterAlreadyExists(name) => {
+                write!(f, "parameter '{}' already exists", name)
+            }
+            ParameterDataError::ParameterPatternMismatch(reason) => {
+                write!(f, "value does not match the allowed pattern: {}", reason)
+            }
+            ParameterDataError::ParameterTooLarge(reason) => {
+                write!(f, "value is too large: {}", reason)
+            }
+            ParameterDataError::ParameterTierMismatch(reason) => {
+                write!(f, "parameter tier does not fit: {}", reason)
+            }
+            ParameterDataError::ConcurrentModification(current) => write!(
+                f,
+                "parameter '{}' has been changed in the meantime, it is at version {}",
+                current.name,
+                current.version.unwrap_or_default()
+            ),
+            ParameterDataError::ParameterFileError(reason) => {
+                write!(f, "failed to access parameter file: {}", reason)
+            }
+            ParameterDataError::ImportPlanChanged => write!(
+                f,
+                "the file or the parameters changed since the import was planned, plan it again"
+            ),
+            ParameterDataError::Throttled(reason) => {
+                write!(f, "too many requests: {}", reason)
+            }
+            ParameterDataError::ClientConfigurationError(reason) => {
+                write!(f, "failed to configure client: {}", reason)
+            }
+            ParameterDataError::EncodedAuthorizationFailure(_) => write!(
+                f,
+                "not authorized, decode the authorization failure message for details"
+            ),
+            ParameterDataError::ProfileProtected(denial) => write!(f, "{}", denial),
         }
     }
 }
@@ -65,9 +123,48 @@ impl Serialize for ParameterDataError {
                 ("UnsupportedParameterType", self.to_string())
             }
             ParameterDataError::UnknownParameterType => ("UnknownParameterType", self.to_string()),
-            ParameterDataError::ParameterDataWriteError(reason) => ("ParameterDataWriteError", reason.to_string()),
+            ParameterDataError::ParameterDataWriteError(reason) => {
+                ("ParameterDataWriteError", reason.to_string())
+            }
+            ParameterDataError::ParameterNotFound(_) => ("ParameterNotFound", self.to_string()),
+            ParameterDataError::ParameterAlreadyExists(_) => {
+                ("ParameterAlreadyExists", self.to_string())
+            }
+            ParameterDataError::ParameterPatternMismatch(_) => {
+                ("ParameterPatternMismatch", self.to_string())
+            }
+            ParameterDataError::ParameterTooLarge(_) => ("ParameterTooLarge", self.to_string()),
+            ParameterDataError::ParameterTierMismatch(_) => {
+                ("ParameterTierMismatch", self.to_string())
+            }
+            ParameterDataError::ConcurrentModification(_) => {
+                ("ConcurrentModification", self.to_string())
+            }
+            ParameterDataError::ParameterFileError(_) => ("ParameterFileError", self.to_string()),
+            ParameterDataError::ImportPlanChanged => ("ImportPlanChanged", self.to_string()),
+            ParameterDataError::Throttled(_) => ("Throttled", self.to_string()),
+            ParameterDataError::ClientConfigurationError(_) => {
+                ("ClientConfigurationError", self.to_string())
+            }
+            ParameterDataError::EncodedAuthorizationFailure(_) => {
+                ("EncodedAuthorizationFailure", self.to_string())
+            }
+            ParameterDataError::ProfileProtected(_) => ("ProfileProtected", self.to_string()),
         };
-        state.serialize_field("error", &json!({ "code": code, "message": message }))?;
+        let mut error = json!({ "code": code, "message": message });
+        // Hand the encoded message to the frontend so it can offer to decode it.
+        if let ParameterDataError::EncodedAuthorizationFailure(encoded_message) = self {
+            error["encodedMessage"] = json!(encoded_message);
+        }
+        // Lets the frontend show who changed the parameter before reloading it.
+        if let ParameterDataError::ConcurrentModification(current) = self {
+            error["current"] = json!(current);
+        }
+        // Lets the frontend tell read-only profiles apart and repeat confirmed operations.
+        if let ParameterDataError::ProfileProtected(denial) = self {
+            error["protection"] = json!(denial);
+        }
+        state.serialize_field("error", &error)?;
         state.end()
     }
 }
@@ -77,6 +174,9 @@ mod tests {
     use error_stack::Report;
     use serde_json::json;
 
+    use crate::parameters::core::domain::{ParameterMetadata, ParameterType};
+    use crate::profiles::core::domain::ProtectedOperation;
+
     use super::*;
 
     #[test]
@@ -154,4 +254,119 @@ mod tests {
         });
         assert_eq!(serialized, expected.to_string());
     }
+
+    #[test]
+    fn should_serialize_import_plan_changed() {
+        let error = ParameterDataError::ImportPlanChanged;
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "ImportPlanChanged",
+                "message": "the file or the parameters changed since the import was planned, plan it again"
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
+
+    #[test]
+    fn should_serialize_parameter_already_exists() {
+        let error = ParameterDataError::ParameterAlreadyExists("/app/name".to_string());
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "ParameterAlreadyExists",
+                "message": "parameter '/app/name' already exists"
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
+
+    #[test]
+    fn should_serialize_parameter_not_found() {
+        let error = ParameterDataError::ParameterNotFound("/app/name".to_string());
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "ParameterNotFound",
+                "message": "parameter '/app/name' not found"
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
+
+    #[test]
+    fn should_serialize_parameter_too_large() {
+        let error = ParameterDataError::ParameterTooLarge(
+            "standard parameters hold up to 4096 bytes".to_string(),
+        );
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "ParameterTooLarge",
+                "message": "value is too large: standard parameters hold up to 4096 bytes"
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
+
+    #[test]
+    fn should_serialize_concurrent_modification_with_current_metadata() {
+        let error = ParameterDataError::ConcurrentModification(Box::new(ParameterInfo {
+            name: "/app/name".to_string(),
+            parameter_type: ParameterType::String,
+            version: Some(3),
+            last_modified_date: None,
+            metadata: ParameterMetadata {
+                last_modified_user: Some("arn:aws:iam::000000000000:user/alice".to_string()),
+                ..ParameterMetadata::default()
+            },
+        }));
+        let serialized = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(serialized["error"]["code"], json!("ConcurrentModification"));
+        assert_eq!(
+            serialized["error"]["message"],
+            json!("parameter '/app/name' has been changed in the meantime, it is at version 3")
+        );
+        assert_eq!(serialized["error"]["current"]["version"], json!(3));
+        assert_eq!(
+            serialized["error"]["current"]["metadata"]["last_modified_user"],
+            json!("arn:aws:iam::000000000000:user/alice")
+        );
+    }
+
+    #[test]
+    fn should_serialize_encoded_authorization_failure_with_encoded_message() {
+        let error = ParameterDataError::EncodedAuthorizationFailure("fK1yQ-9x".to_string());
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "EncodedAuthorizationFailure",
+                "message": "not authorized, decode the authorization failure message for details",
+                "encodedMessage": "fK1yQ-9x"
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
+
+    #[test]
+    fn should_serialize_profile_protected_with_protection_details() {
+        let error = ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+            operation: ProtectedOperation::SetParameter,
+            profile_names: vec!["prod".to_string()],
+        });
+        let serialized = serde_json::to_string(&error).unwrap();
+        let expected = json!({
+            "error": {
+                "code": "ProfileProtected",
+                "message": "cannot set parameter, profile 'prod' is read-only",
+                "protection": {
+                    "level": "readOnly",
+                    "operation": "setParameter",
+                    "profileNames": ["prod"]
+                }
+            }
+        });
+        assert_eq!(serialized, expected.to_string());
+    }
 }
@@ -0,0 +1,698 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use async_trait::async_trait;
+use error_stack::Report;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use zeroize::Zeroize;
+
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::api::ParameterExportAPI;
+use crate::parameters::core::domain::{Parameter, ParameterTree, ParameterType, ParameterValue};
+use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::spi::{ParameterDataSPI, ParameterFileSPI};
+
+const REDACTED_VALUE: &str = "<redacted>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportFormat {
+    /// Nested by path segment.
+    Json,
+    /// Nested by path segment, like JSON.
+    Yaml,
+    /// One `NAME="value"` line per parameter, named after the path below the exported one.
+    Dotenv,
+    /// One `name = "value"` variable per parameter, named like for dotenv but in snake case.
+    Tfvars,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportRequest {
+    /// Path of the exported subtree, e.g. `/app/prod`.
+    pub path: String,
+    pub format: ExportFormat,
+    /// Secure values end up in the file as plain text when included.
+    #[serde(default)]
+    pub include_secure_values: bool,
+    pub file_path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSummary {
+    pub file_path: String,
+    pub parameter_count: usize,
+    pub redacted_count: usize,
+}
+
+pub struct ExportService {
+    parameter_data_spi: Box<dyn ParameterDataSPI>,
+    parameter_file_spi: Box<dyn ParameterFileSPI>,
+}
+
+impl ExportService {
+    pub fn new(
+        parameter_data_spi: Box<dyn ParameterDataSPI>,
+        parameter_file_spi: Box<dyn ParameterFileSPI>,
+    ) -> Self {
+        Self {
+            parameter_data_spi,
+            parameter_file_spi,
+        }
+    }
+}
+
+impl ExportService {
+    /// Loads only the values of plain parameters, so no secure value is decrypted when it is
+    /// redacted anyway. Secure parameters are kept with an empty value.
+    async fn load_redacted_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: &str,
+    ) -> error_stack::Result<Vec<Parameter>, ParameterDataError> {
+        let infos = self
+            .parameter_data_spi
+            .load_parameter_infos_by_path(profile_name, region.clone(), path.to_string(), true)
+            .await?;
+        let (secure_infos, plain_infos): (Vec<_>, Vec<_>) = infos
+            .into_iter()
+            .partition(|info| info.parameter_type == ParameterType::SecureString);
+
+        let mut parameters: Vec<Parameter> = secure_infos
+            .into_iter()
+            .map(|info| {
+                Parameter::from((
+                    info.name,
+                    ParameterValue::SecureString(SecureString::from("")),
+                ))
+            })
+            .collect();
+        if !plain_infos.is_empty() {
+            let plain_names = plain_infos.into_iter().map(|info| info.name).collect();
+            let plain_parameters = self
+                .parameter_data_spi
+                .load_parameters(profile_name, region, plain_names)
+                .await?;
+            parameters.extend(plain_parameters.values().iter().cloned());
+        }
+        parameters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(parameters)
+    }
+}
+
+#[async_trait]
+impl ParameterExportAPI for ExportService {
+    async fn export_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ExportRequest,
+    ) -> error_stack::Result<ExportSummary, ParameterDataError> {
+        let path = ParameterTree::normalize_path(&request.path);
+        let parameters = if request.include_secure_values {
+            self.parameter_data_spi
+                .load_parameters_by_path(profile_name, region, path.clone(), true)
+                .await?
+        } else {
+            self.load_redacted_parameters(profile_name, region, &path)
+                .await?
+        };
+
+        let content = render_export(
+            &path,
+            &parameters,
+            request.format,
+            request.include_secure_values,
+        )
+        .map_err(Report::new)?;
+        // Kept in a secure string, so it is wiped once it has been written.
+        self.parameter_file_spi.write_file(
+            &request.file_path,
+            &content,
+            request.include_secure_values,
+        )?;
+
+        let redacted_count = if request.include_secure_values {
+            0
+        } else {
+            parameters
+                .iter()
+                .filter(|parameter| matches!(parameter.value, ParameterValue::SecureString(_)))
+                .count()
+        };
+        Ok(ExportSummary {
+            file_path: request.file_path,
+            parameter_count: parameters.len(),
+            redacted_count,
+        })
+    }
+}
+
+/// Renders the parameters below `path`, named by their path segments relative to it. Secure
+/// values may be included, so every intermediate copy is wiped and the content ends up in a
+/// secure string.
+pub fn render_export(
+    path: &str,
+    parameters: &[Parameter],
+    format: ExportFormat,
+    include_secure_values: bool,
+) -> Result<SecureString, ParameterDataError> {
+    let prefix = if path == "/" {
+        "/".to_string()
+    } else {
+        format!("{}/", path.trim_end_matches('/'))
+    };
+    let mut entries = vec![];
+    for parameter in parameters {
+        let Some(relative_name) = parameter.name.strip_prefix(&prefix) else {
+            continue;
+        };
+        let segments: Vec<&str> = relative_name
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        if segments.is_empty() {
+            continue;
+        }
+        entries.push((
+            parameter.name.as_str(),
+            segments,
+            export_value(&parameter.value, include_secure_values)?,
+        ));
+    }
+
+    let mut content = ExportBuffer::new();
+    match format {
+        ExportFormat::Json => {
+            let document = nest(&entries)?;
+            serde_json::to_writer_pretty(&mut content, &document.0).map_err(|_| {
+                ParameterDataError::ParameterFileError("cannot render JSON".to_string())
+            })?;
+            content.push_str("\n");
+        }
+        ExportFormat::Yaml => {
+            serde_yaml::to_writer(&mut content, &nest(&entries)?.0).map_err(|_| {
+                ParameterDataError::ParameterFileError("cannot render YAML".to_string())
+            })?
+        }
+        ExportFormat::Dotenv => {
+            for (name, value) in flatten(&entries, |name| name.to_shouty_snake_case())? {
+                content.push_str(&name);
+                content.push_str("=");
+                write_dotenv_value(&mut content, value);
+                content.push_str("\n");
+            }
+        }
+        ExportFormat::Tfvars => {
+            for (name, value) in flatten(&entries, |name| name.to_snake_case())? {
+                content.push_str(&name);
+                content.push_str(" = ");
+                write_tfvars_value(&mut content, value);
+                content.push_str("\n");
+            }
+        }
+    }
+
+    Ok(content.into_secure_string())
+}
+
+/// A JSON value whose strings are wiped when it is dropped, as they may be secure values.
+struct WipedJson(JsonValue);
+
+impl Drop for WipedJson {
+    fn drop(&mut self) {
+        wipe(&mut self.0);
+    }
+}
+
+fn wipe(value: &mut JsonValue) {
+    match value {
+        JsonValue::String(text) => text.zeroize(),
+        JsonValue::Array(values) => values.iter_mut().for_each(wipe),
+        JsonValue::Object(object) => object.values_mut().for_each(wipe),
+        _ => {}
+    }
+}
+
+/// Collects the rendered content. A growing `Vec` would leave its old allocation behind
+/// unwiped, so the buffer is grown by hand and the previous one is wiped first.
+struct ExportBuffer {
+    bytes: Vec<u8>,
+}
+
+impl ExportBuffer {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::with_capacity(4 * 1024),
+        }
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.push_bytes(text.as_bytes());
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        let required = self.bytes.len() + bytes.len();
+        if required > self.bytes.capacity() {
+            let mut grown = Vec::with_capacity(required.max(2 * self.bytes.capacity()));
+            grown.extend_from_slice(&self.bytes);
+            self.bytes.zeroize();
+            self.bytes = grown;
+        }
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn into_secure_string(mut self) -> SecureString {
+        SecureString::from(std::mem::take(&mut self.bytes))
+    }
+}
+
+impl Write for ExportBuffer {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.push_bytes(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ExportBuffer {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+fn export_value(
+    value: &ParameterValue,
+    include_secure_values: bool,
+) -> Result<WipedJson, ParameterDataError> {
+    match value {
+        ParameterValue::String(value) => Ok(WipedJson(JsonValue::String(value.clone()))),
+        ParameterValue::StringList(values) => Ok(WipedJson(JsonValue::Array(
+            values.iter().cloned().map(JsonValue::String).collect(),
+        ))),
+        ParameterValue::SecureString(_) if !include_secure_values => {
+            Ok(WipedJson(JsonValue::String(REDACTED_VALUE.to_string())))
+        }
+        ParameterValue::SecureString(value) => value
+            .try_as_str()
+            .map(|value| WipedJson(JsonValue::String(value.to_string())))
+            .map_err(|_| {
+                ParameterDataError::InvalidParameter(
+                    "secure string values should be valid UTF-8".to_string(),
+                )
+            }),
+    }
+}
+
+fn nest(entries: &[(&str, Vec<&str>, WipedJson)]) -> Result<WipedJson, ParameterDataError> {
+    let mut document = WipedJson(JsonValue::Object(Map::new()));
+    for (name, segments, value) in entries {
+        let conflict = || {
+            ParameterDataError::InvalidParameter(format!(
+                "'{}' is both a parameter and a path, export it as dotenv or tfvars instead",
+                name
+            ))
+        };
+        let (last_segment, parent_segments) = segments.split_last().ok_or_else(conflict)?;
+
+        let mut object = document.0.as_object_mut().ok_or_else(conflict)?;
+        for segment in parent_segments {
+            object = object
+                .entry(segment.to_string())
+                .or_insert_with(|| JsonValue::Object(Map::new()))
+                .as_object_mut()
+                .ok_or_else(conflict)?;
+        }
+        if object.contains_key(*last_segment) {
+            return Err(conflict());
+        }
+        object.insert(last_segment.to_string(), value.0.clone());
+    }
+
+    Ok(document)
+}
+
+/// Variable names may not start with a digit, so those get a leading underscore.
+fn flatten<'a>(
+    entries: &'a [(&str, Vec<&str>, WipedJson)],
+    convert_case: impl Fn(&str) -> String,
+) -> Result<BTreeMap<String, &'a JsonValue>, ParameterDataError> {
+    let mut variables = BTreeMap::new();
+    let mut sources: BTreeMap<String, &str> = BTreeMap::new();
+    for (name, segments, value) in entries {
+        let mut variable_name = convert_case(&segments.join("_"));
+        if variable_name.starts_with(|c: char| c.is_ascii_digit()) {
+            variable_name.insert(0, '_');
+        }
+        if let Some(other_name) = sources.insert(variable_name.clone(), *name) {
+            return Err(ParameterDataError::InvalidParameter(format!(
+                "'{}' and '{}' both map to the variable '{}'",
+                other_name, name, variable_name
+            )));
+        }
+        variables.insert(variable_name, &value.0);
+    }
+
+    Ok(variables)
+}
+
+/// Escapes character by character straight into the buffer, so no copy of the value is made.
+fn write_escaped(
+    content: &mut ExportBuffer,
+    text: &str,
+    mut escape: impl FnMut(char) -> Option<&'static str>,
+) {
+    let mut encoded = [0; 4];
+    for c in text.chars() {
+        match escape(c) {
+            Some(escaped) => content.push_str(escaped),
+            None => content.push_str(c.encode_utf8(&mut encoded)),
+        }
+    }
+}
+
+fn write_dotenv_value(content: &mut ExportBuffer, value: &JsonValue) {
+    let escape = |c| match c {
+        '\\' => Some("\\\\"),
+        '"' => Some("\\\""),
+        '\n' => Some("\\n"),
+        '$' => Some("\\$"),
+        _ => None,
+    };
+
+    content.push_str("\"");
+    match value {
+        JsonValue::Array(values) => {
+            for (index, item) in values.iter().filter_map(JsonValue::as_str).enumerate() {
+                if index > 0 {
+                    content.push_str(",");
+                }
+                write_escaped(content, item, escape);
+            }
+        }
+        _ => write_escaped(content, value.as_str().unwrap_or_default(), escape),
+    }
+    content.push_str("\"");
+}
+
+fn write_tfvars_value(content: &mut ExportBuffer, value: &JsonValue) {
+    match value {
+        JsonValue::Array(values) => {
+            content.push_str("[");
+            for (index, item) in values.iter().enumerate() {
+                if index > 0 {
+                    content.push_str(", ");
+                }
+                write_tfvars_value(content, item);
+            }
+            content.push_str("]");
+        }
+        _ => {
+            // Template sequences are escaped, so values are taken literally.
+            let text = value.as_str().unwrap_or_default();
+            let mut next_chars = text.chars().skip(1).chain(std::iter::once(' '));
+            content.push_str("\"");
+            write_escaped(content, text, |c| {
+                let next = next_chars.next();
+                match c {
+                    '\\' => Some("\\\\"),
+                    '"' => Some("\\\""),
+                    '\n' => Some("\\n"),
+                    '\r' => Some("\\r"),
+                    '\t' => Some("\\t"),
+                    '$' if next == Some('{') => Some("$$"),
+                    '%' if next == Some('{') => Some("%%"),
+                    _ => None,
+                }
+            });
+            content.push_str("\"");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::*;
+    use serde_json::json;
+    use spectral::prelude::*;
+
+    use crate::parameters::core::domain::{ParameterInfo, ParameterSet};
+    use crate::parameters::core::spi::{MockParameterDataSPI, MockParameterFileSPI};
+
+    use super::*;
+
+    fn parameters() -> Vec<Parameter> {
+        vec![
+            Parameter::from((
+                "/app/db/hosts".to_string(),
+                ParameterValue::StringList(vec!["a".to_string(), "b".to_string()]),
+            )),
+            Parameter::from((
+                "/app/db/password".to_string(),
+                ParameterValue::SecureString(SecureString::from("secret")),
+            )),
+            Parameter::from((
+                "/app/feature-flags".to_string(),
+                ParameterValue::String("on \"${x}\"".to_string()),
+            )),
+        ]
+    }
+
+    #[test]
+    fn should_nest_parameters_by_path_segment_and_redact_secure_values() {
+        let actual = render_export("/app", &parameters(), ExportFormat::Json, false).unwrap();
+
+        let document: JsonValue = serde_json::from_str(actual.try_as_str().unwrap()).unwrap();
+        assert_that!(document).is_equal_to(json!({
+            "db": {
+                "hosts": ["a", "b"],
+                "password": "<redacted>"
+            },
+            "feature-flags": "on \"${x}\""
+        }));
+    }
+
+    #[test]
+    fn should_render_yaml_with_secure_values_when_included() {
+        let actual = render_export("/app/db", &parameters(), ExportFormat::Yaml, true).unwrap();
+
+        let document: JsonValue = serde_yaml::from_str(actual.try_as_str().unwrap()).unwrap();
+        assert_that!(document).is_equal_to(json!({
+            "hosts": ["a", "b"],
+            "password": "secret"
+        }));
+    }
+
+    #[test]
+    fn should_name_dotenv_variables_after_relative_path() {
+        let actual = render_export("/app/", &parameters(), ExportFormat::Dotenv, false).unwrap();
+
+        assert_that!(actual).is_equal_to(SecureString::from(
+            "DB_HOSTS=\"a,b\"\nDB_PASSWORD=\"<redacted>\"\nFEATURE_FLAGS=\"on \\\"\\${x}\\\"\"\n",
+        ));
+    }
+
+    #[test]
+    fn should_escape_template_sequences_in_tfvars() {
+        let actual = render_export("/app", &parameters(), ExportFormat::Tfvars, false).unwrap();
+
+        assert_that!(actual).is_equal_to(SecureString::from(
+            "db_hosts = [\"a\", \"b\"]\ndb_password = \"<redacted>\"\nfeature_flags = \"on \\\"$${x}\\\"\"\n",
+        ));
+    }
+
+    #[test]
+    fn should_refuse_to_nest_parameter_that_is_also_a_path() {
+        let mut parameters = parameters();
+        parameters.push(Parameter::from((
+            "/app/db".to_string(),
+            ParameterValue::String("x".to_string()),
+        )));
+
+        let actual = render_export("/app", &parameters, ExportFormat::Json, false);
+        let actual_flat = render_export("/app", &parameters, ExportFormat::Dotenv, false);
+
+        assert_that!(actual).is_err();
+        assert_that!(actual_flat).is_ok();
+    }
+
+    fn contains(content: &SecureString, text: &str) -> bool {
+        content
+            .expose_str(|content| content.contains(text))
+            .unwrap_or(false)
+    }
+
+    fn infos() -> Vec<ParameterInfo> {
+        parameters().iter().map(ParameterInfo::from).collect()
+    }
+
+    #[tokio::test]
+    async fn should_export_subtree_to_file_without_decrypting_redacted_values() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app".to_string()),
+                eq(true),
+            )
+            .returning(|_, _, _, _| Ok(infos()));
+        mock_parameter_data_spi
+            .expect_load_parameters()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(vec![
+                    "/app/db/hosts".to_string(),
+                    "/app/feature-flags".to_string(),
+                ]),
+            )
+            .times(1)
+            .returning(|_, _, names| {
+                let mut loaded = ParameterSet::new();
+                loaded.add_all_parameters(
+                    parameters()
+                        .into_iter()
+                        .filter(|parameter| names.contains(&parameter.name))
+                        .collect(),
+                );
+                Ok(loaded)
+            });
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .never();
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        mock_parameter_file_spi
+            .expect_write_file()
+            .withf(|file_path, content, contains_secrets| {
+                file_path == "/tmp/app.env"
+                    && contains(content, "DB_HOSTS=\"a,b\"")
+                    && contains(content, "DB_PASSWORD=\"<redacted>\"")
+                    && !contains_secrets
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let cut = ExportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(mock_parameter_file_spi),
+        );
+
+        let actual = cut
+            .export_parameters(
+                "dev",
+                None,
+                ExportRequest {
+                    path: "app".to_string(),
+                    format: ExportFormat::Dotenv,
+                    include_secure_values: false,
+                    file_path: "/tmp/app.env".to_string(),
+                },
+            )
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(ExportSummary {
+            file_path: "/tmp/app.env".to_string(),
+            parameter_count: 3,
+            redacted_count: 1,
+        });
+    }
+
+    #[tokio::test]
+    async fn should_not_load_any_value_when_all_redacted_parameters_are_secure() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .returning(|_, _, _, _| {
+                Ok(infos()
+                    .into_iter()
+                    .filter(|info| info.parameter_type == ParameterType::SecureString)
+                    .collect())
+            });
+        mock_parameter_data_spi.expect_load_parameters().never();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .never();
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        mock_parameter_file_spi
+            .expect_write_file()
+            .withf(|_, content, contains_secrets| !contains(content, "secret") && !contains_secrets)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let cut = ExportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(mock_parameter_file_spi),
+        );
+
+        let actual = cut
+            .export_parameters(
+                "dev",
+                None,
+                ExportRequest {
+                    path: "app".to_string(),
+                    format: ExportFormat::Json,
+                    include_secure_values: false,
+                    file_path: "/tmp/app.json".to_string(),
+                },
+            )
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(ExportSummary {
+            file_path: "/tmp/app.json".to_string(),
+            parameter_count: 1,
+            redacted_count: 1,
+        });
+    }
+
+    #[tokio::test]
+    async fn should_export_secure_values_to_private_file_when_included() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app".to_string()),
+                eq(true),
+            )
+            .returning(|_, _, _, _| Ok(parameters()));
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        mock_parameter_file_spi
+            .expect_write_file()
+            .withf(|file_path, content, contains_secrets| {
+                file_path == "/tmp/app.env" && contains(content, "secret") && *contains_secrets
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let cut = ExportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(mock_parameter_file_spi),
+        );
+
+        let actual = cut
+            .export_parameters(
+                "dev",
+                None,
+                ExportRequest {
+                    path: "app".to_string(),
+                    format: ExportFormat::Dotenv,
+                    include_secure_values: true,
+                    file_path: "/tmp/app.env".to_string(),
+                },
+            )
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(ExportSummary {
+            file_path: "/tmp/app.env".to_string(),
+            parameter_count: 3,
+            redacted_count: 0,
+        });
+    }
+}
@@ -0,0 +1,1244 @@
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use error_stack::Report;
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::api::ParameterImportAPI;
+use crate::parameters::core::domain::{
+    DeletionStatus, Parameter, ParameterMetadata, ParameterTree, ParameterType, ParameterValue,
+    WriteMode,
+};
+use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::spi::{ParameterDataSPI, ParameterFileSPI};
+use crate::profiles::core::domain::ProtectedOperation;
+use crate::profiles::core::protection_guard::ProfileProtectionGuard;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportFormat {
+    /// Nested objects, each level adds a path segment.
+    Json,
+    /// Nested mappings, like JSON.
+    Yaml,
+    /// `NAME=value` lines, each name becomes a parameter directly below the prefix.
+    Dotenv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportRequest {
+    pub file_path: String,
+    pub format: ImportFormat,
+    /// Path the imported names are placed below, e.g. `/app/prod`.
+    pub prefix: String,
+    /// Type of the imported parameters that have no entry in `types`.
+    pub parameter_type: ParameterType,
+    /// Types of single parameters, by parameter name.
+    #[serde(default)]
+    pub types: BTreeMap<String, ParameterType>,
+    /// Deletes the parameters below the prefix that are missing from the file. For dotenv, only
+    /// the direct children of the prefix are considered.
+    #[serde(default)]
+    pub prune: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportAction {
+    Create,
+    Update,
+    Unchanged,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportPlanItem {
+    pub name: String,
+    pub action: ImportAction,
+    /// The type after the import, or the current type of deleted parameters.
+    pub parameter_type: ParameterType,
+    /// What gets written, kept in the backend so that secure values are not handed out.
+    #[serde(skip)]
+    pub parameter: Option<Parameter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportPlan {
+    pub items: Vec<ImportPlanItem>,
+    /// Covers the planned changes including their values, without revealing them. The import
+    /// is only applied while it still matches the plan the user reviewed.
+    pub fingerprint: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportItemResult {
+    pub name: String,
+    pub action: ImportAction,
+    pub error: Option<ParameterDataError>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportConfig {
+    /// Writes sent at the same time, halved whenever Parameter Store throttles them.
+    pub concurrency: usize,
+    /// Pause before throttled writes are retried, doubled with every further round.
+    pub throttle_backoff: Duration,
+    /// Rounds throttled writes are retried in before they are reported as failed.
+    pub max_throttle_retries: u32,
+}
+
+impl Default for ImportConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            throttle_backoff: Duration::from_secs(1),
+            max_throttle_retries: 3,
+        }
+    }
+}
+
+pub struct ImportService {
+    parameter_data_spi: Box<dyn ParameterDataSPI>,
+    parameter_file_spi: Box<dyn ParameterFileSPI>,
+    protection_guard: Arc<ProfileProtectionGuard>,
+    config: ImportConfig,
+    /// Keys the plan fingerprints, so they cannot be matched against guessed values.
+    fingerprint_state: RandomState,
+}
+
+impl ImportService {
+    pub fn new(
+        parameter_data_spi: Box<dyn ParameterDataSPI>,
+        parameter_file_spi: Box<dyn ParameterFileSPI>,
+        protection_guard: Arc<ProfileProtectionGuard>,
+        config: ImportConfig,
+    ) -> Self {
+        Self {
+            parameter_data_spi,
+            parameter_file_spi,
+            protection_guard,
+            config,
+            fingerprint_state: RandomState::new(),
+        }
+    }
+
+    async fn plan(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: &ImportRequest,
+    ) -> error_stack::Result<ImportPlan, ParameterDataError> {
+        let content = self.parameter_file_spi.read_file(&request.file_path)?;
+        let imported = imported_parameters(&content, request).map_err(Report::new)?;
+
+        // Dotenv names are direct children of the prefix, so nested parameters are neither
+        // compared nor pruned.
+        let recursive = request.format != ImportFormat::Dotenv;
+        let prefix = ParameterTree::normalize_path(&request.prefix);
+        let current = self
+            .parameter_data_spi
+            .load_parameters_by_path(profile_name, region, prefix, recursive)
+            .await?;
+
+        let items = compute_import_plan(imported, &current, request.prune);
+        let fingerprint = self.fingerprint(&items);
+        Ok(ImportPlan { items, fingerprint })
+    }
+
+    fn fingerprint(&self, items: &[ImportPlanItem]) -> String {
+        let mut hasher = self.fingerprint_state.build_hasher();
+        for item in items {
+            item.name.hash(&mut hasher);
+            item.action.hash(&mut hasher);
+            item.parameter_type.as_str().hash(&mut hasher);
+            match item.parameter.as_ref().map(|parameter| &parameter.value) {
+                Some(ParameterValue::String(value)) => value.hash(&mut hasher),
+                Some(ParameterValue::StringList(values)) => values.hash(&mut hasher),
+                Some(ParameterValue::SecureString(value)) => {
+                    value.expose(|bytes| bytes.hash(&mut hasher))
+                }
+                None => {}
+            }
+        }
+        format!("{:016x}", hasher.finish())
+    }
+
+    async fn apply_item(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        item: &ImportPlanItem,
+    ) -> Result<(), ParameterDataError> {
+        let mode = match item.action {
+            // Deletions are sent in batches by `apply_deletions`.
+            ImportAction::Unchanged | ImportAction::Delete => return Ok(()),
+            // Created parameters must not replace one that turned up since the plan was made.
+            ImportAction::Create => WriteMode::CreateOnly,
+            ImportAction::Update => WriteMode::Overwrite,
+        };
+        let parameter = item.parameter.clone().ok_or_else(|| {
+            ParameterDataError::InvalidParameter(format!("'{}' has no value", item.name))
+        })?;
+
+        self.parameter_data_spi
+            .upsert_parameter(profile_name, region, parameter, mode, vec![])
+            .await
+            .map_err(ParameterDataError::from)
+    }
+
+    async fn apply_plan(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        plan: ImportPlan,
+    ) -> Vec<ImportItemResult> {
+        let (deletions, writes): (Vec<_>, Vec<_>) = plan
+            .items
+            .into_iter()
+            .partition(|item| item.action == ImportAction::Delete);
+
+        let mut results = self
+            .apply_writes(profile_name, region.clone(), writes)
+            .await;
+        results.extend(self.apply_deletions(profile_name, region, deletions).await);
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        results
+    }
+
+    /// Writes the items a few at a time. Throttled items are retried in later rounds, with
+    /// fewer writes at once and a growing pause, so a large import does not keep hitting the
+    /// rate limit.
+    async fn apply_writes(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        items: Vec<ImportPlanItem>,
+    ) -> Vec<ImportItemResult> {
+        let mut results = vec![];
+        let mut pending = items;
+        let mut concurrency = self.config.concurrency.max(1);
+        let mut backoff = self.config.throttle_backoff;
+        let mut retries = 0;
+
+        while !pending.is_empty() {
+            let mut throttled = vec![];
+            let mut chunks = pending.chunks(concurrency);
+            for chunk in chunks.by_ref() {
+                let outcomes = join_all(
+                    chunk
+                        .iter()
+                        .map(|item| self.apply_item(profile_name, region.clone(), item)),
+                )
+                .await;
+
+                for (item, outcome) in chunk.iter().zip(outcomes) {
+                    match outcome {
+                        Err(ParameterDataError::Throttled(_))
+                            if retries < self.config.max_throttle_retries =>
+                        {
+                            throttled.push(item.clone())
+                        }
+                        outcome => results.push(ImportItemResult {
+                            name: item.name.clone(),
+                            action: item.action,
+                            error: outcome.err(),
+                        }),
+                    }
+                }
+                if !throttled.is_empty() {
+                    break;
+                }
+            }
+            if throttled.is_empty() {
+                break;
+            }
+
+            // The rest has not been tried yet and waits for the throttled items.
+            throttled.extend(chunks.flatten().cloned());
+            tracing::warn!(
+                "Throttled while importing, retrying {} parameters",
+                throttled.len()
+            );
+            tokio::time::sleep(backoff).await;
+            concurrency = (concurrency / 2).max(1);
+            backoff *= 2;
+            retries += 1;
+            pending = throttled;
+        }
+
+        results
+    }
+
+    /// Parameters that are already gone count as deleted.
+    async fn apply_deletions(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        items: Vec<ImportPlanItem>,
+    ) -> Vec<ImportItemResult> {
+        if items.is_empty() {
+            return vec![];
+        }
+
+        let names = items.iter().map(|item| item.name.clone()).collect();
+        match self
+            .parameter_data_spi
+            .delete_parameters(profile_name, region, names)
+            .await
+        {
+            Ok(deletions) => deletions
+                .into_iter()
+                .map(|deletion| ImportItemResult {
+                    error: match deletion.status {
+                        DeletionStatus::Deleted | DeletionStatus::NotFound => None,
+                        DeletionStatus::Failed { reason } => {
+                            Some(ParameterDataError::ParameterDataWriteError(reason))
+                        }
+                    },
+                    name: deletion.name,
+                    action: ImportAction::Delete,
+                })
+                .collect(),
+            Err(report) => {
+                let error = ParameterDataError::from(report);
+                items
+                    .into_iter()
+                    .map(|item| ImportItemResult {
+                        name: item.name,
+                        action: item.action,
+                        error: Some(error.clone()),
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn check_protection(
+        &self,
+        profile_name: &str,
+        region: Option<&str>,
+        request: &ImportRequest,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let target = format!(
+            "{}:{}:{}",
+            region.unwrap_or_default(),
+            request.prefix,
+            request.file_path
+        );
+
+        self.protection_guard
+            .check(
+                ProtectedOperation::ImportParameters,
+                &[profile_name.to_string()],
+                &target,
+                confirmation_token,
+            )
+            .map_err(|denial| Report::new(ParameterDataError::ProfileProtected(denial)))
+    }
+}
+
+#[async_trait]
+impl ParameterImportAPI for ImportService {
+    async fn plan_import(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ImportRequest,
+    ) -> error_stack::Result<ImportPlan, ParameterDataError> {
+        self.plan(profile_name, region, &request).await
+    }
+
+    async fn import_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        request: ImportRequest,
+        plan_fingerprint: String,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<Vec<ImportItemResult>, ParameterDataError> {
+        self.check_protection(
+            profile_name,
+            region.as_deref(),
+            &request,
+            confirmation_token,
+        )?;
+
+        let plan = self.plan(profile_name, region.clone(), &request).await?;
+        if plan.fingerprint != plan_fingerprint {
+            return Err(Report::new(ParameterDataError::ImportPlanChanged));
+        }
+
+        Ok(self.apply_plan(profile_name, region, plan).await)
+    }
+}
+
+/// Compares the imported parameters with the ones currently below the prefix. Parameters are
+/// unchanged if type and value are the same, other metadata is kept as it is on updates.
+pub fn compute_import_plan(
+    imported: Vec<Parameter>,
+    current: &[Parameter],
+    prune: bool,
+) -> Vec<ImportPlanItem> {
+    let current_by_name: BTreeMap<&str, &Parameter> = current
+        .iter()
+        .map(|parameter| (parameter.name.as_str(), parameter))
+        .collect();
+
+    let mut items: Vec<ImportPlanItem> = imported
+        .iter()
+        .map(|parameter| {
+            let existing = current_by_name.get(parameter.name.as_str());
+            let (action, parameter) = match existing {
+                None => (ImportAction::Create, parameter.clone()),
+                Some(existing) if existing.value == parameter.value => {
+                    (ImportAction::Unchanged, parameter.clone())
+                }
+                Some(existing) => (
+                    ImportAction::Update,
+                    parameter
+                        .clone()
+                        .with_metadata(kept_metadata(existing, parameter)),
+                ),
+            };
+            ImportPlanItem {
+                name: parameter.name.clone(),
+                action,
+                parameter_type: parameter.value.parameter_type(),
+                parameter: Some(parameter),
+            }
+        })
+        .collect();
+
+    if prune {
+        items.extend(
+            current
+                .iter()
+                .filter(|parameter| {
+                    !imported
+                        .iter()
+                        .any(|imported| imported.name == parameter.name)
+                })
+                .map(|parameter| ImportPlanItem {
+                    name: parameter.name.clone(),
+                    action: ImportAction::Delete,
+                    parameter_type: parameter.value.parameter_type(),
+                    parameter: None,
+                }),
+        );
+    }
+
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+    items
+}
+
+/// The key only applies as long as the parameter stays secure.
+fn kept_metadata(existing: &Parameter, imported: &Parameter) -> ParameterMetadata {
+    let metadata = &existing.metadata;
+    let still_secure = matches!(imported.value, ParameterValue::SecureString(_))
+        && matches!(existing.value, ParameterValue::SecureString(_));
+
+    ParameterMetadata {
+        description: metadata.description.clone(),
+        tier: metadata.tier,
+        data_type: metadata.data_type.clone(),
+        key_id: metadata.key_id.clone().filter(|_| still_secure),
+        allowed_pattern: metadata.allowed_pattern.clone(),
+        ..ParameterMetadata::default()
+    }
+}
+
+enum ImportedValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+/// Reads the file content into parameters below the request's prefix, with the chosen types.
+fn imported_parameters(
+    content: &str,
+    request: &ImportRequest,
+) -> Result<Vec<Parameter>, ParameterDataError> {
+    let entries = match request.format {
+        ImportFormat::Json => {
+            let document: JsonValue = serde_json::from_str(content).map_err(|err| {
+                ParameterDataError::ParameterFileError(format!("not valid JSON: {}", err))
+            })?;
+            document_entries(document)?
+        }
+        ImportFormat::Yaml => {
+            let document: JsonValue = serde_yaml::from_str(content).map_err(|err| {
+                ParameterDataError::ParameterFileError(format!("not valid YAML: {}", err))
+            })?;
+            document_entries(document)?
+        }
+        ImportFormat::Dotenv => dotenv_entries(content)?,
+    };
+
+    let prefix = ParameterTree::normalize_path(&request.prefix);
+    let mut parameters: Vec<Parameter> = vec![];
+    for (relative_name, value) in entries {
+        let name = if prefix == "/" {
+            format!("/{}", relative_name)
+        } else {
+            format!("{}/{}", prefix, relative_name)
+        };
+        if parameters.iter().any(|parameter| parameter.name == name) {
+            return Err(ParameterDataError::InvalidParameter(format!(
+                "'{}' appears more than once in the file",
+                name
+            )));
+        }
+
+        let parameter_type = request
+            .types
+            .get(&name)
+            .copied()
+            .unwrap_or(request.parameter_type);
+        let value = typed_value(&name, value, parameter_type)?;
+        parameters.push(Parameter::from((name, value)));
+    }
+
+    Ok(parameters)
+}
+
+fn typed_value(
+    name: &str,
+    value: ImportedValue,
+    parameter_type: ParameterType,
+) -> Result<ParameterValue, ParameterDataError> {
+    match (parameter_type, value) {
+        (ParameterType::String, ImportedValue::Text(text)) => Ok(ParameterValue::String(text)),
+        (ParameterType::SecureString, ImportedValue::Text(text)) => {
+            Ok(ParameterValue::SecureString(SecureString::from(text)))
+        }
+        (ParameterType::StringList, ImportedValue::Text(text)) => Ok(ParameterValue::StringList(
+            text.split(',').map(str::to_string).collect(),
+        )),
+        (ParameterType::StringList, ImportedValue::List(values)) => {
+            Ok(ParameterValue::StringList(values))
+        }
+        (_, ImportedValue::List(_)) => Err(ParameterDataError::InvalidParameter(format!(
+            "'{}' holds a list, import it as {}",
+            name,
+            ParameterType::StringList.as_str()
+        ))),
+    }
+}
+
+fn document_entries(
+    document: JsonValue,
+) -> Result<Vec<(String, ImportedValue)>, ParameterDataError> {
+    let JsonValue::Object(object) = document else {
+        return Err(ParameterDataError::ParameterFileError(
+            "the file should hold an object of parameters".to_string(),
+        ));
+    };
+
+    let mut entries = vec![];
+    for (key, value) in object {
+        collect_entries(key, value, &mut entries)?;
+    }
+    Ok(entries)
+}
+
+/// Nested objects extend the name by a path segment for each level.
+fn collect_entries(
+    name: String,
+    value: JsonValue,
+    entries: &mut Vec<(String, ImportedValue)>,
+) -> Result<(), ParameterDataError> {
+    let name = name.trim_matches('/').to_string();
+    match value {
+        JsonValue::Object(object) => {
+            for (key, value) in object {
+                collect_entries(format!("{}/{}", name, key), value, entries)?;
+            }
+            Ok(())
+        }
+        JsonValue::Array(values) => {
+            let values = values
+                .into_iter()
+                .map(|value| {
+                    scalar_text(value).ok_or_else(|| {
+                        ParameterDataError::InvalidParameter(format!(
+                            "'{}' should only list plain values",
+                            name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<String>, ParameterDataError>>()?;
+            entries.push((name, ImportedValue::List(values)));
+            Ok(())
+        }
+        value => {
+            let text = scalar_text(value).ok_or_else(|| {
+                ParameterDataError::InvalidParameter(format!("'{}' has no value", name))
+            })?;
+            entries.push((name, ImportedValue::Text(text)));
+            Ok(())
+        }
+    }
+}
+
+fn scalar_text(value: JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(text) => Some(text),
+        JsonValue::Number(number) => Some(number.to_string()),
+        JsonValue::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Supports comments, `export` prefixes and single or double quoted values, the latter with
+/// the escapes the export writes.
+fn dotenv_entries(content: &str) -> Result<Vec<(String, ImportedValue)>, ParameterDataError> {
+    let mut entries = vec![];
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((name, value)) = line
+            .split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, _)| !name.is_empty())
+        else {
+            return Err(ParameterDataError::ParameterFileError(format!(
+                "line {} is not a NAME=value assignment",
+                index + 1
+            )));
+        };
+
+        let double_quoted = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'));
+        let single_quoted = value
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''));
+        let value = if let Some(quoted) = double_quoted {
+            unescape(quoted)
+        } else if let Some(quoted) = single_quoted {
+            quoted.to_string()
+        } else {
+            match value.find(" #") {
+                Some(comment_start) => value[..comment_start].trim_end().to_string(),
+                None => value.to_string(),
+            }
+        };
+        entries.push((name.to_string(), ImportedValue::Text(value)));
+    }
+
+    Ok(entries)
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mockall::predicate::*;
+    use mockall::Sequence;
+    use spectral::prelude::*;
+
+    use crate::common::demo::DemoFixture;
+    use crate::parameters::core::api::ParameterDataAPI;
+    use crate::parameters::core::domain::ParameterDeletion;
+    use crate::parameters::core::export_service::{render_export, ExportFormat};
+    use crate::parameters::core::parameter_service::ParameterService;
+    use crate::parameters::core::spi::{MockParameterDataSPI, MockParameterFileSPI};
+    use crate::parameters::infrastructure::memory::in_memory_parameter_adapter::InMemoryParameterAdapter;
+    use crate::profiles::core::domain::ProtectionLevel;
+    use crate::profiles::core::spi::MockProfileProtectionSPI;
+
+    use super::*;
+
+    fn protection_guard_with(
+        protection_levels: Vec<(&str, ProtectionLevel)>,
+    ) -> Arc<ProfileProtectionGuard> {
+        let protection_levels: HashMap<String, ProtectionLevel> = protection_levels
+            .into_iter()
+            .map(|(profile_name, level)| (profile_name.to_string(), level))
+            .collect();
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(move || Ok(protection_levels.clone()));
+
+        Arc::new(ProfileProtectionGuard::new(Box::new(protection_spi_mock)))
+    }
+
+    fn file_with(content: &'static str) -> MockParameterFileSPI {
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        mock_parameter_file_spi
+            .expect_read_file()
+            .with(eq("/tmp/import.json"))
+            .returning(move |_| Ok(content.to_string()));
+        mock_parameter_file_spi
+    }
+
+    fn request(format: ImportFormat, prune: bool) -> ImportRequest {
+        ImportRequest {
+            file_path: "/tmp/import.json".to_string(),
+            format,
+            prefix: "app".to_string(),
+            parameter_type: ParameterType::String,
+            types: BTreeMap::new(),
+            prune,
+        }
+    }
+
+    fn string_parameter(name: &str, value: &str) -> Parameter {
+        Parameter::from((name.to_string(), ParameterValue::String(value.to_string())))
+    }
+
+    async fn reviewed_fingerprint(cut: &ImportService, request: ImportRequest) -> String {
+        cut.plan_import("dev", None, request)
+            .await
+            .unwrap()
+            .fingerprint
+    }
+
+    fn no_retry_delay() -> ImportConfig {
+        ImportConfig {
+            throttle_backoff: Duration::ZERO,
+            ..ImportConfig::default()
+        }
+    }
+
+    #[test]
+    fn should_plan_create_update_unchanged_and_delete() {
+        let imported = vec![
+            string_parameter("/app/a", "1"),
+            string_parameter("/app/b", "changed"),
+            string_parameter("/app/c", "same"),
+        ];
+        let current = vec![
+            string_parameter("/app/b", "2"),
+            string_parameter("/app/c", "same"),
+            string_parameter("/app/d", "gone"),
+        ];
+
+        let cut = compute_import_plan(imported, &current, true);
+
+        let actions: Vec<(&str, ImportAction)> = cut
+            .iter()
+            .map(|item| (item.name.as_str(), item.action))
+            .collect();
+        assert_that!(actions).is_equal_to(vec![
+            ("/app/a", ImportAction::Create),
+            ("/app/b", ImportAction::Update),
+            ("/app/c", ImportAction::Unchanged),
+            ("/app/d", ImportAction::Delete),
+        ]);
+    }
+
+    #[test]
+    fn should_not_plan_deletions_without_prune() {
+        let current = vec![string_parameter("/app/d", "kept")];
+
+        let cut = compute_import_plan(vec![], &current, false);
+
+        assert_that!(cut).is_empty();
+    }
+
+    #[test]
+    fn should_keep_metadata_but_drop_key_when_parameter_is_no_longer_secure() {
+        let current = vec![Parameter::from((
+            "/app/b".to_string(),
+            ParameterValue::SecureString(SecureString::from("old")),
+        ))
+        .with_metadata(ParameterMetadata {
+            description: Some("database url".to_string()),
+            key_id: Some("alias/app".to_string()),
+            ..ParameterMetadata::default()
+        })];
+
+        let cut = compute_import_plan(vec![string_parameter("/app/b", "new")], &current, false);
+
+        let metadata = cut[0].parameter.clone().unwrap().metadata;
+        assert_that!(metadata.description).is_equal_to(Some("database url".to_string()));
+        assert_that!(metadata.key_id).is_none();
+    }
+
+    #[tokio::test]
+    async fn should_plan_nested_json_against_parameters_below_prefix() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app".to_string()),
+                eq(true),
+            )
+            .returning(|_, _, _, _| Ok(vec![string_parameter("/app/db/port", "5432")]));
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(
+                r#"{"db": {"port": 5432, "hosts": ["a", "b"], "password": "secret"}}"#,
+            )),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let mut request = request(ImportFormat::Json, false);
+        request.types = BTreeMap::from([
+            ("/app/db/hosts".to_string(), ParameterType::StringList),
+            ("/app/db/password".to_string(), ParameterType::SecureString),
+        ]);
+
+        let actual = cut.plan_import("dev", None, request).await.unwrap();
+
+        let items: Vec<(&str, ImportAction, ParameterType)> = actual
+            .items
+            .iter()
+            .map(|item| (item.name.as_str(), item.action, item.parameter_type))
+            .collect();
+        assert_that!(items).is_equal_to(vec![
+            (
+                "/app/db/hosts",
+                ImportAction::Create,
+                ParameterType::StringList,
+            ),
+            (
+                "/app/db/password",
+                ImportAction::Create,
+                ParameterType::SecureString,
+            ),
+            (
+                "/app/db/port",
+                ImportAction::Unchanged,
+                ParameterType::String,
+            ),
+        ]);
+        let serialized = serde_json::to_string(&actual).unwrap();
+        assert_that!(serialized.contains("secret")).is_false();
+    }
+
+    #[tokio::test]
+    async fn should_refuse_list_for_plain_string_type() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .never();
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(r#"{"hosts": ["a", "b"]}"#)),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+
+        let actual = cut
+            .plan_import("dev", None, request(ImportFormat::Json, false))
+            .await;
+
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::InvalidParameter(_)
+        ));
+    }
+
+    #[test]
+    fn should_read_dotenv_assignments_below_prefix() {
+        let content = "# comment\nexport DB_URL=\"postgres://db\\n\\\"x\\\"\"\nPLAIN=value # note\nSINGLE='a\\b'\n";
+
+        let actual = imported_parameters(content, &request(ImportFormat::Dotenv, false)).unwrap();
+
+        assert_that!(actual).is_equal_to(vec![
+            string_parameter("/app/DB_URL", "postgres://db\n\"x\""),
+            string_parameter("/app/PLAIN", "value"),
+            string_parameter("/app/SINGLE", "a\\b"),
+        ]);
+    }
+
+    #[test]
+    fn should_refuse_duplicate_names() {
+        let actual = imported_parameters(
+            "db:\n  port: 1\ndb/port: 2\n",
+            &request(ImportFormat::Yaml, false),
+        );
+
+        assert_that!(actual).is_err_containing(ParameterDataError::InvalidParameter(
+            "'/app/db/port' appears more than once in the file".to_string(),
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_apply_plan_and_report_each_item() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .returning(|_, _, _, _| {
+                Ok(vec![
+                    string_parameter("/app/b", "2"),
+                    string_parameter("/app/c", "3"),
+                ])
+            });
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(|_, _, parameter, mode, _| {
+                parameter.name == "/app/a" && *mode == WriteMode::CreateOnly
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(|_, _, parameter, mode, _| {
+                parameter.name == "/app/b" && *mode == WriteMode::Overwrite
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| {
+                Err(Report::new(ParameterDataError::ParameterPatternMismatch(
+                    "digits only".to_string(),
+                )))
+            });
+        mock_parameter_data_spi
+            .expect_delete_parameters()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(vec!["/app/c".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, names| {
+                Ok(names
+                    .into_iter()
+                    .map(|name| ParameterDeletion::new(name, DeletionStatus::Deleted))
+                    .collect())
+            });
+        mock_parameter_data_spi.expect_delete_parameter().never();
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(r#"{"a": "1", "b": "x"}"#)),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let fingerprint = reviewed_fingerprint(&cut, request(ImportFormat::Json, true)).await;
+
+        let actual = cut
+            .import_parameters(
+                "dev",
+                None,
+                request(ImportFormat::Json, true),
+                fingerprint,
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(vec![
+            ImportItemResult {
+                name: "/app/a".to_string(),
+                action: ImportAction::Create,
+                error: None,
+            },
+            ImportItemResult {
+                name: "/app/b".to_string(),
+                action: ImportAction::Update,
+                error: Some(ParameterDataError::ParameterPatternMismatch(
+                    "digits only".to_string(),
+                )),
+            },
+            ImportItemResult {
+                name: "/app/c".to_string(),
+                action: ImportAction::Delete,
+                error: None,
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn should_retry_throttled_writes() {
+        let mut sequence = Sequence::new();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _, _| {
+                Err(Report::new(ParameterDataError::Throttled(
+                    "Rate exceeded".to_string(),
+                )))
+            });
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(r#"{"a": "1"}"#)),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let fingerprint = reviewed_fingerprint(&cut, request(ImportFormat::Json, false)).await;
+
+        let actual = cut
+            .import_parameters(
+                "dev",
+                None,
+                request(ImportFormat::Json, false),
+                fingerprint,
+                None,
+            )
+            .await;
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to(vec![ImportItemResult {
+                name: "/app/a".to_string(),
+                action: ImportAction::Create,
+                error: None,
+            }]);
+    }
+
+    #[tokio::test]
+    async fn should_report_writes_still_throttled_after_retries() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(2)
+            .returning(|_, _, _, _, _| {
+                Err(Report::new(ParameterDataError::Throttled(
+                    "Rate exceeded".to_string(),
+                )))
+            });
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(r#"{"a": "1"}"#)),
+            protection_guard_with(vec![]),
+            ImportConfig {
+                max_throttle_retries: 1,
+                ..no_retry_delay()
+            },
+        );
+        let fingerprint = reviewed_fingerprint(&cut, request(ImportFormat::Json, false)).await;
+
+        let actual = cut
+            .import_parameters(
+                "dev",
+                None,
+                request(ImportFormat::Json, false),
+                fingerprint,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_that!(actual[0].error).is_equal_to(Some(ParameterDataError::Throttled(
+            "Rate exceeded".to_string(),
+        )));
+    }
+
+    #[tokio::test]
+    async fn should_not_import_into_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .never();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(MockParameterFileSPI::new()),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+            no_retry_delay(),
+        );
+
+        let actual = cut
+            .import_parameters(
+                "prod",
+                None,
+                request(ImportFormat::Json, false),
+                "0000000000000000".to_string(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::ProfileProtected(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_apply_plan_that_changed_since_it_was_reviewed() {
+        let mut sequence = Sequence::new();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _| Ok(vec![string_parameter("/app/a", "meanwhile")]));
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        mock_parameter_data_spi.expect_delete_parameters().never();
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(file_with(r#"{"a": "1"}"#)),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let fingerprint = reviewed_fingerprint(&cut, request(ImportFormat::Json, false)).await;
+
+        let actual = cut
+            .import_parameters(
+                "dev",
+                None,
+                request(ImportFormat::Json, false),
+                fingerprint,
+                None,
+            )
+            .await;
+
+        assert_that!(ParameterDataError::from(actual.unwrap_err()))
+            .is_equal_to(ParameterDataError::ImportPlanChanged);
+    }
+
+    #[tokio::test]
+    async fn should_fingerprint_changed_secure_values_without_revealing_them() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .returning(|_, _, _, _| Ok(vec![]));
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        let mut sequence = Sequence::new();
+        for value in ["secret", "changed"] {
+            mock_parameter_file_spi
+                .expect_read_file()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(move |_| Ok(format!(r#"{{"password": "{}"}}"#, value)));
+        }
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(mock_parameter_file_spi),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let mut request = request(ImportFormat::Json, false);
+        request.parameter_type = ParameterType::SecureString;
+
+        let reviewed = cut.plan_import("dev", None, request.clone()).await.unwrap();
+        let changed = cut.plan_import("dev", None, request).await.unwrap();
+
+        assert_that!(reviewed.fingerprint).is_not_equal_to(changed.fingerprint);
+        let serialized = serde_json::to_string(&reviewed).unwrap();
+        assert_that!(serialized.contains("secret")).is_false();
+    }
+
+    #[tokio::test]
+    async fn should_not_prune_nested_parameters_when_importing_exported_dotenv() {
+        let nested = Parameter::from((
+            "/app/db/password".to_string(),
+            ParameterValue::SecureString(SecureString::from("secret")),
+        ));
+        let exported = render_export("/app", &[nested.clone()], ExportFormat::Dotenv, true)
+            .unwrap()
+            .into_unsecure_string()
+            .unwrap();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app".to_string()),
+                eq(false),
+            )
+            .returning(|_, _, _, _| Ok(vec![string_parameter("/app/STALE", "x")]));
+        let mut mock_parameter_file_spi = MockParameterFileSPI::new();
+        mock_parameter_file_spi
+            .expect_read_file()
+            .returning(move |_| Ok(exported.clone()));
+        let cut = ImportService::new(
+            Box::new(mock_parameter_data_spi),
+            Box::new(mock_parameter_file_spi),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+
+        let actual = cut
+            .plan_import("dev", None, request(ImportFormat::Dotenv, true))
+            .await
+            .unwrap();
+
+        let actions: Vec<(&str, ImportAction)> = actual
+            .items
+            .iter()
+            .map(|item| (item.name.as_str(), item.action))
+            .collect();
+        assert_that!(actions).is_equal_to(vec![
+            ("/app/DB_PASSWORD", ImportAction::Create),
+            ("/app/STALE", ImportAction::Delete),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn should_show_imported_sub_paths_when_browsing_tree_after_import() {
+        let parameters = InMemoryParameterAdapter::new(DemoFixture::default());
+        let browser =
+            ParameterService::new(Box::new(parameters.clone()), protection_guard_with(vec![]));
+        let cut = ImportService::new(
+            Box::new(parameters),
+            Box::new(file_with(r#"{"name": "custodian", "db": {"url": "jdbc"}}"#)),
+            protection_guard_with(vec![]),
+            no_retry_delay(),
+        );
+        let before = browser
+            .get_parameter_tree("dev", None, "/app".to_string(), false)
+            .await;
+        let fingerprint = reviewed_fingerprint(&cut, request(ImportFormat::Json, false)).await;
+
+        cut.import_parameters(
+            "dev",
+            None,
+            request(ImportFormat::Json, false),
+            fingerprint,
+            None,
+        )
+        .await
+        .unwrap();
+        let after = browser
+            .get_parameter_tree("dev", None, "/app".to_string(), false)
+            .await
+            .unwrap();
+
+        assert_that!(before.unwrap().sub_paths).is_empty();
+        assert_that!(after.parameters).has_length(1);
+        assert_that!(after.sub_paths).is_equal_to(vec![ParameterTree::new("/app/db")]);
+    }
+}
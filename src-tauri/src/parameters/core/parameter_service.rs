@@ -1,21 +1,204 @@
-use crate::common::secure_string::SecureString;
 use crate::parameters::core::api::{ParameterDataAPI, SetParameterRequest, Value};
-use crate::parameters::core::domain::ParameterValue;
+use crate::parameters::core::diff_service;
+use crate::parameters::core::diff_service::ParameterVersionDiff;
 #[allow(unused_imports)] // false-positive
 use crate::parameters::core::domain::{Parameter, ParameterSet};
+use crate::parameters::core::domain::{
+    ParameterDeletion, ParameterFilters, ParameterHistory, ParameterInfo, ParameterLabel,
+    ParameterLabelUpdate, ParameterMetadata, ParameterNamePage, ParameterPathIndex,
+    ParameterRegionComparison, ParameterSelector, ParameterTag, ParameterTier, ParameterTree,
+    ParameterType, ParameterValue, WriteMode,
+};
 use crate::parameters::core::error::ParameterDataError;
 use crate::parameters::core::spi::ParameterDataSPI;
+use crate::profiles::core::domain::ProtectedOperation;
+use crate::profiles::core::protection_guard::ProfileProtectionGuard;
 use async_trait::async_trait;
+use error_stack::Report;
+use futures::future::join_all;
 #[cfg(test)]
 use mockall::predicate::*;
+use std::sync::Arc;
+
+const MAX_PAGE_SIZE: i32 = 50;
+const MAX_STANDARD_VALUE_SIZE: usize = 4 * 1024;
+const MAX_ADVANCED_VALUE_SIZE: usize = 8 * 1024;
+const DATA_TYPES: [&str; 3] = ["text", "aws:ec2:image", "aws:ssm:integration"];
+const MAX_LABELS: usize = 10;
+const MAX_TAG_KEY_LENGTH: usize = 128;
+const MAX_TAG_VALUE_LENGTH: usize = 256;
 
 pub struct ParameterService {
     parameter_data_spi: Box<dyn ParameterDataSPI>,
+    protection_guard: Arc<ProfileProtectionGuard>,
 }
 
 impl ParameterService {
-    pub fn new(parameter_data_spi: Box<dyn ParameterDataSPI>) -> Self {
-        Self { parameter_data_spi }
+    pub fn new(
+        parameter_data_spi: Box<dyn ParameterDataSPI>,
+        protection_guard: Arc<ProfileProtectionGuard>,
+    ) -> Self {
+        Self {
+            parameter_data_spi,
+            protection_guard,
+        }
+    }
+
+    /// Catches what Parameter Store would reject anyway, so the user gets a precise reason.
+    fn validate_parameter(parameter: &Parameter) -> error_stack::Result<(), ParameterDataError> {
+        let metadata = &parameter.metadata;
+        let parameter_type = parameter.value.parameter_type();
+
+        if metadata.key_id.is_some() && parameter_type != ParameterType::SecureString {
+            return Err(Report::new(ParameterDataError::InvalidParameter(
+                "only secure parameters are encrypted with a KMS key".to_string(),
+            )));
+        }
+        if let Some(data_type) = &metadata.data_type {
+            if !DATA_TYPES.contains(&data_type.as_str()) {
+                return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                    "unsupported data type '{}'",
+                    data_type
+                ))));
+            }
+            if data_type != "text" && parameter_type != ParameterType::String {
+                return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                    "data type '{}' requires a plain string parameter",
+                    data_type
+                ))));
+            }
+        }
+
+        // Without a tier, the account's default tier applies, which might be the advanced one.
+        let (tier_name, max_size) = match metadata.tier {
+            Some(ParameterTier::Standard) => ("standard", MAX_STANDARD_VALUE_SIZE),
+            _ => ("advanced", MAX_ADVANCED_VALUE_SIZE),
+        };
+        let size = parameter.value.size();
+        if size > max_size {
+            return Err(Report::new(ParameterDataError::ParameterTooLarge(format!(
+                "{} bytes exceed the {} bytes of {} parameters",
+                size, max_size, tier_name
+            ))));
+        }
+
+        Ok(())
+    }
+
+    fn validate_labels(labels: &[String]) -> error_stack::Result<(), ParameterDataError> {
+        if labels.is_empty() || labels.len() > MAX_LABELS {
+            return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                "between 1 and {} labels can be changed at once",
+                MAX_LABELS
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Keys starting with `aws:` are reserved for tags set by AWS itself.
+    fn validate_tags(tags: &[ParameterTag]) -> error_stack::Result<(), ParameterDataError> {
+        for tag in tags {
+            if tag.key.is_empty()
+                || tag.key.len() > MAX_TAG_KEY_LENGTH
+                || tag.key.to_ascii_lowercase().starts_with("aws:")
+            {
+                return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                    "invalid tag key '{}'",
+                    tag.key
+                ))));
+            }
+            if tag.value.len() > MAX_TAG_VALUE_LENGTH {
+                return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                    "the value of tag '{}' exceeds {} characters",
+                    tag.key, MAX_TAG_VALUE_LENGTH
+                ))));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parameter Store has no conditional put, so this narrows the window for a race rather than
+    /// closing it. The version is read with GetParameters, as DescribeParameters is eventually
+    /// consistent and can miss a write that just happened.
+    async fn check_current_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter: &Parameter,
+        expected_version: i64,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let current_version = self
+            .parameter_data_spi
+            .load_parameter_version(profile_name, region.clone(), parameter.name.clone())
+            .await?;
+
+        match current_version {
+            Some(version) if version == expected_version => Ok(()),
+            Some(version) => {
+                let current = self
+                    .current_parameter_info(profile_name, region, parameter, version)
+                    .await;
+                Err(Report::new(ParameterDataError::ConcurrentModification(
+                    Box::new(current),
+                )))
+            }
+            None => Err(Report::new(ParameterDataError::ParameterNotFound(
+                parameter.name.clone(),
+            ))),
+        }
+    }
+
+    /// Describes the conflicting version, so the user can see who changed the parameter. Only
+    /// the version is certain, as the metadata may lag behind it.
+    async fn current_parameter_info(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter: &Parameter,
+        version: i64,
+    ) -> ParameterInfo {
+        let described = self
+            .parameter_data_spi
+            .load_parameter_metadata(profile_name, region, vec![parameter.name.clone()])
+            .await
+            .ok()
+            .and_then(|infos| infos.into_iter().find(|info| info.name == parameter.name));
+
+        ParameterInfo {
+            version: Some(version),
+            ..described.unwrap_or_else(|| ParameterInfo {
+                version: None,
+                ..ParameterInfo::from(parameter)
+            })
+        }
+    }
+
+    /// Confirmations are bound to the region and the parameters, so confirming a change of one
+    /// parameter cannot be replayed for another.
+    fn check_protection(
+        &self,
+        operation: ProtectedOperation,
+        profile_name: &str,
+        region: Option<&str>,
+        parameter_names: &[String],
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let target = format!(
+            "{}:{}",
+            region.unwrap_or_default(),
+            parameter_names.join(",")
+        );
+
+        self.protection_guard
+            .check(
+                operation,
+                &[profile_name.to_string()],
+                &target,
+                confirmation_token,
+            )
+            .map_err(|denial| Report::new(ParameterDataError::ProfileProtected(denial)))
     }
 }
 
@@ -24,62 +207,505 @@ impl ParameterDataAPI for ParameterService {
     async fn get_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter_names: Vec<String>,
     ) -> error_stack::Result<ParameterSet, ParameterDataError> {
+        if let Some(invalid_name) = parameter_names
+            .iter()
+            .find(|name| ParameterSelector::parse(name).is_none())
+        {
+            return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                "invalid version or label in '{}'",
+                invalid_name
+            ))));
+        }
+
+        self.parameter_data_spi
+            .load_parameters(profile_name, region, parameter_names)
+            .await
+    }
+
+    async fn get_parameter_metadata(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError> {
         self.parameter_data_spi
-            .load_parameters(profile_name, parameter_names)
+            .load_parameter_metadata(profile_name, region, parameter_names)
             .await
     }
 
     async fn get_available_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
     ) -> error_stack::Result<Vec<String>, ParameterDataError> {
         self.parameter_data_spi
-            .load_available_parameter_names(profile_name)
+            .load_available_parameter_names(profile_name, region)
+            .await
+    }
+
+    async fn get_available_parameters_page(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError> {
+        if filters.tag_value.is_some() && filters.tag_key.is_none() {
+            return Err(Report::new(ParameterDataError::InvalidParameter(
+                "tag value filters require a tag key".to_string(),
+            )));
+        }
+        // DescribeParameters returns at most 50 results per call.
+        let page_size = page_size.map(|size| size.clamp(1, MAX_PAGE_SIZE));
+
+        self.parameter_data_spi
+            .load_parameter_names_page(profile_name, region, filters, cursor, page_size)
+            .await
+    }
+
+    async fn get_parameter_history(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterHistory, ParameterDataError> {
+        self.parameter_data_spi
+            .load_parameter_history(profile_name, region, parameter_name)
+            .await
+    }
+
+    async fn get_parameter_labels(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterLabel>, ParameterDataError> {
+        self.parameter_data_spi
+            .load_parameter_labels(profile_name, region, parameter_name)
             .await
     }
 
+    async fn compare_parameter_versions(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        from_version: i64,
+        to_version: i64,
+        reveal_secure_values: bool,
+    ) -> error_stack::Result<ParameterVersionDiff, ParameterDataError> {
+        let history = self
+            .parameter_data_spi
+            .load_parameter_history(profile_name, region, parameter_name.clone())
+            .await?;
+        let find_version = |version: i64| {
+            history.version(version).ok_or_else(|| {
+                Report::new(ParameterDataError::InvalidParameter(format!(
+                    "parameter '{}' has no version {}",
+                    parameter_name, version
+                )))
+            })
+        };
+
+        diff_service::diff_versions(
+            &parameter_name,
+            find_version(from_version)?,
+            find_version(to_version)?,
+            reveal_secure_values,
+        )
+        .map_err(Report::new)
+    }
+
     async fn set_parameter(
         &self,
         profile_name: &str,
+        region: Option<String>,
         request: SetParameterRequest,
+        confirmation_token: Option<String>,
     ) -> error_stack::Result<(), ParameterDataError> {
-        match request.value {
+        self.check_protection(
+            ProtectedOperation::SetParameter,
+            profile_name,
+            region.as_deref(),
+            &[request.name.clone()],
+            confirmation_token,
+        )?;
+
+        let parameter_value = match request.value {
+            Value::Single(value) if request.secure.unwrap_or(false) => {
+                ParameterValue::SecureString(value)
+            }
             Value::Single(value) => {
-                let parameter_value;
-                if request.secure.unwrap_or(false) {
-                    let secure_value = SecureString::from(value);
-                    parameter_value = ParameterValue::SecureString(secure_value);
-                } else {
-                    parameter_value = ParameterValue::String(value);
-                }
+                let plain_value = value.into_unsecure_string().map_err(|_| {
+                    Report::new(ParameterDataError::InvalidParameter(
+                        "parameter values should be valid UTF-8".to_string(),
+                    ))
+                })?;
+                ParameterValue::String(plain_value)
+            }
+            // Parameter Store has no secure string lists, so the values would be stored in plain.
+            Value::Multiple(_) if request.secure.unwrap_or(false) => {
+                return Err(Report::new(ParameterDataError::InvalidParameter(
+                    "string lists cannot be stored as secure parameters".to_string(),
+                )));
+            }
+            Value::Multiple(values) => ParameterValue::StringList(values),
+        };
+        let parameter =
+            Parameter::from((request.name, parameter_value)).with_metadata(ParameterMetadata {
+                description: request.description,
+                tier: request.tier,
+                data_type: request.data_type,
+                key_id: request.key_id,
+                allowed_pattern: request.allowed_pattern,
+                ..ParameterMetadata::default()
+            });
+        Self::validate_parameter(&parameter)?;
+        Self::validate_tags(&request.tags)?;
+        if let Some(expected_version) = request.expected_version {
+            self.check_current_version(profile_name, region.clone(), &parameter, expected_version)
+                .await?;
+        }
+
+        // Tags are created together with the parameter, so a failing tag call cannot leave an
+        // untagged parameter behind that a create-only retry then refuses to write.
+        let parameter_name = parameter.name.clone();
+        let (creation_tags, tags) = match request.mode {
+            WriteMode::CreateOnly => (request.tags, vec![]),
+            WriteMode::Overwrite => (vec![], request.tags),
+        };
+        self.parameter_data_spi
+            .upsert_parameter(
+                profile_name,
+                region.clone(),
+                parameter,
+                request.mode,
+                creation_tags,
+            )
+            .await?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        self.parameter_data_spi
+            .add_parameter_tags(profile_name, region, parameter_name, tags)
+            .await
+    }
+
+    async fn restore_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        expected_version: i64,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::SetParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+
+        let history = self
+            .parameter_data_spi
+            .load_parameter_history(profile_name, region.clone(), parameter_name.clone())
+            .await?;
+        if let Some(latest) = history
+            .latest()
+            .filter(|latest| latest.version != expected_version)
+        {
+            return Err(Report::new(ParameterDataError::ConcurrentModification(
+                Box::new(latest.info(&parameter_name)),
+            )));
+        }
+        let restored = history.version(version).ok_or_else(|| {
+            Report::new(ParameterDataError::InvalidParameter(format!(
+                "parameter '{}' has no version {}",
+                parameter_name, version
+            )))
+        })?;
+
+        let parameter = Parameter::from((parameter_name, restored.value.clone())).with_metadata(
+            ParameterMetadata {
+                description: restored.metadata.description.clone(),
+                tier: restored.metadata.tier,
+                data_type: restored.metadata.data_type.clone(),
+                key_id: restored.metadata.key_id.clone(),
+                allowed_pattern: restored.metadata.allowed_pattern.clone(),
+                ..ParameterMetadata::default()
+            },
+        );
+
+        self.parameter_data_spi
+            .upsert_parameter(
+                profile_name,
+                region,
+                parameter,
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+    }
+
+    async fn label_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::LabelParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+        Self::validate_labels(&labels)?;
+
+        self.parameter_data_spi
+            .label_parameter_version(profile_name, region, parameter_name, version, labels)
+            .await
+    }
+
+    async fn unlabel_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::LabelParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+        Self::validate_labels(&labels)?;
+
+        self.parameter_data_spi
+            .unlabel_parameter_version(profile_name, region, parameter_name, version, labels)
+            .await
+    }
+
+    async fn get_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterTag>, ParameterDataError> {
+        self.parameter_data_spi
+            .load_parameter_tags(profile_name, region, parameter_name)
+            .await
+    }
 
-                let parameter: Parameter = (request.name.to_string(), parameter_value).into();
-                self.parameter_data_spi
-                    .upsert_parameter(profile_name, parameter)
-                    .await
+    async fn add_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tags: Vec<ParameterTag>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::TagParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+        Self::validate_tags(&tags)?;
+        if tags.is_empty() {
+            return Ok(());
+        }
+
+        self.parameter_data_spi
+            .add_parameter_tags(profile_name, region, parameter_name, tags)
+            .await
+    }
+
+    async fn remove_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tag_keys: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::TagParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+        if tag_keys.is_empty() {
+            return Ok(());
+        }
+
+        self.parameter_data_spi
+            .remove_parameter_tags(profile_name, region, parameter_name, tag_keys)
+            .await
+    }
+
+    async fn delete_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<ParameterDeletion, ParameterDataError> {
+        self.check_protection(
+            ProtectedOperation::DeleteParameter,
+            profile_name,
+            region.as_deref(),
+            &[parameter_name.clone()],
+            confirmation_token,
+        )?;
+
+        self.parameter_data_spi
+            .delete_parameter(profile_name, region, parameter_name)
+            .await
+    }
+
+    async fn delete_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<Vec<ParameterDeletion>, ParameterDataError> {
+        let mut unique_names = parameter_names;
+        unique_names.sort();
+        unique_names.dedup();
+
+        self.check_protection(
+            ProtectedOperation::DeleteParameter,
+            profile_name,
+            region.as_deref(),
+            &unique_names,
+            confirmation_token,
+        )?;
+
+        self.parameter_data_spi
+            .delete_parameters(profile_name, region, unique_names)
+            .await
+    }
+
+    async fn get_parameter_across_regions(
+        &self,
+        profile_name: &str,
+        parameter_name: String,
+        regions: Vec<String>,
+    ) -> error_stack::Result<ParameterRegionComparison, ParameterDataError> {
+        let mut unique_regions: Vec<String> = vec![];
+        for region in regions {
+            if !unique_regions.contains(&region) {
+                unique_regions.push(region);
             }
-            Value::Multiple(values) => {
-                let parameter: Parameter =
-                    (request.name.to_string(), ParameterValue::StringList(values)).into();
+        }
+
+        // A region that cannot be queried, e.g. because it is not enabled for the account, must
+        // not hide the others.
+        let results = join_all(unique_regions.iter().map(|region| {
+            self.parameter_data_spi.load_parameters(
+                profile_name,
+                Some(region.clone()),
+                vec![parameter_name.clone()],
+            )
+        }))
+        .await;
 
-                self.parameter_data_spi
-                    .upsert_parameter(profile_name, parameter)
-                    .await
+        let mut comparison = ParameterRegionComparison::new(parameter_name.clone());
+        for (region, result) in unique_regions.into_iter().zip(results) {
+            match result {
+                Ok(parameter_set) => {
+                    let parameter = parameter_set
+                        .values()
+                        .iter()
+                        .find(|parameter| parameter.name == parameter_name)
+                        .cloned();
+                    comparison.add_region(region, parameter);
+                }
+                Err(err) => comparison.add_failed_region(region, ParameterDataError::from(err)),
             }
         }
+
+        Ok(comparison)
+    }
+
+    async fn get_parameter_tree(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<ParameterTree, ParameterDataError> {
+        let path = ParameterTree::normalize_path(&path);
+        let parameters = self
+            .parameter_data_spi
+            .load_parameter_infos_by_path(profile_name, region.clone(), path.clone(), recursive)
+            .await?;
+
+        if recursive {
+            return Ok(ParameterTree::from_parameters(&path, parameters));
+        }
+
+        // GetParametersByPath does not report sub-paths, so they are derived from the names below
+        // the expanded level. They are listed every time, so the tree never shows stale paths.
+        let names = self
+            .parameter_data_spi
+            .load_parameter_names_by_path(profile_name, region, path.clone())
+            .await?;
+        let index = ParameterPathIndex::new(&path, names);
+
+        Ok(ParameterTree::from_level(&path, parameters, &index))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parameters::core::domain::{Parameter, ParameterValue};
+    use crate::common::secure_string::SecureString;
+    use crate::parameters::core::diff_service::ValueDiff;
+    use crate::parameters::core::domain::{
+        DeletionStatus, Parameter, ParameterMetadata, ParameterType, ParameterValue,
+        ParameterVersion,
+    };
     use crate::parameters::core::spi::MockParameterDataSPI;
+    use crate::profiles::core::domain::{ProtectionDenial, ProtectionLevel};
+    use crate::profiles::core::spi::MockProfileProtectionSPI;
     use spectral::prelude::*;
+    use std::collections::HashMap;
 
     use super::*;
 
+    fn protection_guard_with(
+        protection_levels: Vec<(&str, ProtectionLevel)>,
+    ) -> Arc<ProfileProtectionGuard> {
+        let protection_levels: HashMap<String, ProtectionLevel> = protection_levels
+            .into_iter()
+            .map(|(profile_name, level)| (profile_name.to_string(), level))
+            .collect();
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(move || Ok(protection_levels.clone()));
+
+        Arc::new(ProfileProtectionGuard::new(Box::new(protection_spi_mock)))
+    }
+
     #[tokio::test]
     async fn should_load_available_parameters() {
         let input_profile_name = "dev";
@@ -88,15 +714,96 @@ mod tests {
         let mut mock_parameter_data_spi = MockParameterDataSPI::new();
         mock_parameter_data_spi
             .expect_load_available_parameter_names()
-            .with(eq(input_profile_name))
-            .returning(move |_| Ok(vec![output_param_name.to_string()]));
-        let cut = ParameterService::new(Box::new(mock_parameter_data_spi));
+            .with(eq(input_profile_name), eq(None::<String>))
+            .returning(move |_, _| Ok(vec![output_param_name.to_string()]));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
 
-        let result = cut.get_available_parameters(input_profile_name).await;
+        let result = cut.get_available_parameters(input_profile_name, None).await;
 
         assert_that!(result).is_ok();
     }
 
+    #[tokio::test]
+    async fn should_pass_filters_and_cursor_when_loading_page() {
+        let filters = ParameterFilters {
+            name_prefix: Some("/app".to_string()),
+            ..ParameterFilters::default()
+        };
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_names_page()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(filters.clone()),
+                eq(Some("cursor".to_string())),
+                eq(Some(50)),
+            )
+            .returning(|_, _, _, _, _| {
+                Ok(ParameterNamePage {
+                    names: vec!["/app/name".to_string()],
+                    next_cursor: None,
+                })
+            });
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_available_parameters_page(
+                "dev",
+                None,
+                filters,
+                Some("cursor".to_string()),
+                Some(500),
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+        assert_that!(actual.unwrap().names).has_length(1);
+    }
+
+    #[tokio::test]
+    async fn should_load_parameter_metadata() {
+        let output_info = ParameterInfo {
+            name: "/app/password".to_string(),
+            parameter_type: ParameterType::SecureString,
+            version: Some(2),
+            last_modified_date: None,
+            metadata: ParameterMetadata {
+                last_modified_user: Some("arn:aws:iam::000000000000:user/alice".to_string()),
+                ..ParameterMetadata::default()
+            },
+        };
+        let expected_info = output_info.clone();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_metadata()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(vec!["/app/password".to_string()]),
+            )
+            .returning(move |_, _, _| Ok(vec![output_info.clone()]));
+        mock_parameter_data_spi.expect_load_parameters().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_parameter_metadata("dev", None, vec!["/app/password".to_string()])
+            .await;
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to(vec![expected_info]);
+    }
+
     #[tokio::test]
     async fn should_load_parameters() {
         let input_profile_name = "dev";
@@ -113,104 +820,1410 @@ mod tests {
             .expect_load_parameters()
             .with(
                 eq(input_profile_name),
+                eq(None::<String>),
                 eq(vec![input_param_name.to_string()]),
             )
-            .returning(move |_, _| {
+            .returning(move |_, _, _| {
                 let mut parameters = ParameterSet::new();
                 parameters.add_all_parameters(vec![output_param.clone()]);
                 Ok(parameters)
             });
-        let cut = ParameterService::new(Box::new(mock_parameter_data_spi));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
 
         let result = cut
-            .get_parameters(input_profile_name, vec![input_param_name.to_string()])
+            .get_parameters(input_profile_name, None, vec![input_param_name.to_string()])
             .await;
 
         assert_that!(result).is_ok();
     }
 
     #[tokio::test]
-    async fn should_upsert_string_parameter() {
-        let input_profile_name = "dev";
-        let parameter_value = "value1".to_string();
-        let parameter_name = "param1".to_string();
-        let input_parameter: Parameter = (
-            parameter_name.clone(),
-            ParameterValue::String(parameter_value.clone()),
-        )
-            .into();
+    async fn should_reject_invalid_selector_without_loading_parameters() {
         let mut mock_parameter_data_spi = MockParameterDataSPI::new();
-        mock_parameter_data_spi
-            .expect_upsert_parameter()
-            .with(eq(input_profile_name), eq(input_parameter))
-            .returning(|_, _| Ok(()));
-        let cut = ParameterService::new(Box::new(mock_parameter_data_spi));
+        mock_parameter_data_spi.expect_load_parameters().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
 
-        let actual = cut
-            .set_parameter(
-                input_profile_name,
-                (parameter_name, Value::Single(parameter_value)).into(),
+        let result = cut
+            .get_parameters(
+                "dev",
+                None,
+                vec!["/app/db-url:live".to_string(), "/app/db-url:".to_string()],
             )
             .await;
 
-        assert_that!(actual).is_ok();
+        assert_that!(result).is_err();
+        assert_that!(ParameterDataError::from(result.unwrap_err())).is_equal_to(
+            ParameterDataError::InvalidParameter(
+                "invalid version or label in '/app/db-url:'".to_string(),
+            ),
+        );
     }
 
     #[tokio::test]
-    async fn should_upsert_secure_parameter() {
-        let input_profile_name = "dev";
-        let parameter_value = "value1".to_string();
-        let parameter_name = "param1".to_string();
-        let input_parameter: Parameter = (
-            parameter_name.clone(),
-            ParameterValue::SecureString(SecureString::from(parameter_value.clone())),
-        )
-            .into();
+    async fn should_list_labels_without_loading_decrypted_history() {
+        let labels = vec![ParameterLabel {
+            label: "live".to_string(),
+            version: 2,
+        }];
         let mut mock_parameter_data_spi = MockParameterDataSPI::new();
         mock_parameter_data_spi
-            .expect_upsert_parameter()
-            .with(eq(input_profile_name), eq(input_parameter))
-            .returning(|_, _| Ok(()));
-        let cut = ParameterService::new(Box::new(mock_parameter_data_spi));
+            .expect_load_parameter_labels()
+            .with(eq("dev"), eq(None::<String>), eq("/app/config".to_string()))
+            .returning({
+                let labels = labels.clone();
+                move |_, _, _| Ok(labels.clone())
+            });
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
 
         let actual = cut
-            .set_parameter(
-                input_profile_name,
-                SetParameterRequest::new(
-                    parameter_name,
-                    Value::Single(parameter_value),
-                    Some(true),
-                ),
-            )
+            .get_parameter_labels("dev", None, "/app/config".to_string())
             .await;
 
-        assert_that!(actual).is_ok();
+        assert_that!(actual).is_ok().is_equal_to(labels);
     }
 
     #[tokio::test]
-    async fn should_upsert_string_list_parameter() {
-        let input_profile_name = "dev";
-        let parameter_value = "value1".to_string();
-        let parameter_name = "param1".to_string();
-        let input_parameter: Parameter = (
-            parameter_name.clone(),
-            ParameterValue::StringList(vec![parameter_value.clone()]),
-        )
+    async fn should_label_parameter_version() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_label_parameter_version()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app/config".to_string()),
+                eq(2),
+                eq(vec!["live".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, name, version, labels| {
+                Ok(ParameterLabelUpdate {
+                    name,
+                    version,
+                    labels,
+                    invalid_labels: vec![],
+                })
+            });
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .label_parameter_version(
+                "dev",
+                None,
+                "/app/config".to_string(),
+                2,
+                vec!["live".to_string()],
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_unlabel_without_labels_or_for_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_unlabel_parameter_version()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let without_labels = cut
+            .unlabel_parameter_version("dev", None, "/app/config".to_string(), 2, vec![], None)
+            .await;
+        let read_only = cut
+            .unlabel_parameter_version(
+                "prod",
+                None,
+                "/app/config".to_string(),
+                2,
+                vec!["live".to_string()],
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            ParameterDataError::from(without_labels.unwrap_err()),
+            ParameterDataError::InvalidParameter(_)
+        ));
+        assert!(matches!(
+            ParameterDataError::from(read_only.unwrap_err()),
+            ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::LabelParameter,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_upsert_string_parameter() {
+        let input_profile_name = "dev";
+        let parameter_value = "value1".to_string();
+        let parameter_name = "param1".to_string();
+        let input_parameter: Parameter = (
+            parameter_name.clone(),
+            ParameterValue::String(parameter_value.clone()),
+        )
+            .into();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .with(
+                eq(input_profile_name),
+                eq(None::<String>),
+                eq(input_parameter),
+                eq(WriteMode::Overwrite),
+                eq(vec![]),
+            )
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter(
+                input_profile_name,
+                None,
+                (
+                    parameter_name,
+                    Value::Single(SecureString::from(parameter_value)),
+                )
+                    .into(),
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    fn request_with_expected_version(expected_version: i64) -> SetParameterRequest {
+        SetParameterRequest {
+            expected_version: Some(expected_version),
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn should_upsert_parameter_when_expected_version_is_current() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_version()
+            .with(eq("dev"), eq(None::<String>), eq("/app/name".to_string()))
+            .returning(|_, _, _| Ok(Some(3)));
+        mock_parameter_data_spi.expect_load_parameters().never();
+        mock_parameter_data_spi
+            .expect_load_parameter_metadata()
+            .never();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter("dev", None, request_with_expected_version(3), None)
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_overwrite_newer_version() {
+        let mut described = parameter_info("/app/name");
+        described.version = Some(3);
+        let loaded = described.clone();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_version()
+            .returning(|_, _, _| Ok(Some(4)));
+        mock_parameter_data_spi
+            .expect_load_parameter_metadata()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(vec!["/app/name".to_string()]),
+            )
+            .returning(move |_, _, _| Ok(vec![loaded.clone()]));
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter("dev", None, request_with_expected_version(3), None)
+            .await;
+
+        assert_that!(actual).is_err();
+        // The metadata lags behind, but the error reports the version that was read.
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::ConcurrentModification(Box::new(ParameterInfo {
+                version: Some(4),
+                ..described
+            })),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_recreate_parameter_deleted_in_the_meantime() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_version()
+            .returning(|_, _, _| Ok(None));
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter("dev", None, request_with_expected_version(3), None)
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::ParameterNotFound("/app/name".to_string()),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_tag_overwritten_parameter_once_it_has_been_written() {
+        let tags = vec![ParameterTag::new(
+            "team".to_string(),
+            "payments".to_string(),
+        )];
+        let mut sequence = mockall::Sequence::new();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _, _| Ok(()));
+        mock_parameter_data_spi
+            .expect_add_parameter_tags()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app/name".to_string()),
+                eq(tags.clone()),
+            )
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tags,
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_create_parameter_together_with_its_tags() {
+        let tags = vec![ParameterTag::new(
+            "team".to_string(),
+            "payments".to_string(),
+        )];
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf({
+                let tags = tags.clone();
+                move |_, _, parameter, mode, creation_tags| {
+                    parameter.name == "/app/name"
+                        && *mode == WriteMode::CreateOnly
+                        && *creation_tags == tags
+                }
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        mock_parameter_data_spi.expect_add_parameter_tags().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tags,
+            mode: WriteMode::CreateOnly,
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_write_parameter_with_reserved_tag_key() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        mock_parameter_data_spi.expect_add_parameter_tags().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tags: vec![ParameterTag::new(
+                "aws:createdBy".to_string(),
+                "me".to_string(),
+            )],
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::InvalidParameter("invalid tag key 'aws:createdBy'".to_string()),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_remove_tags_for_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_remove_parameter_tags()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = cut
+            .remove_parameter_tags(
+                "prod",
+                None,
+                "/app/name".to_string(),
+                vec!["team".to_string()],
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::TagParameter,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_upsert_secure_parameter() {
+        let input_profile_name = "dev";
+        let parameter_value = "value1".to_string();
+        let parameter_name = "param1".to_string();
+        let input_parameter: Parameter = (
+            parameter_name.clone(),
+            ParameterValue::SecureString(SecureString::from(parameter_value.clone())),
+        )
+            .into();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .with(
+                eq(input_profile_name),
+                eq(None::<String>),
+                eq(input_parameter),
+                eq(WriteMode::Overwrite),
+                eq(vec![]),
+            )
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter(
+                input_profile_name,
+                None,
+                SetParameterRequest::new(
+                    parameter_name,
+                    Value::Single(SecureString::from(parameter_value)),
+                    Some(true),
+                ),
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_pass_deserialized_secret_to_spi_without_copying_it() {
+        let secret = "super_secret_password".to_string();
+        let buffer = secret.as_ptr() as usize;
+        let request: SetParameterRequest = serde_json::from_value(serde_json::json!({
+            "name": "/app/db-password",
+            "value": secret,
+            "secure": true
+        }))
+        .unwrap();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(move |_, _, parameter, _, _| match &parameter.value {
+                ParameterValue::SecureString(value) => {
+                    value.expose(|bytes| bytes.as_ptr() as usize) == buffer
+                }
+                _ => false,
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_upsert_string_list_parameter() {
+        let input_profile_name = "dev";
+        let parameter_value = "value1".to_string();
+        let parameter_name = "param1".to_string();
+        let input_parameter: Parameter = (
+            parameter_name.clone(),
+            ParameterValue::StringList(vec![parameter_value.clone()]),
+        )
+            .into();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .with(
+                eq(input_profile_name),
+                eq(None::<String>),
+                eq(input_parameter),
+                eq(WriteMode::Overwrite),
+                eq(vec![]),
+            )
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter(
+                input_profile_name,
+                None,
+                (parameter_name, Value::Multiple(vec![parameter_value])).into(),
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_upsert_secure_string_list_parameter() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest::new(
+            "param1".to_string(),
+            Value::Multiple(vec!["value1".to_string()]),
+            Some(true),
+        );
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_create_parameter_together_with_its_tags() {
+        let tags = vec![ParameterTag::new(
+            "team".to_string(),
+            "payments".to_string(),
+        )];
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf({
+                let tags = tags.clone();
+                move |_, _, parameter, mode, creation_tags| {
+                    parameter.name == "/app/name"
+                        && *mode == WriteMode::CreateOnly
+                        && *creation_tags == tags
+                }
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        mock_parameter_data_spi.expect_add_parameter_tags().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tags,
+            mode: WriteMode::CreateOnly,
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_write_parameter_with_reserved_tag_key() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        mock_parameter_data_spi.expect_add_parameter_tags().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tags: vec![ParameterTag::new(
+                "aws:createdBy".to_string(),
+                "me".to_string(),
+            )],
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::InvalidParameter("invalid tag key 'aws:createdBy'".to_string()),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_remove_tags_for_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_remove_parameter_tags()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = cut
+            .remove_parameter_tags(
+                "prod",
+                None,
+                "/app/name".to_string(),
+                vec!["team".to_string()],
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::TagParameter,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_upsert_secure_parameter() {
+        let input_profile_name = "dev";
+        let parameter_value = "value1".to_string();
+        let parameter_name = "param1".to_string();
+        let input_parameter: Parameter = (
+            parameter_name.clone(),
+            ParameterValue::SecureString(SecureString::from(parameter_value.clone())),
+        )
+            .into();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .with(
+                eq(input_profile_name),
+                eq(None::<String>),
+                eq(input_parameter),
+                eq(WriteMode::Overwrite),
+                eq(vec![]),
+            )
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .set_parameter(
+                input_profile_name,
+                None,
+                SetParameterRequest::new(
+                    parameter_name,
+                    Value::Single(SecureString::from(parameter_value)),
+                    Some(true),
+                ),
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_pass_deserialized_secret_to_spi_without_copying_it() {
+        let secret = "super_secret_password".to_string();
+        let buffer = secret.as_ptr() as usize;
+        let request: SetParameterRequest = serde_json::from_value(serde_json::json!({
+            "name": "/app/db-password",
+            "value": secret,
+            "secure": true
+        }))
+        .unwrap();
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(move |_, _, parameter, _, _| match &parameter.value {
+                ParameterValue::SecureString(value) => {
+                    value.expose(|bytes| bytes.as_ptr() as usize) == buffer
+                }
+                _ => false,
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_upsert_string_list_parameter() {
+        let input_profile_name = "dev";
+        let parameter_value = "value1".to_string();
+        let parameter_name = "param1".to_string();
+        let input_parameter: Parameter = (
+            parameter_name.clone(),
+            ParameterValue::StringList(vec![parameter_value.clone()]),
+        )
             .into();
         let mut mock_parameter_data_spi = MockParameterDataSPI::new();
         mock_parameter_data_spi
             .expect_upsert_parameter()
-            .with(eq(input_profile_name), eq(input_parameter))
-            .returning(|_, _| Ok(()));
-        let cut = ParameterService::new(Box::new(mock_parameter_data_spi));
+            .with(
+                eq(input_profile_name),
+                eq(None::<String>),
+                eq(input_parameter),
+                eq(WriteMode::Overwrite),
+                eq(vec![]),
+            )
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
 
         let actual = cut
             .set_parameter(
                 input_profile_name,
+                None,
                 (parameter_name, Value::Multiple(vec![parameter_value])).into(),
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_upsert_secure_string_list_parameter() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            secure: Some(true),
+            ..(
+                "param1".to_string(),
+                Value::Multiple(vec!["value1".to_string()]),
+            )
+                .into()
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_err();
+        assert!(matches!(
+            actual.unwrap_err().current_context(),
+            ParameterDataError::InvalidParameter(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_create_advanced_secure_parameter_with_customer_managed_key() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(|_, _, parameter, mode, _| {
+                parameter.metadata.tier == Some(ParameterTier::Advanced)
+                    && parameter.metadata.key_id == Some("alias/app".to_string())
+                    && parameter.metadata.description == Some("Database password".to_string())
+                    && *mode == WriteMode::CreateOnly
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            description: Some("Database password".to_string()),
+            tier: Some(ParameterTier::Advanced),
+            key_id: Some("alias/app".to_string()),
+            mode: WriteMode::CreateOnly,
+            ..SetParameterRequest::new(
+                "/app/password".to_string(),
+                Value::Single(SecureString::from("secret")),
+                Some(true),
+            )
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_not_upsert_standard_parameter_exceeding_four_kilobytes() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            tier: Some(ParameterTier::Standard),
+            ..SetParameterRequest::from((
+                "/app/certificate".to_string(),
+                Value::Single(SecureString::from("x".repeat(4097))),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_err();
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::ParameterTooLarge(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_upsert_plain_parameter_with_kms_key() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+        let request = SetParameterRequest {
+            key_id: Some("alias/app".to_string()),
+            ..SetParameterRequest::from((
+                "/app/name".to_string(),
+                Value::Single(SecureString::from("custodian")),
+            ))
+        };
+
+        let actual = cut.set_parameter("dev", None, request, None).await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::InvalidParameter(
+                "only secure parameters are encrypted with a KMS key".to_string(),
+            ),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_load_parameter_from_each_region_once() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters()
+            .with(
+                eq("dev"),
+                eq(Some("eu-west-1".to_string())),
+                eq(vec!["param1".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                let mut parameters = ParameterSet::new();
+                parameters.add_parameter(Parameter::new(
+                    "param1".to_string(),
+                    ParameterValue::String("value1".to_string()),
+                    Some(2),
+                    None,
+                    None,
+                ));
+                Ok(parameters)
+            });
+        mock_parameter_data_spi
+            .expect_load_parameters()
+            .with(
+                eq("dev"),
+                eq(Some("us-east-1".to_string())),
+                eq(vec!["param1".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(ParameterSet::new()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_parameter_across_regions(
+                "dev",
+                "param1".to_string(),
+                vec![
+                    "eu-west-1".to_string(),
+                    "us-east-1".to_string(),
+                    "eu-west-1".to_string(),
+                ],
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+        let comparison = actual.unwrap();
+        assert_that!(comparison.regions).has_length(2);
+        assert_that!(comparison.regions[0]
+            .parameter
+            .as_ref()
+            .and_then(|p| p.version))
+        .is_equal_to(Some(2));
+        assert_that!(comparison.regions[1].parameter).is_none();
+    }
+
+    #[tokio::test]
+    async fn should_record_error_of_failing_region_and_keep_the_others() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameters()
+            .with(
+                eq("dev"),
+                eq(Some("eu-west-1".to_string())),
+                eq(vec!["param1".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                let mut parameters = ParameterSet::new();
+                parameters.add_parameter(Parameter::new(
+                    "param1".to_string(),
+                    ParameterValue::String("value1".to_string()),
+                    Some(1),
+                    None,
+                    None,
+                ));
+                Ok(parameters)
+            });
+        mock_parameter_data_spi
+            .expect_load_parameters()
+            .with(
+                eq("dev"),
+                eq(Some("ap-east-1".to_string())),
+                eq(vec!["param1".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, _| Err(Report::new(ParameterDataError::ParameterDataLoadError)));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_parameter_across_regions(
+                "dev",
+                "param1".to_string(),
+                vec!["eu-west-1".to_string(), "ap-east-1".to_string()],
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+        let comparison = actual.unwrap();
+        assert_that!(comparison.regions).has_length(2);
+        assert_that!(comparison.regions[0].parameter).is_some();
+        assert_that!(comparison.regions[0].error).is_none();
+        assert_that!(comparison.regions[1].region.as_str()).is_equal_to("ap-east-1");
+        assert_that!(comparison.regions[1].error)
+            .is_equal_to(Some(ParameterDataError::ParameterDataLoadError));
+        assert_that!(comparison.is_in_sync()).is_false();
+    }
+
+    #[tokio::test]
+    async fn should_not_upsert_parameter_of_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = cut
+            .set_parameter(
+                "prod",
+                None,
+                (
+                    "param1".to_string(),
+                    Value::Single(SecureString::from("value1")),
+                )
+                    .into(),
+                Some("token".to_string()),
+            )
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::SetParameter,
+                profile_names: vec!["prod".to_string()],
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_upsert_parameter_of_confirm_required_profile_once_confirmed() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ConfirmRequired)]),
+        );
+        let request: SetParameterRequest = (
+            "param1".to_string(),
+            Value::Single(SecureString::from("value1")),
+        )
+            .into();
+
+        let unconfirmed = cut.set_parameter("prod", None, request.clone(), None).await;
+        let confirmation_token = match ParameterDataError::from(unconfirmed.unwrap_err()) {
+            ParameterDataError::ProfileProtected(ProtectionDenial::ConfirmationRequired {
+                confirmation_token,
+                ..
+            }) => confirmation_token,
+            other => panic!("expected confirmation to be required, got {:?}", other),
+        };
+        let actual = cut
+            .set_parameter("prod", None, request, Some(confirmation_token))
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_delete_each_parameter_once() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_delete_parameters()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq(vec!["/app/name".to_string(), "/app/url".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(vec![
+                    ParameterDeletion::new("/app/name".to_string(), DeletionStatus::Deleted),
+                    ParameterDeletion::new("/app/url".to_string(), DeletionStatus::NotFound),
+                ])
+            });
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .delete_parameters(
+                "dev",
+                None,
+                vec![
+                    "/app/url".to_string(),
+                    "/app/name".to_string(),
+                    "/app/url".to_string(),
+                ],
+                None,
+            )
+            .await;
+
+        assert_that!(actual).is_ok().has_length(2);
+    }
+
+    #[tokio::test]
+    async fn should_not_delete_parameter_of_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi.expect_delete_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = cut
+            .delete_parameter("prod", None, "/app/name".to_string(), None)
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::DeleteParameter,
+                profile_names: vec!["prod".to_string()],
+            }),
+        );
+    }
+
+    fn history_with_values(values: Vec<&str>) -> ParameterHistory {
+        let versions = values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| ParameterVersion {
+                version: index as i64 + 1,
+                value: ParameterValue::String(value.to_string()),
+                last_modified_date: None,
+                labels: vec![],
+                metadata: ParameterMetadata::default(),
+            })
+            .collect();
+
+        ParameterHistory::new("/app/config".to_string(), versions)
+    }
+
+    #[tokio::test]
+    async fn should_compare_versions_from_history() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .with(eq("dev"), eq(None::<String>), eq("/app/config".to_string()))
+            .returning(|_, _, _| {
+                Ok(history_with_values(vec![
+                    r#"{"port": 5432}"#,
+                    r#"{"port": 6432}"#,
+                ]))
+            });
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .compare_parameter_versions("dev", None, "/app/config".to_string(), 1, 2, false)
+            .await;
+
+        assert_that!(actual).is_ok();
+        let diff = actual.unwrap();
+        assert!(matches!(diff.value, ValueDiff::Json { changes } if changes.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_compare_unknown_version() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .returning(|_, _, _| Ok(history_with_values(vec!["a"])));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .compare_parameter_versions("dev", None, "/app/config".to_string(), 1, 5, false)
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::InvalidParameter(
+                "parameter '/app/config' has no version 5".to_string(),
+            ),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_restore_version_with_its_original_type_key_and_tier() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .with(eq("dev"), eq(None::<String>), eq("/app/config".to_string()))
+            .returning(|_, _, _| {
+                let mut history = history_with_values(vec!["old", "bad"]);
+                history.versions[0].value = ParameterValue::SecureString(SecureString::from("old"));
+                history.versions[0].metadata = ParameterMetadata {
+                    tier: Some(ParameterTier::Advanced),
+                    key_id: Some("alias/app".to_string()),
+                    ..ParameterMetadata::default()
+                };
+                Ok(history)
+            });
+        mock_parameter_data_spi
+            .expect_upsert_parameter()
+            .withf(|profile_name, _, parameter, mode, _| {
+                profile_name == "dev"
+                    && parameter.name == "/app/config"
+                    && parameter.value == ParameterValue::SecureString(SecureString::from("old"))
+                    && parameter.metadata.tier == Some(ParameterTier::Advanced)
+                    && parameter.metadata.key_id == Some("alias/app".to_string())
+                    && *mode == WriteMode::Overwrite
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .restore_parameter_version("dev", None, "/app/config".to_string(), 1, 2, None)
+            .await;
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_refuse_to_restore_version_when_parameter_changed_in_the_meantime() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .returning(|_, _, _| Ok(history_with_values(vec!["old", "bad", "fixed"])));
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .restore_parameter_version("dev", None, "/app/config".to_string(), 1, 2, None)
+            .await;
+
+        assert_that!(actual).is_err();
+        let error = ParameterDataError::from(actual.unwrap_err());
+        assert!(matches!(
+            error,
+            ParameterDataError::ConcurrentModification(current) if current.version == Some(3)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_restore_version_for_read_only_profile() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_history()
+            .never();
+        mock_parameter_data_spi.expect_upsert_parameter().never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = cut
+            .restore_parameter_version("prod", None, "/app/config".to_string(), 1, 2, None)
+            .await;
+
+        assert_that!(actual).is_err();
+        assert!(matches!(
+            ParameterDataError::from(actual.unwrap_err()),
+            ParameterDataError::ProfileProtected(_)
+        ));
+    }
+
+    fn parameter_info(name: &str) -> ParameterInfo {
+        ParameterInfo::from(&Parameter::from((
+            name.to_string(),
+            ParameterValue::String("value".to_string()),
+        )))
+    }
+
+    #[tokio::test]
+    async fn should_load_single_level_of_parameter_tree() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .with(
+                eq("dev"),
+                eq(None::<String>),
+                eq("/app".to_string()),
+                eq(false),
             )
+            .returning(|_, _, _, _| Ok(vec![parameter_info("/app/name")]));
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .with(eq("dev"), eq(None::<String>), eq("/app".to_string()))
+            .returning(|_, _, _| Ok(vec!["/app/name".to_string(), "/app/db/url".to_string()]));
+        mock_parameter_data_spi
+            .expect_load_parameters_by_path()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_parameter_tree("dev", None, "/app/".to_string(), false)
+            .await;
+
+        assert_that!(actual).is_ok();
+        let tree = actual.unwrap();
+        assert_that!(tree.parameters).is_equal_to(vec![parameter_info("/app/name")]);
+        assert_that!(tree.sub_paths).is_equal_to(vec![ParameterTree::new("/app/db")]);
+    }
+
+    #[tokio::test]
+    async fn should_list_names_below_each_expanded_level_only() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .withf(|_, _, _, recursive| !recursive)
+            .times(2)
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .with(eq("dev"), eq(None::<String>), eq("/app".to_string()))
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(vec![
+                    "/app/db/url".to_string(),
+                    "/app/db/pool/size".to_string(),
+                ])
+            });
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .with(eq("dev"), eq(None::<String>), eq("/app/db".to_string()))
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(vec![
+                    "/app/db/url".to_string(),
+                    "/app/db/pool/size".to_string(),
+                ])
+            });
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let app = cut
+            .get_parameter_tree("dev", None, "/app".to_string(), false)
+            .await;
+        let db = cut
+            .get_parameter_tree("dev", None, "/app/db".to_string(), false)
+            .await;
+
+        assert_that!(app.unwrap().sub_paths).is_equal_to(vec![ParameterTree::new("/app/db")]);
+        assert_that!(db.unwrap().sub_paths).is_equal_to(vec![ParameterTree::new("/app/db/pool")]);
+    }
+
+    #[tokio::test]
+    async fn should_show_sub_paths_created_outside_of_the_app_when_level_is_expanded_again() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        let mut sequence = mockall::Sequence::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _| Ok(vec!["/app/name".to_string()]));
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _| Ok(vec!["/app/name".to_string(), "/app/db/url".to_string()]));
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let before = cut
+            .get_parameter_tree("dev", None, "/app".to_string(), false)
+            .await;
+        let after = cut
+            .get_parameter_tree("dev", None, "/app".to_string(), false)
+            .await;
+
+        assert_that!(before.unwrap().sub_paths).is_empty();
+        assert_that!(after.unwrap().sub_paths).is_equal_to(vec![ParameterTree::new("/app/db")]);
+    }
+
+    #[tokio::test]
+    async fn should_load_parameter_tree_recursively_without_listing_names() {
+        let mut mock_parameter_data_spi = MockParameterDataSPI::new();
+        mock_parameter_data_spi
+            .expect_load_parameter_infos_by_path()
+            .with(eq("dev"), eq(None::<String>), eq("/".to_string()), eq(true))
+            .returning(|_, _, _, _| Ok(vec![parameter_info("/app/db/url")]));
+        mock_parameter_data_spi
+            .expect_load_parameter_names_by_path()
+            .never();
+        let cut = ParameterService::new(
+            Box::new(mock_parameter_data_spi),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = cut
+            .get_parameter_tree("dev", None, "/".to_string(), true)
             .await;
 
         assert_that!(actual).is_ok();
+        let tree = actual.unwrap();
+        assert_that!(tree.sub_paths[0].sub_paths[0].parameters).has_length(1);
+        assert_that!(tree.sub_paths[0].sub_paths[0].expanded).is_true();
     }
 }
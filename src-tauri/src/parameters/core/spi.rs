@@ -2,26 +2,183 @@ use async_trait::async_trait;
 #[cfg(test)]
 use mockall::automock;
 
-use crate::parameters::core::domain::{Parameter, ParameterSet};
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::domain::{
+    Parameter, ParameterDeletion, ParameterFilters, ParameterHistory, ParameterInfo,
+    ParameterLabel, ParameterLabelUpdate, ParameterNamePage, ParameterSet, ParameterTag, WriteMode,
+};
 use crate::parameters::core::error::ParameterDataError;
 
+/// A region of `None` targets the profile's default region.
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait ParameterDataSPI: Send + Sync {
     async fn load_available_parameter_names(
         &self,
         profile_name: &str,
+        region: Option<String>,
     ) -> error_stack::Result<Vec<String>, ParameterDataError>;
 
+    /// Loads a single page of names. A cursor of `None` starts with the first page.
+    async fn load_parameter_names_page(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError>;
+
+    /// Names may carry a `:version` or `:label` selector, which is kept on the loaded parameter.
+    /// Only the values are loaded, the metadata comes from `load_parameter_metadata`.
     async fn load_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter_names: Vec<String>,
     ) -> error_stack::Result<ParameterSet, ParameterDataError>;
 
+    /// Loads the metadata of the named parameters without their values, so nothing is decrypted.
+    async fn load_parameter_metadata(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError>;
+
+    /// The latest version of the parameter, read with GetParameters without decrypting it, or
+    /// `None` if the parameter does not exist.
+    async fn load_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Option<i64>, ParameterDataError>;
+
+    /// Every version of the parameter, with decrypted values.
+    async fn load_parameter_history(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterHistory, ParameterDataError>;
+
+    /// The labels of every version, sorted by label. The history is loaded without decrypting
+    /// the values.
+    async fn load_parameter_labels(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterLabel>, ParameterDataError>;
+
+    /// Writes the value together with the metadata that is set on the parameter. Parameter Store
+    /// only accepts tags when the parameter is created, so tags must be empty for overwrites.
     async fn upsert_parameter(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter: Parameter,
+        mode: WriteMode,
+        tags: Vec<ParameterTag>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    /// Attaches the labels to the version, moving them away from the version they were on.
+    async fn label_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError>;
+
+    async fn unlabel_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError>;
+
+    async fn load_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterTag>, ParameterDataError>;
+
+    /// Tags with a key the parameter already has replace the existing value.
+    async fn add_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tags: Vec<ParameterTag>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    async fn remove_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tag_keys: Vec<String>,
+    ) -> error_stack::Result<(), ParameterDataError>;
+
+    async fn delete_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterDeletion, ParameterDataError>;
+
+    /// Reports each name as deleted, not found or failed, in the order of the given names. Names
+    /// that could not be deleted do not stop the others.
+    async fn delete_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterDeletion>, ParameterDataError>;
+
+    /// Loads the parameters directly below `path`, or every parameter beneath it if `recursive`.
+    async fn load_parameters_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<Parameter>, ParameterDataError>;
+
+    /// Like `load_parameters_by_path`, but without the values, so nothing is decrypted.
+    async fn load_parameter_infos_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError>;
+
+    /// Names of every parameter beneath `path`, without loading the values.
+    async fn load_parameter_names_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+    ) -> error_stack::Result<Vec<String>, ParameterDataError>;
+}
+
+/// Access to the files parameters are exported to and imported from.
+#[cfg_attr(test, automock)]
+pub trait ParameterFileSPI: Send + Sync {
+    fn read_file(&self, file_path: &str) -> error_stack::Result<String, ParameterDataError>;
+
+    /// Files that contain secrets are only made readable by the owner.
+    fn write_file(
+        &self,
+        file_path: &str,
+        content: &SecureString,
+        contains_secrets: bool,
     ) -> error_stack::Result<(), ParameterDataError>;
 }
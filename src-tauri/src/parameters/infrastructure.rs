@@ -1 +1,3 @@
 pub mod aws;
+pub mod file;
+pub mod memory;
@@ -2,17 +2,25 @@ use async_trait::async_trait;
 use aws_sdk_ssm::config::http::HttpResponse;
 use aws_sdk_ssm::error::{ProvideErrorMetadata, SdkError};
 use aws_sdk_ssm::operation::put_parameter::PutParameterError;
+use aws_sdk_ssm::primitives::DateTime as SSMDateTime;
 use aws_sdk_ssm::types::{
-    Parameter as SSMParameter, ParameterMetadata as SSMParamterMetadata,
-    ParameterType as SSMParamterType, ParameterType,
+    Parameter as SSMParameter, ParameterHistory as SSMParameterHistory, ParameterInlinePolicy,
+    ParameterMetadata as SSMParamterMetadata, ParameterStringFilter,
+    ParameterTier as SSMParameterTier, ParameterType as SSMParamterType, ResourceTypeForTagging,
+    Tag,
 };
 use aws_sdk_ssm::Client;
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use error_stack::{Report, ResultExt};
 
-use crate::common::aws::{localstack_endpoint, shared_config_loader, ssm_client};
+use crate::common::aws::{client_factory, encoded_authorization_message};
 use crate::common::secure_string::SecureString;
-use crate::parameters::core::domain::{Parameter, ParameterSet, ParameterValue};
+use crate::parameters::core::domain::{
+    DeletionStatus, Parameter, ParameterDeletion, ParameterFilters, ParameterHistory,
+    ParameterInfo, ParameterLabel, ParameterLabelUpdate, ParameterMetadata, ParameterNamePage,
+    ParameterPolicy, ParameterSet, ParameterTag, ParameterTier, ParameterType, ParameterValue,
+    ParameterVersion, WriteMode,
+};
 use crate::parameters::core::error::ParameterDataError;
 use crate::parameters::core::spi::ParameterDataSPI;
 
@@ -23,8 +31,9 @@ impl ParameterDataSPI for ParameterStoreAdapter {
     async fn load_available_parameter_names(
         &self,
         profile_name: &str,
+        region: Option<String>,
     ) -> error_stack::Result<Vec<String>, ParameterDataError> {
-        let client = Self::get_ssm_client(profile_name).await;
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
 
         let result: Result<Vec<_>, _> = client
             .describe_parameters()
@@ -51,14 +60,52 @@ impl ParameterDataSPI for ParameterStoreAdapter {
                 Ok(parameter_names)
             }
             Err(err) => {
-                let error_meta = err.meta();
-                let error_code = error_meta.code();
-                let error_message = error_meta.message();
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterMetaDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_names_page(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        if filters.label.is_some() {
+            return Self::load_labeled_parameter_names_page(&client, filters, cursor, page_size)
+                .await;
+        }
 
-                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+        let parameter_filters = Self::parameter_string_filters(&filters)?;
+        let result = client
+            .describe_parameters()
+            .set_parameter_filters(Some(parameter_filters).filter(|filters| !filters.is_empty()))
+            .set_next_token(cursor)
+            .set_max_results(page_size)
+            .send()
+            .await;
 
-                Err(Report::from(err)
-                    .change_context(ParameterDataError::ParameterMetaDataLoadError))
+        match result {
+            Ok(response) => Ok(ParameterNamePage {
+                names: response
+                    .parameters()
+                    .iter()
+                    .filter_map(|parameter_metadata| parameter_metadata.name.clone())
+                    .collect(),
+                next_cursor: response.next_token.clone(),
+            }),
+            Err(err) => {
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterMetaDataLoadError);
+
+                Err(Report::from(err).change_context(context))
             }
         }
     }
@@ -66,10 +113,11 @@ impl ParameterDataSPI for ParameterStoreAdapter {
     async fn load_parameters(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter_names: Vec<String>,
     ) -> error_stack::Result<ParameterSet, ParameterDataError> {
         let name_chunks = parameter_names.chunks(10);
-        let client = Self::get_ssm_client(profile_name).await;
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
         let mut parameters = vec![];
 
         for name_chunk in name_chunks {
@@ -83,12 +131,149 @@ impl ParameterDataSPI for ParameterStoreAdapter {
         Ok(parameter_set)
     }
 
+    async fn load_parameter_metadata(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+        let mut parameter_infos = vec![];
+
+        // A name filter takes up to 50 values.
+        for name_chunk in parameter_names.chunks(50) {
+            let metadata_chunk =
+                Self::describe_parameter_chunk(name_chunk.to_vec(), &client).await?;
+            for ssm_metadata in &metadata_chunk {
+                parameter_infos.push(Self::parse_ssm_parameter_metadata(ssm_metadata)?);
+            }
+        }
+
+        Ok(parameter_infos)
+    }
+
+    async fn load_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Option<i64>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .get_parameters()
+            .names(parameter_name.clone())
+            .with_decryption(false)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => Ok(response
+                .parameters()
+                .iter()
+                .find(|ssm_parameter| ssm_parameter.name.as_deref() == Some(&parameter_name))
+                .map(|ssm_parameter| ssm_parameter.version)),
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_history(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterHistory, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result: Result<Vec<_>, _> = client
+            .get_parameter_history()
+            .name(parameter_name.clone())
+            .with_decryption(true)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => {
+                let mut versions = vec![];
+                for ssm_history in responses
+                    .iter()
+                    .flat_map(|response| response.parameters().iter())
+                {
+                    versions.push(Self::parse_ssm_parameter_version(ssm_history)?);
+                }
+
+                Ok(ParameterHistory::new(parameter_name, versions))
+            }
+            Err(err) if err.meta().code() == Some("ParameterNotFound") => Err(Report::from(err)
+                .change_context(ParameterDataError::ParameterNotFound(parameter_name))),
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_labels(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterLabel>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result: Result<Vec<_>, _> = client
+            .get_parameter_history()
+            .name(parameter_name.clone())
+            .with_decryption(false)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => {
+                let mut labels: Vec<ParameterLabel> = responses
+                    .iter()
+                    .flat_map(|response| response.parameters().iter())
+                    .flat_map(|ssm_history| {
+                        ssm_history.labels().iter().map(|label| ParameterLabel {
+                            label: label.clone(),
+                            version: ssm_history.version,
+                        })
+                    })
+                    .collect();
+                labels.sort_by(|a, b| a.label.cmp(&b.label));
+
+                Ok(labels)
+            }
+            Err(err) if err.meta().code() == Some("ParameterNotFound") => Err(Report::from(err)
+                .change_context(ParameterDataError::ParameterNotFound(parameter_name))),
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
     async fn upsert_parameter(
         &self,
         profile_name: &str,
+        region: Option<String>,
         parameter: Parameter,
+        mode: WriteMode,
+        tags: Vec<ParameterTag>,
     ) -> error_stack::Result<(), ParameterDataError> {
-        let client = Self::get_ssm_client(profile_name).await;
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+        let parameter_name = parameter.name.clone();
+        let ssm_tags = Self::ssm_tags(tags)?;
 
         let handle_error = |err: SdkError<PutParameterError, HttpResponse>| {
             let error_meta = err.meta();
@@ -97,38 +282,91 @@ impl ParameterDataSPI for ParameterStoreAdapter {
 
             tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
 
-            ParameterDataError::ParameterDataWriteError(
-                error_message
-                    .unwrap_or("unknown parameter error")
-                    .to_string(),
-            )
+            if let Some(encoded_message) = encoded_authorization_message(error_message) {
+                return ParameterDataError::EncodedAuthorizationFailure(encoded_message);
+            }
+
+            let reason = error_message
+                .unwrap_or("unknown parameter error")
+                .to_string();
+            match err.as_service_error() {
+                Some(PutParameterError::ParameterAlreadyExists(_)) => {
+                    ParameterDataError::ParameterAlreadyExists(parameter_name.clone())
+                }
+                Some(PutParameterError::TooManyUpdates(_)) => ParameterDataError::Throttled(reason),
+                Some(PutParameterError::ParameterPatternMismatchException(_)) => {
+                    ParameterDataError::ParameterPatternMismatch(reason)
+                }
+                // Policies and more parameters than the standard tier allows need the advanced
+                // tier.
+                Some(PutParameterError::IncompatiblePolicyException(_))
+                | Some(PutParameterError::ParameterLimitExceeded(_)) => {
+                    ParameterDataError::ParameterTierMismatch(reason)
+                }
+                Some(PutParameterError::UnsupportedParameterType(_)) => {
+                    ParameterDataError::UnsupportedParameterType(reason)
+                }
+                Some(PutParameterError::HierarchyLevelLimitExceededException(_))
+                | Some(PutParameterError::HierarchyTypeMismatchException(_))
+                | Some(PutParameterError::InvalidAllowedPatternException(_))
+                | Some(PutParameterError::InvalidKeyId(_))
+                | Some(PutParameterError::InvalidPolicyAttributeException(_))
+                | Some(PutParameterError::InvalidPolicyTypeException(_))
+                | Some(PutParameterError::PoliciesLimitExceededException(_)) => {
+                    ParameterDataError::InvalidParameter(reason)
+                }
+                _ if error_code == Some("ThrottlingException") => {
+                    ParameterDataError::Throttled(reason)
+                }
+                // Anything else, including a ValidationException, is shown as Parameter Store
+                // reported it.
+                _ => ParameterDataError::ParameterDataWriteError(reason),
+            }
         };
 
+        let metadata = parameter.metadata;
+        let request = client
+            .put_parameter()
+            .name(parameter.name)
+            .set_description(metadata.description)
+            .set_tier(
+                metadata
+                    .tier
+                    .map(|tier| SSMParameterTier::from(tier.as_str())),
+            )
+            .set_allowed_pattern(metadata.allowed_pattern)
+            .set_data_type(metadata.data_type)
+            .set_tags((!ssm_tags.is_empty()).then_some(ssm_tags))
+            .overwrite(mode == WriteMode::Overwrite);
+
         let result = match parameter.value {
             ParameterValue::String(value) => {
-                client
-                    .put_parameter()
-                    .name(parameter.name)
+                request
                     .value(value)
-                    .r#type(ParameterType::String)
+                    .r#type(SSMParamterType::String)
                     .send()
                     .await
             }
             ParameterValue::SecureString(value) => {
-                client
-                    .put_parameter()
-                    .name(parameter.name)
-                    .value(value.as_str())
-                    .r#type(ParameterType::SecureString)
+                // The SDK only takes an owned string, so the buffer is handed over instead of
+                // leaving an unwiped copy of the secret behind.
+                let plain_value = value.into_unsecure_string().map_err(|_| {
+                    Report::new(ParameterDataError::InvalidParameter(
+                        "secure string values should be valid UTF-8".to_string(),
+                    ))
+                })?;
+
+                request
+                    .value(plain_value)
+                    .r#type(SSMParamterType::SecureString)
+                    .set_key_id(metadata.key_id)
                     .send()
                     .await
             }
             ParameterValue::StringList(list) => {
-                client
-                    .put_parameter()
-                    .name(parameter.name)
+                request
                     .value(list.join(","))
-                    .r#type(ParameterType::StringList)
+                    .r#type(SSMParamterType::StringList)
                     .send()
                     .await
             }
@@ -138,23 +376,572 @@ impl ParameterDataSPI for ParameterStoreAdapter {
             .map(|_| ())
             .map_err(|err| Report::from(handle_error(err)))
     }
+
+    async fn label_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .label_parameter_version()
+            .name(parameter_name.clone())
+            .parameter_version(version)
+            .set_labels(Some(labels.clone()))
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => {
+                let invalid_labels = response.invalid_labels().to_vec();
+                let labels = labels
+                    .into_iter()
+                    .filter(|label| !invalid_labels.contains(label))
+                    .collect();
+
+                Ok(ParameterLabelUpdate {
+                    name: parameter_name,
+                    version,
+                    labels,
+                    invalid_labels,
+                })
+            }
+            Err(err) => {
+                let context = Self::label_error_context(&err, parameter_name, version);
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn unlabel_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .unlabel_parameter_version()
+            .name(parameter_name.clone())
+            .parameter_version(version)
+            .set_labels(Some(labels))
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => Ok(ParameterLabelUpdate {
+                name: parameter_name,
+                version,
+                labels: response.removed_labels().to_vec(),
+                invalid_labels: response.invalid_labels().to_vec(),
+            }),
+            Err(err) => {
+                let context = Self::label_error_context(&err, parameter_name, version);
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterTag>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .list_tags_for_resource()
+            .resource_type(ResourceTypeForTagging::Parameter)
+            .resource_id(parameter_name.clone())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => Ok(response
+                .tag_list()
+                .iter()
+                .map(|tag| ParameterTag::new(tag.key().to_string(), tag.value().to_string()))
+                .collect()),
+            Err(err) => {
+                let context = Self::tag_error_context(
+                    &err,
+                    parameter_name,
+                    ParameterDataError::ParameterMetaDataLoadError,
+                );
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn add_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tags: Vec<ParameterTag>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+        let ssm_tags = Self::ssm_tags(tags)?;
+
+        let result = client
+            .add_tags_to_resource()
+            .resource_type(ResourceTypeForTagging::Parameter)
+            .resource_id(parameter_name.clone())
+            .set_tags(Some(ssm_tags))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let fallback = ParameterDataError::ParameterDataWriteError(
+                    err.meta()
+                        .message()
+                        .unwrap_or("unknown parameter error")
+                        .to_string(),
+                );
+                let context = Self::tag_error_context(&err, parameter_name, fallback);
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn remove_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tag_keys: Vec<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .remove_tags_from_resource()
+            .resource_type(ResourceTypeForTagging::Parameter)
+            .resource_id(parameter_name.clone())
+            .set_tag_keys(Some(tag_keys))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let fallback = ParameterDataError::ParameterDataWriteError(
+                    err.meta()
+                        .message()
+                        .unwrap_or("unknown parameter error")
+                        .to_string(),
+                );
+                let context = Self::tag_error_context(&err, parameter_name, fallback);
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn delete_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterDeletion, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result = client
+            .delete_parameter()
+            .name(parameter_name.clone())
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(ParameterDeletion::new(
+                parameter_name,
+                DeletionStatus::Deleted,
+            )),
+            Err(err) if err.meta().code() == Some("ParameterNotFound") => Ok(
+                ParameterDeletion::new(parameter_name, DeletionStatus::NotFound),
+            ),
+            Err(err) => {
+                let reason = err
+                    .meta()
+                    .message()
+                    .unwrap_or("unknown parameter error")
+                    .to_string();
+                let fallback = match err.meta().code() {
+                    Some("ThrottlingException") => ParameterDataError::Throttled(reason),
+                    _ => ParameterDataError::ParameterDataWriteError(reason),
+                };
+                let context = Self::error_context(&err, fallback);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn delete_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterDeletion>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+        let mut deletions = vec![];
+
+        for name_chunk in parameter_names.chunks(10) {
+            let deletions_chunk = Self::delete_parameter_chunk(name_chunk.to_vec(), &client).await;
+            deletions.extend(deletions_chunk);
+        }
+
+        Ok(deletions)
+    }
+
+    async fn load_parameters_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<Parameter>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result: Result<Vec<_>, _> = client
+            .get_parameters_by_path()
+            .path(path)
+            .recursive(recursive)
+            .with_decryption(true)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => {
+                let parameters: Vec<Parameter> = responses
+                    .iter()
+                    .flat_map(|response| response.parameters().iter())
+                    .flat_map(Self::parse_ssm_parameter)
+                    .collect();
+
+                Ok(parameters)
+            }
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_infos_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let result: Result<Vec<_>, _> = client
+            .get_parameters_by_path()
+            .path(path)
+            .recursive(recursive)
+            .with_decryption(false)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => {
+                let parameter_infos: Vec<ParameterInfo> = responses
+                    .iter()
+                    .flat_map(|response| response.parameters().iter())
+                    .flat_map(Self::parse_ssm_parameter_info)
+                    .collect();
+
+                Ok(parameter_infos)
+            }
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    async fn load_parameter_names_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+    ) -> error_stack::Result<Vec<String>, ParameterDataError> {
+        let client = Self::get_ssm_client(profile_name, region.as_deref()).await?;
+
+        let path_filter = ParameterStringFilter::builder()
+            .key("Path")
+            .option("Recursive")
+            .values(path)
+            .build()
+            .change_context(ParameterDataError::ParameterMetaDataLoadError)?;
+
+        let result: Result<Vec<_>, _> = client
+            .describe_parameters()
+            .parameter_filters(path_filter)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => {
+                let parameter_names = responses
+                    .iter()
+                    .flat_map(|response| response.parameters().iter())
+                    .filter_map(|parameter_metadata| parameter_metadata.name.clone())
+                    .collect();
+
+                Ok(parameter_names)
+            }
+            Err(err) => {
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterMetaDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
 }
 
 impl ParameterStoreAdapter {
-    async fn get_ssm_client(profile_name: &str) -> Client {
-        let mut shared_config_loader = shared_config_loader(profile_name).await;
+    fn error_context<E: ProvideErrorMetadata>(
+        err: &SdkError<E, HttpResponse>,
+        fallback: ParameterDataError,
+    ) -> ParameterDataError {
+        let error_meta = err.meta();
+        let error_code = error_meta.code();
+        let error_message = error_meta.message();
 
-        if let Some(localstack_endpoint) = localstack_endpoint() {
-            shared_config_loader = shared_config_loader
-                .region("us-east-1")
-                .endpoint_url(localstack_endpoint);
+        tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+        match encoded_authorization_message(error_message) {
+            Some(encoded_message) => {
+                ParameterDataError::EncodedAuthorizationFailure(encoded_message)
+            }
+            None => fallback,
         }
+    }
+
+    fn label_error_context<E: ProvideErrorMetadata>(
+        err: &SdkError<E, HttpResponse>,
+        parameter_name: String,
+        version: i64,
+    ) -> ParameterDataError {
+        let error_meta = err.meta();
+        let error_code = error_meta.code();
+        let error_message = error_meta.message();
 
-        let shared_config = shared_config_loader.load().await;
+        tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
 
-        ssm_client(&shared_config)
+        if let Some(encoded_message) = encoded_authorization_message(error_message) {
+            return ParameterDataError::EncodedAuthorizationFailure(encoded_message);
+        }
+
+        let reason = error_message
+            .unwrap_or("unknown parameter error")
+            .to_string();
+        match error_code {
+            Some("ParameterNotFound") => ParameterDataError::ParameterNotFound(parameter_name),
+            Some("ParameterVersionNotFound") => ParameterDataError::InvalidParameter(format!(
+                "parameter '{}' has no version {}",
+                parameter_name, version
+            )),
+            // A version holds at most 10 labels.
+            Some("ParameterVersionLabelLimitExceeded") => {
+                ParameterDataError::InvalidParameter(reason)
+            }
+            _ => ParameterDataError::ParameterDataWriteError(reason),
+        }
     }
 
+    /// The tagging API reports unknown parameters as invalid resource IDs.
+    fn tag_error_context<E: ProvideErrorMetadata>(
+        err: &SdkError<E, HttpResponse>,
+        parameter_name: String,
+        fallback: ParameterDataError,
+    ) -> ParameterDataError {
+        let error_meta = err.meta();
+        let error_code = error_meta.code();
+        let error_message = error_meta.message();
+
+        tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+        if let Some(encoded_message) = encoded_authorization_message(error_message) {
+            return ParameterDataError::EncodedAuthorizationFailure(encoded_message);
+        }
+
+        match error_code {
+            Some("InvalidResourceId") => ParameterDataError::ParameterNotFound(parameter_name),
+            // A parameter holds at most 50 tags.
+            Some("TooManyTagsError") => ParameterDataError::InvalidParameter(
+                error_message.unwrap_or("too many tags").to_string(),
+            ),
+            _ => fallback,
+        }
+    }
+
+    fn ssm_tags(tags: Vec<ParameterTag>) -> error_stack::Result<Vec<Tag>, ParameterDataError> {
+        tags.into_iter()
+            .map(|tag| {
+                Tag::builder()
+                    .key(tag.key)
+                    .value(tag.value)
+                    .build()
+                    .change_context(ParameterDataError::InvalidParameter(
+                        "tags should have a key and a value".to_string(),
+                    ))
+            })
+            .collect()
+    }
+
+    async fn get_ssm_client(
+        profile_name: &str,
+        region: Option<&str>,
+    ) -> error_stack::Result<Client, ParameterDataError> {
+        client_factory()
+            .ssm_client(profile_name, region)
+            .await
+            .map_err(|err| Report::new(ParameterDataError::ClientConfigurationError(err)))
+    }
+
+    fn parameter_string_filters(
+        filters: &ParameterFilters,
+    ) -> error_stack::Result<Vec<ParameterStringFilter>, ParameterDataError> {
+        let mut parameter_filters = vec![];
+        if let Some(name_prefix) = &filters.name_prefix {
+            parameter_filters.push(Self::parameter_string_filter(
+                "Name",
+                "BeginsWith",
+                name_prefix,
+            )?);
+        }
+        if let Some(name_part) = &filters.name_contains {
+            parameter_filters.push(Self::parameter_string_filter(
+                "Name", "Contains", name_part,
+            )?);
+        }
+        if let Some(parameter_type) = &filters.parameter_type {
+            parameter_filters.push(Self::parameter_string_filter(
+                "Type",
+                "Equals",
+                parameter_type.as_str(),
+            )?);
+        }
+        if let Some(tier) = &filters.tier {
+            parameter_filters.push(Self::parameter_string_filter(
+                "Tier",
+                "Equals",
+                tier.as_str(),
+            )?);
+        }
+        if let Some(key_id) = &filters.key_id {
+            parameter_filters.push(Self::parameter_string_filter("KeyId", "Equals", key_id)?);
+        }
+        if let Some(data_type) = &filters.data_type {
+            parameter_filters.push(Self::parameter_string_filter(
+                "DataType", "Equals", data_type,
+            )?);
+        }
+        if let Some(label) = &filters.label {
+            parameter_filters.push(Self::parameter_string_filter("Label", "Equals", label)?);
+        }
+        if let Some(tag_key) = &filters.tag_key {
+            let tag_filter = match &filters.tag_value {
+                Some(tag_value) => {
+                    Self::parameter_string_filter(&format!("tag:{}", tag_key), "Equals", tag_value)?
+                }
+                None => Self::parameter_string_filter("tag-key", "Equals", tag_key)?,
+            };
+            parameter_filters.push(tag_filter);
+        }
+
+        Ok(parameter_filters)
+    }
+
+    fn parameter_string_filter(
+        key: &str,
+        option: &str,
+        value: &str,
+    ) -> error_stack::Result<ParameterStringFilter, ParameterDataError> {
+        ParameterStringFilter::builder()
+            .key(key)
+            .option(option)
+            .values(value)
+            .build()
+            .change_context(ParameterDataError::InvalidParameter(format!(
+                "invalid {} filter",
+                key
+            )))
+    }
+
+    /// DescribeParameters does not accept label filters, so labeled parameters are looked up
+    /// through GetParametersByPath instead, which in turn rejects name, tier, data type and tag
+    /// filters and returns at most 10 parameters per call.
+    async fn load_labeled_parameter_names_page(
+        client: &Client,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError> {
+        if filters.name_prefix.is_some()
+            || filters.name_contains.is_some()
+            || filters.tier.is_some()
+            || filters.data_type.is_some()
+            || filters.tag_key.is_some()
+        {
+            return Err(Report::new(ParameterDataError::InvalidParameter(
+                "label filters can only be combined with type and KMS key filters".to_string(),
+            )));
+        }
+
+        let parameter_filters = Self::parameter_string_filters(&filters)?;
+        let result = client
+            .get_parameters_by_path()
+            .path("/")
+            .recursive(true)
+            .with_decryption(false)
+            .set_parameter_filters(Some(parameter_filters))
+            .set_next_token(cursor)
+            .set_max_results(page_size.map(|size| size.min(10)))
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => Ok(ParameterNamePage {
+                names: response
+                    .parameters()
+                    .iter()
+                    .filter_map(|parameter| parameter.name.clone())
+                    .collect(),
+                next_cursor: response.next_token.clone(),
+            }),
+            Err(err) => {
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterMetaDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
+
+    /// GetParameters only reports the data type, so the remaining metadata is taken from the
+    /// DescribeParameters result if there is one.
     fn parse_ssm_parameter(ssm_parameter: &SSMParameter) -> Result<Parameter, ParameterDataError> {
         let name = ssm_parameter
             .name
@@ -167,16 +954,18 @@ impl ParameterStoreAdapter {
             .ok_or(ParameterDataError::InvalidParameter(
                 "parameters should have a type".to_string(),
             ))?;
-        let value = Self::parse_ssm_parameter_value(ssm_parameter, parameter_type)?;
+        let value = Self::parse_ssm_parameter_value(ssm_parameter.value.clone(), parameter_type)?;
 
         let version = Some(ssm_parameter.version);
-        let last_modified_date = ssm_parameter.last_modified_date.map(|date_time| {
-            let nanos = date_time.as_nanos();
-            let millis = (nanos / 1_000_000) as i64;
-
-            DateTime::from_timestamp_millis(millis).expect("should be valid date time")
-        });
+        let last_modified_date = ssm_parameter
+            .last_modified_date
+            .as_ref()
+            .map(Self::parse_date_time);
         let identifier = ssm_parameter.arn.clone();
+        let metadata = ParameterMetadata {
+            data_type: ssm_parameter.data_type.clone(),
+            ..ParameterMetadata::default()
+        };
 
         Ok(Parameter {
             name,
@@ -184,14 +973,161 @@ impl ParameterStoreAdapter {
             version,
             last_modified_date,
             identifier,
+            // Returned as `:live` or `:3`, depending on what was selected.
+            selector: ssm_parameter
+                .selector
+                .as_ref()
+                .map(|selector| selector.trim_start_matches(':').to_string()),
+            metadata,
         })
     }
 
-    fn parse_ssm_parameter_value(
+    /// Leaves out the value, which is still encrypted for secure parameters loaded without
+    /// decryption.
+    fn parse_ssm_parameter_info(
         ssm_parameter: &SSMParameter,
+    ) -> Result<ParameterInfo, ParameterDataError> {
+        let name = ssm_parameter
+            .name
+            .clone()
+            .ok_or(ParameterDataError::InvalidParameter(
+                "parameters should have a name".to_string(),
+            ))?;
+        let parameter_type = ssm_parameter
+            .r#type()
+            .ok_or(ParameterDataError::InvalidParameter(
+                "parameters should have a type".to_string(),
+            ))?;
+
+        Ok(ParameterInfo {
+            name,
+            parameter_type: Self::parse_ssm_parameter_type(parameter_type)?,
+            version: Some(ssm_parameter.version),
+            last_modified_date: ssm_parameter
+                .last_modified_date
+                .as_ref()
+                .map(Self::parse_date_time),
+            metadata: ParameterMetadata {
+                data_type: ssm_parameter.data_type.clone(),
+                ..ParameterMetadata::default()
+            },
+        })
+    }
+
+    fn parse_ssm_parameter_metadata(
+        ssm_metadata: &SSMParamterMetadata,
+    ) -> Result<ParameterInfo, ParameterDataError> {
+        let name = ssm_metadata
+            .name
+            .clone()
+            .ok_or(ParameterDataError::InvalidParameter(
+                "parameters should have a name".to_string(),
+            ))?;
+        let parameter_type = ssm_metadata
+            .r#type()
+            .ok_or(ParameterDataError::InvalidParameter(
+                "parameters should have a type".to_string(),
+            ))?;
+
+        Ok(ParameterInfo {
+            name,
+            parameter_type: Self::parse_ssm_parameter_type(parameter_type)?,
+            version: Some(ssm_metadata.version),
+            last_modified_date: ssm_metadata
+                .last_modified_date
+                .as_ref()
+                .map(Self::parse_date_time),
+            metadata: Self::parse_metadata(ssm_metadata),
+        })
+    }
+
+    fn parse_metadata(ssm_metadata: &SSMParamterMetadata) -> ParameterMetadata {
+        ParameterMetadata {
+            description: ssm_metadata.description.clone(),
+            tier: Self::parse_tier(ssm_metadata.tier()),
+            data_type: ssm_metadata.data_type.clone(),
+            key_id: ssm_metadata.key_id.clone(),
+            allowed_pattern: ssm_metadata.allowed_pattern.clone(),
+            policies: Self::parse_policies(ssm_metadata.policies()),
+            last_modified_user: ssm_metadata.last_modified_user.clone(),
+        }
+    }
+
+    fn parse_ssm_parameter_version(
+        ssm_history: &SSMParameterHistory,
+    ) -> Result<ParameterVersion, ParameterDataError> {
+        let parameter_type = ssm_history
+            .r#type()
+            .ok_or(ParameterDataError::InvalidParameter(
+                "parameters should have a type".to_string(),
+            ))?;
+
+        Ok(ParameterVersion {
+            version: ssm_history.version,
+            value: Self::parse_ssm_parameter_value(ssm_history.value.clone(), parameter_type)?,
+            last_modified_date: ssm_history
+                .last_modified_date
+                .as_ref()
+                .map(Self::parse_date_time),
+            labels: ssm_history.labels().to_vec(),
+            metadata: ParameterMetadata {
+                description: ssm_history.description.clone(),
+                tier: Self::parse_tier(ssm_history.tier()),
+                data_type: ssm_history.data_type.clone(),
+                key_id: ssm_history.key_id.clone(),
+                allowed_pattern: ssm_history.allowed_pattern.clone(),
+                policies: Self::parse_policies(ssm_history.policies()),
+                last_modified_user: ssm_history.last_modified_user.clone(),
+            },
+        })
+    }
+
+    fn parse_tier(tier: Option<&SSMParameterTier>) -> Option<ParameterTier> {
+        tier.and_then(|tier| match tier {
+            SSMParameterTier::Standard => Some(ParameterTier::Standard),
+            SSMParameterTier::Advanced => Some(ParameterTier::Advanced),
+            SSMParameterTier::IntelligentTiering => Some(ParameterTier::IntelligentTiering),
+            _ => None,
+        })
+    }
+
+    fn parse_policies(policies: &[ParameterInlinePolicy]) -> Vec<ParameterPolicy> {
+        policies
+            .iter()
+            .map(|policy| ParameterPolicy {
+                policy_type: policy.policy_type.clone(),
+                policy_text: policy.policy_text.clone(),
+                policy_status: policy.policy_status.clone(),
+            })
+            .collect()
+    }
+
+    fn parse_ssm_parameter_type(
+        parameter_type: &SSMParamterType,
+    ) -> Result<ParameterType, ParameterDataError> {
+        match parameter_type {
+            SSMParamterType::String => Ok(ParameterType::String),
+            SSMParamterType::StringList => Ok(ParameterType::StringList),
+            SSMParamterType::SecureString => Ok(ParameterType::SecureString),
+            other if other.as_str() == "NewFeature" => Err(
+                ParameterDataError::UnsupportedParameterType(other.as_str().to_string()),
+            ),
+            _ => Err(ParameterDataError::UnknownParameterType),
+        }
+    }
+
+    fn parse_date_time(date_time: &SSMDateTime) -> DateTime<Utc> {
+        let nanos = date_time.as_nanos();
+        let millis = (nanos / 1_000_000) as i64;
+
+        DateTime::from_timestamp_millis(millis).expect("should be valid date time")
+    }
+
+    fn parse_ssm_parameter_value(
+        ssm_parameter_value: Option<String>,
         parameter_type: &SSMParamterType,
     ) -> Result<ParameterValue, ParameterDataError> {
-        match ssm_parameter.value.clone() {
+        match ssm_parameter_value {
             Some(ssm_parameter_value) => match parameter_type {
                 SSMParamterType::SecureString => Ok(ParameterValue::SecureString(
                     SecureString::from(ssm_parameter_value),
@@ -228,24 +1164,102 @@ impl ParameterStoreAdapter {
             .send()
             .await;
 
-        match result {
-            Ok(response) => {
-                let parameters: Vec<Parameter> = response
-                    .parameters()
-                    .iter()
-                    .flat_map(Self::parse_ssm_parameter)
-                    .collect();
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                let context = Self::error_context(&err, ParameterDataError::ParameterDataLoadError);
 
-                Ok(parameters)
+                return Err(Report::from(err).change_context(context));
             }
+        };
+
+        // Only the values are needed here, the metadata is loaded separately where it is shown.
+        let parameters: Vec<Parameter> = response
+            .parameters()
+            .iter()
+            .flat_map(Self::parse_ssm_parameter)
+            .collect();
+
+        Ok(parameters)
+    }
+
+    async fn describe_parameter_chunk(
+        parameter_names: Vec<String>,
+        client: &Client,
+    ) -> error_stack::Result<Vec<SSMParamterMetadata>, ParameterDataError> {
+        let name_filter = ParameterStringFilter::builder()
+            .key("Name")
+            .option("Equals")
+            .set_values(Some(parameter_names))
+            .build()
+            .change_context(ParameterDataError::ParameterMetaDataLoadError)?;
+
+        let result: Result<Vec<_>, _> = client
+            .describe_parameters()
+            .parameter_filters(name_filter)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => Ok(responses
+                .iter()
+                .flat_map(|response| response.parameters().iter().cloned())
+                .collect()),
             Err(err) => {
-                let error_meta = err.meta();
-                let error_code = error_meta.code();
-                let error_message = error_meta.message();
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterMetaDataLoadError);
+
+                Err(Report::from(err).change_context(context))
+            }
+        }
+    }
 
-                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+    /// A failing chunk does not stop the others, its parameters are reported as failed.
+    async fn delete_parameter_chunk(
+        parameter_names: Vec<String>,
+        client: &Client,
+    ) -> Vec<ParameterDeletion> {
+        let result = client
+            .delete_parameters()
+            .set_names(Some(parameter_names.clone()))
+            .send()
+            .await;
+
+        match result {
+            Ok(response) => {
+                let deleted_names = response.deleted_parameters();
+                parameter_names
+                    .into_iter()
+                    .map(|name| {
+                        let status = if deleted_names.contains(&name) {
+                            DeletionStatus::Deleted
+                        } else {
+                            DeletionStatus::NotFound
+                        };
+                        ParameterDeletion::new(name, status)
+                    })
+                    .collect()
+            }
+            Err(err) => {
+                let reason = err
+                    .meta()
+                    .message()
+                    .unwrap_or("unknown parameter error")
+                    .to_string();
+                let context =
+                    Self::error_context(&err, ParameterDataError::ParameterDataWriteError(reason));
 
-                Err(Report::from(err).change_context(ParameterDataError::ParameterDataLoadError))
+                parameter_names
+                    .into_iter()
+                    .map(|name| {
+                        let status = DeletionStatus::Failed {
+                            reason: context.to_string(),
+                        };
+                        ParameterDeletion::new(name, status)
+                    })
+                    .collect()
             }
         }
     }
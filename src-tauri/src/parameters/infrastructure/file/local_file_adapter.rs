@@ -0,0 +1,175 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::Path;
+
+use error_stack::{Report, ResultExt};
+
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::spi::ParameterFileSPI;
+
+/// Only the owner may read files with secrets in them.
+#[cfg(unix)]
+const PRIVATE_FILE_MODE: u32 = 0o600;
+
+/// Reads and writes files on the local disk, at the locations the user picked.
+pub struct LocalFileAdapter;
+
+impl ParameterFileSPI for LocalFileAdapter {
+    fn read_file(&self, file_path: &str) -> error_stack::Result<String, ParameterDataError> {
+        fs::read_to_string(file_path).change_context_lazy(|| {
+            ParameterDataError::ParameterFileError(format!("cannot read '{}'", file_path))
+        })
+    }
+
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    fn write_file(
+        &self,
+        file_path: &str,
+        content: &SecureString,
+        contains_secrets: bool,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        let path = Path::new(file_path);
+        if path.is_dir() {
+            return Err(Report::new(ParameterDataError::ParameterFileError(
+                format!("'{}' is a directory", file_path),
+            )));
+        }
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent).change_context_lazy(|| {
+                ParameterDataError::ParameterFileError(format!(
+                    "cannot create the directory of '{}'",
+                    file_path
+                ))
+            })?;
+        }
+
+        let write_error =
+            || ParameterDataError::ParameterFileError(format!("cannot write '{}'", file_path));
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        if contains_secrets {
+            options.mode(PRIVATE_FILE_MODE);
+        }
+        let mut file = options.open(path).change_context_lazy(write_error)?;
+        // The mode only applies to new files, so an existing file is restricted before the
+        // secrets are written to it.
+        #[cfg(unix)]
+        if contains_secrets {
+            file.set_permissions(fs::Permissions::from_mode(PRIVATE_FILE_MODE))
+                .change_context_lazy(write_error)?;
+        }
+
+        content
+            .expose(|bytes| file.write_all(bytes))
+            .change_context_lazy(write_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn should_create_parent_directories_and_read_written_file() {
+        let test_dir = tempdir().unwrap();
+        let file_path = test_dir.path().join("nested").join("app.env");
+        let file_path = file_path.to_str().unwrap();
+        let cut = LocalFileAdapter;
+
+        let result = cut.write_file(
+            file_path,
+            &SecureString::from("NAME=\"custodian\"\n"),
+            false,
+        );
+
+        assert_that!(result).is_ok();
+        assert_that!(cut.read_file(file_path))
+            .is_ok()
+            .is_equal_to("NAME=\"custodian\"\n".to_string());
+    }
+
+    #[test]
+    fn should_replace_content_of_existing_file() {
+        let test_dir = tempdir().unwrap();
+        let file_path = test_dir.path().join("app.env");
+        fs::write(&file_path, "NAME=\"a much longer previous value\"\n").unwrap();
+        let file_path = file_path.to_str().unwrap();
+        let cut = LocalFileAdapter;
+
+        let result = cut.write_file(
+            file_path,
+            &SecureString::from("NAME=\"custodian\"\n"),
+            false,
+        );
+
+        assert_that!(result).is_ok();
+        assert_that!(cut.read_file(file_path))
+            .is_ok()
+            .is_equal_to("NAME=\"custodian\"\n".to_string());
+    }
+
+    #[test]
+    fn should_refuse_to_write_to_directory() {
+        let test_dir = tempdir().unwrap();
+        let cut = LocalFileAdapter;
+
+        let actual = cut.write_file(
+            test_dir.path().to_str().unwrap(),
+            &SecureString::from(""),
+            false,
+        );
+
+        assert_that!(actual).is_err();
+        assert!(matches!(
+            actual.unwrap_err().current_context(),
+            ParameterDataError::ParameterFileError(_)
+        ));
+    }
+
+    #[test]
+    fn should_return_file_error_when_file_is_missing() {
+        let test_dir = tempdir().unwrap();
+        let cut = LocalFileAdapter;
+
+        let actual = cut.read_file(test_dir.path().join("missing.env").to_str().unwrap());
+
+        assert_that!(actual).is_err();
+        assert!(matches!(
+            actual.unwrap_err().current_context(),
+            ParameterDataError::ParameterFileError(_)
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_restrict_mode_of_new_and_existing_files_with_secrets() {
+        let test_dir = tempdir().unwrap();
+        let new_file = test_dir.path().join("new.env");
+        let existing_file = test_dir.path().join("existing.env");
+        fs::write(&existing_file, "").unwrap();
+        fs::set_permissions(&existing_file, fs::Permissions::from_mode(0o644)).unwrap();
+        let cut = LocalFileAdapter;
+
+        let content = SecureString::from("PASSWORD=\"secret\"\n");
+
+        cut.write_file(new_file.to_str().unwrap(), &content, true)
+            .unwrap();
+        cut.write_file(existing_file.to_str().unwrap(), &content, true)
+            .unwrap();
+
+        for file in [new_file, existing_file] {
+            let mode = fs::metadata(file).unwrap().permissions().mode() & 0o777;
+            assert_that!(mode).is_equal_to(PRIVATE_FILE_MODE);
+        }
+    }
+}
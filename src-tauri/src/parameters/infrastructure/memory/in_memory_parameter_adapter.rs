@@ -0,0 +1,1174 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use error_stack::Report;
+
+use crate::common::demo::{DemoFixture, DemoParameterType};
+use crate::common::secure_string::SecureString;
+use crate::parameters::core::domain::{
+    DeletionStatus, Parameter, ParameterDeletion, ParameterFilters, ParameterHistory,
+    ParameterInfo, ParameterLabel, ParameterLabelUpdate, ParameterMetadata, ParameterNamePage,
+    ParameterSelector, ParameterSet, ParameterTag, ParameterTier, ParameterType, ParameterValue,
+    ParameterVersion, VersionSelector, WriteMode,
+};
+use crate::parameters::core::error::ParameterDataError;
+use crate::parameters::core::spi::ParameterDataSPI;
+
+const DEMO_ACCOUNT_ID: &str = "000000000000";
+const DEFAULT_PAGE_SIZE: usize = 50;
+const DEFAULT_KEY_ID: &str = "alias/aws/ssm";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ParameterKey {
+    profile_name: String,
+    region: String,
+    name: String,
+}
+
+/// Parameter Store stand-in for the demo mode. Every write adds a version, like in AWS. Clones
+/// share the stored parameters, so every service working on them sees the same state.
+#[derive(Clone)]
+pub struct InMemoryParameterAdapter {
+    fixture: DemoFixture,
+    // All versions of each parameter, oldest first.
+    parameters: Arc<Mutex<BTreeMap<ParameterKey, Vec<Parameter>>>>,
+    // The version each label points to. Locked after `parameters` when both are needed.
+    labels: Arc<Mutex<BTreeMap<ParameterKey, BTreeMap<String, i64>>>>,
+    // Tag values by key, locked after `labels`.
+    tags: Arc<Mutex<BTreeMap<ParameterKey, BTreeMap<String, String>>>>,
+}
+
+impl InMemoryParameterAdapter {
+    pub fn new(fixture: DemoFixture) -> Self {
+        let mut parameters = BTreeMap::new();
+        for demo_parameter in &fixture.parameters {
+            let region = demo_parameter
+                .region
+                .clone()
+                .unwrap_or_else(|| fixture.default_region(&demo_parameter.profile_name));
+            let key = ParameterKey {
+                profile_name: demo_parameter.profile_name.clone(),
+                region,
+                name: demo_parameter.name.clone(),
+            };
+            let versions = demo_parameter
+                .versions
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    Self::versioned_parameter(
+                        &key,
+                        Self::parameter_value(demo_parameter.parameter_type, value),
+                        index as i64 + 1,
+                    )
+                })
+                .collect();
+            parameters.insert(key, versions);
+        }
+
+        Self {
+            fixture,
+            parameters: Arc::new(Mutex::new(parameters)),
+            labels: Arc::new(Mutex::new(BTreeMap::new())),
+            tags: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    fn parameter_value(parameter_type: DemoParameterType, value: &str) -> ParameterValue {
+        match parameter_type {
+            DemoParameterType::String => ParameterValue::String(value.to_string()),
+            DemoParameterType::StringList => {
+                ParameterValue::StringList(value.split(',').map(|item| item.to_string()).collect())
+            }
+            DemoParameterType::SecureString => {
+                ParameterValue::SecureString(SecureString::from(value))
+            }
+        }
+    }
+
+    fn versioned_parameter(key: &ParameterKey, value: ParameterValue, version: i64) -> Parameter {
+        let separator = if key.name.starts_with('/') { "" } else { "/" };
+        let identifier = format!(
+            "arn:aws:ssm:{}:{}:parameter{}{}",
+            key.region, DEMO_ACCOUNT_ID, separator, key.name
+        );
+
+        let key_id = (value.parameter_type() == ParameterType::SecureString)
+            .then(|| DEFAULT_KEY_ID.to_string());
+        let metadata = ParameterMetadata {
+            tier: Some(ParameterTier::Standard),
+            data_type: Some("text".to_string()),
+            key_id,
+            last_modified_user: Some(format!("arn:aws:iam::{}:user/demo", DEMO_ACCOUNT_ID)),
+            ..ParameterMetadata::default()
+        };
+
+        Parameter::new(
+            key.name.clone(),
+            value,
+            Some(version),
+            Some(Utc::now()),
+            Some(identifier),
+        )
+        .with_metadata(metadata)
+    }
+
+    fn region(&self, profile_name: &str, region: Option<String>) -> String {
+        region.unwrap_or_else(|| self.fixture.default_region(profile_name))
+    }
+
+    fn check_injected_error(
+        &self,
+        operation: &str,
+        profile_name: &str,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        match self.fixture.injected_error(operation, profile_name) {
+            Some(injected_error) => {
+                let message = injected_error.message();
+                let error = match injected_error.code.as_str() {
+                    "ParameterMetaDataLoadError" => ParameterDataError::ParameterMetaDataLoadError,
+                    "InvalidParameter" => ParameterDataError::InvalidParameter(message),
+                    "UnsupportedParameterType" => {
+                        ParameterDataError::UnsupportedParameterType(message)
+                    }
+                    "UnknownParameterType" => ParameterDataError::UnknownParameterType,
+                    "ParameterDataWriteError" => {
+                        ParameterDataError::ParameterDataWriteError(message)
+                    }
+                    "ParameterNotFound" => ParameterDataError::ParameterNotFound(message),
+                    "ParameterAlreadyExists" => ParameterDataError::ParameterAlreadyExists(message),
+                    "ParameterPatternMismatch" => {
+                        ParameterDataError::ParameterPatternMismatch(message)
+                    }
+                    "ParameterTooLarge" => ParameterDataError::ParameterTooLarge(message),
+                    "ParameterTierMismatch" => ParameterDataError::ParameterTierMismatch(message),
+                    "EncodedAuthorizationFailure" => {
+                        ParameterDataError::EncodedAuthorizationFailure(message)
+                    }
+                    _ => ParameterDataError::ParameterDataLoadError,
+                };
+
+                Err(Report::new(error))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn matches_filters(
+        parameter: &Parameter,
+        labels: Option<&BTreeMap<String, i64>>,
+        tags: Option<&BTreeMap<String, String>>,
+        filters: &ParameterFilters,
+    ) -> bool {
+        let metadata = &parameter.metadata;
+
+        filters
+            .name_prefix
+            .as_ref()
+            .is_none_or(|prefix| parameter.name.starts_with(prefix))
+            && filters
+                .name_contains
+                .as_ref()
+                .is_none_or(|part| parameter.name.contains(part))
+            && filters
+                .parameter_type
+                .is_none_or(|expected| expected == parameter.value.parameter_type())
+            && filters.tier.is_none_or(|tier| Some(tier) == metadata.tier)
+            && filters
+                .key_id
+                .as_ref()
+                .is_none_or(|key_id| Some(key_id) == metadata.key_id.as_ref())
+            && filters
+                .data_type
+                .as_ref()
+                .is_none_or(|data_type| Some(data_type) == metadata.data_type.as_ref())
+            && filters
+                .label
+                .as_ref()
+                .is_none_or(|label| labels.is_some_and(|labels| labels.contains_key(label)))
+            && filters.tag_key.as_ref().is_none_or(|tag_key| {
+                let tag_value = tags.and_then(|tags| tags.get(tag_key));
+                tag_value.is_some()
+                    && filters
+                        .tag_value
+                        .as_ref()
+                        .is_none_or(|expected| Some(expected) == tag_value)
+            })
+    }
+
+    /// Resolves a `:version` or `:label` suffix like GetParameters does.
+    fn select_version<'a>(
+        versions: &'a [Parameter],
+        labels: Option<&BTreeMap<String, i64>>,
+        selector: &VersionSelector,
+    ) -> Option<&'a Parameter> {
+        let version = match selector {
+            VersionSelector::Version(version) => *version,
+            VersionSelector::Label(label) => *labels?.get(label)?,
+        };
+
+        versions
+            .iter()
+            .find(|parameter| parameter.version == Some(version))
+    }
+
+    /// Mirrors the path matching of GetParametersByPath, which only matches whole path segments.
+    fn is_below_path(name: &str, path: &str, recursive: bool) -> bool {
+        let prefix = if path == "/" {
+            "/".to_string()
+        } else {
+            format!("{}/", path.trim_end_matches('/'))
+        };
+
+        match name.strip_prefix(&prefix) {
+            Some(relative) => recursive || !relative.contains('/'),
+            None => false,
+        }
+    }
+
+    fn lock_parameters(&self) -> MutexGuard<'_, BTreeMap<ParameterKey, Vec<Parameter>>> {
+        self.parameters
+            .lock()
+            .expect("parameter store lock should not be poisoned")
+    }
+
+    fn check_version_exists(
+        &self,
+        key: &ParameterKey,
+        version: i64,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        match self.lock_parameters().get(key) {
+            Some(versions)
+                if versions
+                    .iter()
+                    .any(|parameter| parameter.version == Some(version)) =>
+            {
+                Ok(())
+            }
+            Some(_) => Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                "parameter '{}' has no version {}",
+                key.name, version
+            )))),
+            None => Err(Report::new(ParameterDataError::ParameterNotFound(
+                key.name.clone(),
+            ))),
+        }
+    }
+
+    fn check_parameter_exists(
+        &self,
+        key: &ParameterKey,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        if self.lock_parameters().contains_key(key) {
+            Ok(())
+        } else {
+            Err(Report::new(ParameterDataError::ParameterNotFound(
+                key.name.clone(),
+            )))
+        }
+    }
+
+    fn lock_labels(&self) -> MutexGuard<'_, BTreeMap<ParameterKey, BTreeMap<String, i64>>> {
+        self.labels
+            .lock()
+            .expect("parameter label lock should not be poisoned")
+    }
+
+    fn lock_tags(&self) -> MutexGuard<'_, BTreeMap<ParameterKey, BTreeMap<String, String>>> {
+        self.tags
+            .lock()
+            .expect("parameter tag lock should not be poisoned")
+    }
+}
+
+#[async_trait]
+impl ParameterDataSPI for InMemoryParameterAdapter {
+    async fn load_available_parameter_names(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+    ) -> error_stack::Result<Vec<String>, ParameterDataError> {
+        self.check_injected_error("load_available_parameter_names", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let parameter_names = self
+            .lock_parameters()
+            .keys()
+            .filter(|key| key.profile_name == profile_name && key.region == region)
+            .map(|key| key.name.clone())
+            .collect();
+
+        Ok(parameter_names)
+    }
+
+    async fn load_parameter_names_page(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        filters: ParameterFilters,
+        cursor: Option<String>,
+        page_size: Option<i32>,
+    ) -> error_stack::Result<ParameterNamePage, ParameterDataError> {
+        self.check_injected_error("load_parameter_names_page", profile_name)?;
+        let region = self.region(profile_name, region);
+        // The cursor is the offset of the page, where AWS uses an opaque token instead.
+        let offset = match cursor {
+            Some(cursor) => cursor.parse::<usize>().map_err(|_| {
+                Report::new(ParameterDataError::InvalidParameter(format!(
+                    "invalid cursor '{}'",
+                    cursor
+                )))
+            })?,
+            None => 0,
+        };
+        let page_size = page_size.map_or(DEFAULT_PAGE_SIZE, |size| size.max(1) as usize);
+
+        let parameters = self.lock_parameters();
+        let labels = self.lock_labels();
+        let tags = self.lock_tags();
+        let matching_names: Vec<String> = parameters
+            .iter()
+            .filter(|(key, _)| key.profile_name == profile_name && key.region == region)
+            .filter_map(|(key, versions)| Some((key, versions.last()?)))
+            .filter(|(key, latest)| {
+                Self::matches_filters(latest, labels.get(key), tags.get(key), &filters)
+            })
+            .map(|(_, latest)| latest.name.clone())
+            .collect();
+
+        let next_offset = offset + page_size;
+        Ok(ParameterNamePage {
+            names: matching_names
+                .iter()
+                .skip(offset)
+                .take(page_size)
+                .cloned()
+                .collect(),
+            next_cursor: (next_offset < matching_names.len()).then(|| next_offset.to_string()),
+        })
+    }
+
+    async fn load_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<ParameterSet, ParameterDataError> {
+        self.check_injected_error("load_parameters", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let parameters = self.lock_parameters();
+        let labels = self.lock_labels();
+        let mut parameter_set = ParameterSet::new();
+        for name in parameter_names {
+            let Some(parameter_selector) = ParameterSelector::parse(&name) else {
+                return Err(Report::new(ParameterDataError::InvalidParameter(format!(
+                    "invalid version or label in '{}'",
+                    name
+                ))));
+            };
+            let key = ParameterKey {
+                profile_name: profile_name.to_string(),
+                region: region.clone(),
+                name: parameter_selector.name,
+            };
+            let Some(versions) = parameters.get(&key) else {
+                // Unknown names are skipped, as GetParameters reports them separately.
+                continue;
+            };
+            let selected =
+                match &parameter_selector.selector {
+                    Some(selector) => Self::select_version(versions, labels.get(&key), selector)
+                        .map(|parameter| Parameter {
+                            selector: name.split_once(':').map(|(_, suffix)| suffix.to_string()),
+                            ..parameter.clone()
+                        }),
+                    None => versions.last().cloned(),
+                };
+            if let Some(parameter) = selected {
+                parameter_set.add_parameter(parameter);
+            }
+        }
+
+        Ok(parameter_set)
+    }
+
+    async fn load_parameter_metadata(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError> {
+        self.check_injected_error("load_parameter_metadata", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let parameters = self.lock_parameters();
+        let parameter_infos = parameter_names
+            .into_iter()
+            .filter_map(|name| {
+                let key = ParameterKey {
+                    profile_name: profile_name.to_string(),
+                    region: region.clone(),
+                    name,
+                };
+                parameters
+                    .get(&key)
+                    .and_then(|versions| versions.last())
+                    .map(ParameterInfo::from)
+            })
+            .collect();
+
+        Ok(parameter_infos)
+    }
+
+    async fn load_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Option<i64>, ParameterDataError> {
+        self.check_injected_error("load_parameter_version", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+
+        Ok(self
+            .lock_parameters()
+            .get(&key)
+            .and_then(|versions| versions.last())
+            .and_then(|parameter| parameter.version))
+    }
+
+    async fn load_parameter_history(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterHistory, ParameterDataError> {
+        self.check_injected_error("load_parameter_history", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+
+        let parameters = self.lock_parameters();
+        let labels = self.lock_labels();
+        match parameters.get(&key) {
+            Some(versions) => {
+                let parameter_labels = labels.get(&key);
+                let versions = versions
+                    .iter()
+                    .map(|parameter| {
+                        let mut version = ParameterVersion::from(parameter);
+                        version.labels = parameter_labels
+                            .into_iter()
+                            .flatten()
+                            .filter(|(_, labeled_version)| {
+                                Some(**labeled_version) == parameter.version
+                            })
+                            .map(|(label, _)| label.clone())
+                            .collect();
+                        version
+                    })
+                    .collect();
+
+                Ok(ParameterHistory::new(key.name.clone(), versions))
+            }
+            None => Err(Report::new(ParameterDataError::ParameterNotFound(key.name))),
+        }
+    }
+
+    async fn load_parameter_labels(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterLabel>, ParameterDataError> {
+        self.check_injected_error("load_parameter_labels", profile_name)?;
+        let history = self
+            .load_parameter_history(profile_name, region, parameter_name)
+            .await?;
+
+        Ok(history.labels())
+    }
+
+    async fn upsert_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter: Parameter,
+        mode: WriteMode,
+        tags: Vec<ParameterTag>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_injected_error("upsert_parameter", profile_name)?;
+        if mode == WriteMode::Overwrite && !tags.is_empty() {
+            return Err(Report::new(ParameterDataError::InvalidParameter(
+                "tags can only be set when creating a parameter".to_string(),
+            )));
+        }
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter.name.clone(),
+        };
+
+        let mut parameters = self.lock_parameters();
+        let versions = parameters.entry(key.clone()).or_default();
+        if mode == WriteMode::CreateOnly && !versions.is_empty() {
+            return Err(Report::new(ParameterDataError::ParameterAlreadyExists(
+                parameter.name,
+            )));
+        }
+        let next_version = versions
+            .last()
+            .and_then(|latest| latest.version)
+            .unwrap_or(0)
+            + 1;
+        let mut versioned_parameter =
+            Self::versioned_parameter(&key, parameter.value, next_version);
+        // Metadata that is not passed along is kept from the previous version.
+        let previous_metadata = versions
+            .last()
+            .map(|latest| latest.metadata.clone())
+            .unwrap_or_else(|| versioned_parameter.metadata.clone());
+        let metadata = parameter.metadata;
+        versioned_parameter.metadata = ParameterMetadata {
+            description: metadata.description.or(previous_metadata.description),
+            tier: metadata.tier.or(previous_metadata.tier),
+            data_type: metadata.data_type.or(previous_metadata.data_type),
+            key_id: metadata.key_id.or(versioned_parameter.metadata.key_id),
+            allowed_pattern: metadata
+                .allowed_pattern
+                .or(previous_metadata.allowed_pattern),
+            ..versioned_parameter.metadata
+        };
+        versions.push(versioned_parameter);
+        if !tags.is_empty() {
+            let mut all_tags = self.lock_tags();
+            let parameter_tags = all_tags.entry(key).or_default();
+            for tag in tags {
+                parameter_tags.insert(tag.key, tag.value);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn label_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        self.check_injected_error("label_parameter_version", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+        self.check_version_exists(&key, version)?;
+
+        let (valid_labels, invalid_labels): (Vec<String>, Vec<String>) = labels
+            .into_iter()
+            .partition(|label| VersionSelector::is_valid_label(label));
+        let mut all_labels = self.lock_labels();
+        let parameter_labels = all_labels.entry(key.clone()).or_default();
+        for label in &valid_labels {
+            parameter_labels.insert(label.clone(), version);
+        }
+
+        Ok(ParameterLabelUpdate {
+            name: key.name,
+            version,
+            labels: valid_labels,
+            invalid_labels,
+        })
+    }
+
+    async fn unlabel_parameter_version(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        version: i64,
+        labels: Vec<String>,
+    ) -> error_stack::Result<ParameterLabelUpdate, ParameterDataError> {
+        self.check_injected_error("unlabel_parameter_version", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+        self.check_version_exists(&key, version)?;
+
+        let mut all_labels = self.lock_labels();
+        let parameter_labels = all_labels.entry(key.clone()).or_default();
+        // Labels on other versions are left alone and reported as invalid, like AWS does.
+        let (removed_labels, invalid_labels): (Vec<String>, Vec<String>) = labels
+            .into_iter()
+            .partition(|label| parameter_labels.get(label) == Some(&version));
+        for label in &removed_labels {
+            parameter_labels.remove(label);
+        }
+
+        Ok(ParameterLabelUpdate {
+            name: key.name,
+            version,
+            labels: removed_labels,
+            invalid_labels,
+        })
+    }
+
+    async fn load_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<Vec<ParameterTag>, ParameterDataError> {
+        self.check_injected_error("load_parameter_tags", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+        self.check_parameter_exists(&key)?;
+
+        let tags = self
+            .lock_tags()
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .map(|(tag_key, tag_value)| ParameterTag::new(tag_key.clone(), tag_value.clone()))
+            .collect();
+
+        Ok(tags)
+    }
+
+    async fn add_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tags: Vec<ParameterTag>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_injected_error("add_parameter_tags", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+        self.check_parameter_exists(&key)?;
+
+        let mut all_tags = self.lock_tags();
+        let parameter_tags = all_tags.entry(key).or_default();
+        for tag in tags {
+            parameter_tags.insert(tag.key, tag.value);
+        }
+
+        Ok(())
+    }
+
+    async fn remove_parameter_tags(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+        tag_keys: Vec<String>,
+    ) -> error_stack::Result<(), ParameterDataError> {
+        self.check_injected_error("remove_parameter_tags", profile_name)?;
+        let key = ParameterKey {
+            profile_name: profile_name.to_string(),
+            region: self.region(profile_name, region),
+            name: parameter_name,
+        };
+        self.check_parameter_exists(&key)?;
+
+        if let Some(parameter_tags) = self.lock_tags().get_mut(&key) {
+            for tag_key in &tag_keys {
+                parameter_tags.remove(tag_key);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_parameter(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_name: String,
+    ) -> error_stack::Result<ParameterDeletion, ParameterDataError> {
+        let mut deletions = self
+            .delete_parameters(profile_name, region, vec![parameter_name])
+            .await?;
+
+        Ok(deletions.remove(0))
+    }
+
+    async fn delete_parameters(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        parameter_names: Vec<String>,
+    ) -> error_stack::Result<Vec<ParameterDeletion>, ParameterDataError> {
+        self.check_injected_error("delete_parameters", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let mut parameters = self.lock_parameters();
+        let mut labels = self.lock_labels();
+        let mut tags = self.lock_tags();
+        let deletions = parameter_names
+            .into_iter()
+            .map(|name| {
+                let key = ParameterKey {
+                    profile_name: profile_name.to_string(),
+                    region: region.clone(),
+                    name: name.clone(),
+                };
+                labels.remove(&key);
+                tags.remove(&key);
+                let status = match parameters.remove(&key) {
+                    Some(_) => DeletionStatus::Deleted,
+                    None => DeletionStatus::NotFound,
+                };
+                ParameterDeletion::new(name, status)
+            })
+            .collect();
+
+        Ok(deletions)
+    }
+
+    async fn load_parameters_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<Parameter>, ParameterDataError> {
+        self.check_injected_error("load_parameters_by_path", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let parameters = self
+            .lock_parameters()
+            .iter()
+            .filter(|(key, _)| key.profile_name == profile_name && key.region == region)
+            .filter(|(key, _)| Self::is_below_path(&key.name, &path, recursive))
+            .filter_map(|(_, versions)| versions.last().cloned())
+            .collect();
+
+        Ok(parameters)
+    }
+
+    async fn load_parameter_infos_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+        recursive: bool,
+    ) -> error_stack::Result<Vec<ParameterInfo>, ParameterDataError> {
+        self.check_injected_error("load_parameter_infos_by_path", profile_name)?;
+        let parameters = self
+            .load_parameters_by_path(profile_name, region, path, recursive)
+            .await?;
+
+        Ok(parameters.iter().map(ParameterInfo::from).collect())
+    }
+
+    async fn load_parameter_names_by_path(
+        &self,
+        profile_name: &str,
+        region: Option<String>,
+        path: String,
+    ) -> error_stack::Result<Vec<String>, ParameterDataError> {
+        self.check_injected_error("load_parameter_names_by_path", profile_name)?;
+        let region = self.region(profile_name, region);
+
+        let parameter_names = self
+            .lock_parameters()
+            .keys()
+            .filter(|key| key.profile_name == profile_name && key.region == region)
+            .filter(|key| Self::is_below_path(&key.name, &path, true))
+            .map(|key| key.name.clone())
+            .collect();
+
+        Ok(parameter_names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::common::demo::{DemoParameter, DemoProfile, InjectedError};
+
+    use super::*;
+
+    fn fixture() -> DemoFixture {
+        DemoFixture {
+            profiles: vec![DemoProfile {
+                name: "dev".to_string(),
+                region: Some("eu-west-1".to_string()),
+                output_format: None,
+                access_key_id: None,
+                secret_access_key: None,
+                session_minutes: None,
+                protection_level: Default::default(),
+            }],
+            parameters: vec![DemoParameter {
+                profile_name: "dev".to_string(),
+                region: None,
+                name: "/app/password".to_string(),
+                parameter_type: DemoParameterType::SecureString,
+                versions: vec!["old".to_string(), "new".to_string()],
+            }],
+            errors: vec![InjectedError {
+                operation: "upsert_parameter".to_string(),
+                profile_name: Some("broken".to_string()),
+                code: "ParameterDataWriteError".to_string(),
+                message: Some("ParameterAlreadyExists".to_string()),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn should_load_latest_version_from_profile_default_region() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .load_parameters("dev", None, vec!["/app/password".to_string()])
+            .await;
+
+        assert_that!(actual).is_ok();
+        let parameter_set = actual.unwrap();
+        let parameter = &parameter_set.values()[0];
+        assert_that!(parameter.version).is_equal_to(Some(2));
+        assert_that!(parameter.value)
+            .is_equal_to(ParameterValue::SecureString(SecureString::from("new")));
+    }
+
+    #[tokio::test]
+    async fn should_add_version_on_upsert() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .upsert_parameter(
+                "dev",
+                Some("eu-west-1".to_string()),
+                (
+                    "/app/password".to_string(),
+                    ParameterValue::SecureString(SecureString::from("newer")),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await;
+
+        assert_that!(actual).is_ok();
+        let parameter_set = cut
+            .load_parameters("dev", None, vec!["/app/password".to_string()])
+            .await
+            .unwrap();
+        assert_that!(parameter_set.values()[0].version).is_equal_to(Some(3));
+    }
+
+    #[tokio::test]
+    async fn should_not_overwrite_existing_parameter_in_create_only_mode() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .upsert_parameter(
+                "dev",
+                None,
+                (
+                    "/app/password".to_string(),
+                    ParameterValue::SecureString(SecureString::from("newer")),
+                )
+                    .into(),
+                WriteMode::CreateOnly,
+                vec![],
+            )
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(ParameterDataError::from(actual.unwrap_err())).is_equal_to(
+            ParameterDataError::ParameterAlreadyExists("/app/password".to_string()),
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_deleted_and_missing_parameters() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .delete_parameters(
+                "dev",
+                None,
+                vec!["/app/password".to_string(), "/app/missing".to_string()],
+            )
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(vec![
+            ParameterDeletion::new("/app/password".to_string(), DeletionStatus::Deleted),
+            ParameterDeletion::new("/app/missing".to_string(), DeletionStatus::NotFound),
+        ]);
+        let remaining = cut.load_available_parameter_names("dev", None).await;
+        assert_that!(remaining).is_ok().is_empty();
+    }
+
+    #[tokio::test]
+    async fn should_load_every_version_as_history() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .load_parameter_history("dev", None, "/app/password".to_string())
+            .await;
+
+        assert_that!(actual).is_ok();
+        let history = actual.unwrap();
+        let values: Vec<ParameterValue> = history
+            .versions
+            .iter()
+            .map(|version| version.value.clone())
+            .collect();
+        assert_that!(values).is_equal_to(vec![
+            ParameterValue::SecureString(SecureString::from("old")),
+            ParameterValue::SecureString(SecureString::from("new")),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn should_move_labels_and_load_labeled_version() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+        let name = "/app/password".to_string();
+        let labels = vec!["live".to_string(), "aws-live".to_string()];
+
+        let first_update = cut
+            .label_parameter_version("dev", None, name.clone(), 2, labels.clone())
+            .await;
+        let second_update = cut
+            .label_parameter_version("dev", None, name.clone(), 1, labels)
+            .await;
+        let actual = cut
+            .load_parameters("dev", None, vec!["/app/password:live".to_string()])
+            .await;
+
+        assert_that!(first_update.unwrap().invalid_labels)
+            .is_equal_to(vec!["aws-live".to_string()]);
+        assert_that!(second_update.unwrap().labels).is_equal_to(vec!["live".to_string()]);
+        let parameter_set = actual.unwrap();
+        let parameter = &parameter_set.values()[0];
+        assert_that!(parameter.name).is_equal_to(name.clone());
+        assert_that!(parameter.version).is_equal_to(Some(1));
+        assert_that!(parameter.selector).is_equal_to(Some("live".to_string()));
+        let history = cut.load_parameter_history("dev", None, name).await.unwrap();
+        assert_that!(history.labels().len()).is_equal_to(1);
+        assert_that!(history.versions[0].labels).is_equal_to(vec!["live".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn should_only_unlabel_labels_on_the_version() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+        let name = "/app/password".to_string();
+        cut.label_parameter_version("dev", None, name.clone(), 2, vec!["live".to_string()])
+            .await
+            .unwrap();
+
+        let actual = cut
+            .unlabel_parameter_version("dev", None, name, 1, vec!["live".to_string()])
+            .await;
+
+        assert_that!(actual).is_ok();
+        let update = actual.unwrap();
+        assert_that!(update.labels).is_empty();
+        assert_that!(update.invalid_labels).is_equal_to(vec!["live".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn should_filter_parameter_names_by_tag() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+        cut.add_parameter_tags(
+            "dev",
+            None,
+            "/app/password".to_string(),
+            vec![ParameterTag::new(
+                "team".to_string(),
+                "payments".to_string(),
+            )],
+        )
+        .await
+        .unwrap();
+        let filters = |tag_value: &str| ParameterFilters {
+            tag_key: Some("team".to_string()),
+            tag_value: Some(tag_value.to_string()),
+            ..ParameterFilters::default()
+        };
+
+        let actual_match = cut
+            .load_parameter_names_page("dev", None, filters("payments"), None, None)
+            .await;
+        let actual_mismatch = cut
+            .load_parameter_names_page("dev", None, filters("search"), None, None)
+            .await;
+        let actual_tags = cut
+            .load_parameter_tags("dev", None, "/app/password".to_string())
+            .await;
+
+        assert_that!(actual_match.unwrap().names).is_equal_to(vec!["/app/password".to_string()]);
+        assert_that!(actual_mismatch.unwrap().names).is_empty();
+        assert_that!(actual_tags)
+            .is_ok()
+            .is_equal_to(vec![ParameterTag::new(
+                "team".to_string(),
+                "payments".to_string(),
+            )]);
+    }
+
+    #[tokio::test]
+    async fn should_only_accept_tags_when_creating_parameter() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+        let tags = vec![ParameterTag::new(
+            "team".to_string(),
+            "payments".to_string(),
+        )];
+        let parameter = |name: &str| -> Parameter {
+            (name.to_string(), ParameterValue::String("x".to_string())).into()
+        };
+
+        let actual_creation = cut
+            .upsert_parameter(
+                "dev",
+                None,
+                parameter("/app/team"),
+                WriteMode::CreateOnly,
+                tags.clone(),
+            )
+            .await;
+        let actual_overwrite = cut
+            .upsert_parameter(
+                "dev",
+                None,
+                parameter("/app/team"),
+                WriteMode::Overwrite,
+                tags.clone(),
+            )
+            .await;
+        let actual_tags = cut
+            .load_parameter_tags("dev", None, "/app/team".to_string())
+            .await;
+
+        assert_that!(actual_creation).is_ok();
+        assert_that!(actual_overwrite).is_err();
+        assert_that!(actual_tags).is_ok().is_equal_to(tags);
+    }
+
+    #[tokio::test]
+    async fn should_not_find_parameter_in_other_region() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .load_available_parameter_names("dev", Some("us-east-1".to_string()))
+            .await;
+
+        assert_that!(actual).is_ok().is_empty();
+    }
+
+    #[tokio::test]
+    async fn should_only_load_direct_children_unless_recursive() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let direct = cut
+            .load_parameters_by_path("dev", None, "/".to_string(), false)
+            .await;
+        let recursive = cut
+            .load_parameters_by_path("dev", None, "/".to_string(), true)
+            .await;
+
+        assert_that!(direct).is_ok().is_empty();
+        assert_that!(recursive).is_ok().has_length(1);
+    }
+
+    #[tokio::test]
+    async fn should_page_through_filtered_parameter_names() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+        for name in ["/app/name", "/app/url", "/infra/vpc"] {
+            cut.upsert_parameter(
+                "dev",
+                None,
+                (
+                    name.to_string(),
+                    ParameterValue::String("value".to_string()),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+        let filters = ParameterFilters {
+            name_prefix: Some("/app".to_string()),
+            parameter_type: Some(ParameterType::String),
+            ..ParameterFilters::default()
+        };
+
+        let first_page = cut
+            .load_parameter_names_page("dev", None, filters.clone(), None, Some(1))
+            .await
+            .unwrap();
+        let second_page = cut
+            .load_parameter_names_page("dev", None, filters, first_page.next_cursor, Some(1))
+            .await
+            .unwrap();
+
+        assert_that!(first_page.names).is_equal_to(vec!["/app/name".to_string()]);
+        assert_that!(second_page.names).is_equal_to(vec!["/app/url".to_string()]);
+        assert_that!(second_page.next_cursor).is_none();
+    }
+
+    #[tokio::test]
+    async fn should_load_metadata_without_value() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .load_parameter_metadata("dev", None, vec!["/app/password".to_string()])
+            .await;
+
+        assert_that!(actual).is_ok().has_length(1);
+        let parameter_info = &actual.unwrap()[0];
+        assert_that!(parameter_info.version).is_equal_to(Some(2));
+        assert_that!(parameter_info.metadata.key_id).is_equal_to(Some(DEFAULT_KEY_ID.to_string()));
+    }
+
+    #[tokio::test]
+    async fn should_fail_with_injected_error() {
+        let cut = InMemoryParameterAdapter::new(fixture());
+
+        let actual = cut
+            .upsert_parameter(
+                "broken",
+                None,
+                (
+                    "param".to_string(),
+                    ParameterValue::String("value".to_string()),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await;
+
+        assert_that!(actual).is_err();
+        let error = ParameterDataError::from(actual.unwrap_err());
+        assert_that!(error).is_equal_to(ParameterDataError::ParameterDataWriteError(
+            "ParameterAlreadyExists".to_string(),
+        ));
+    }
+}
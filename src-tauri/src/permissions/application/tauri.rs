@@ -0,0 +1 @@
+pub mod permissions_handler;
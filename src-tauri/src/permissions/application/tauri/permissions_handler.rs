@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::permissions::core::api::PermissionDataAPI;
+use crate::permissions::core::domain::{
+    ActionBundle, PermissionCheckRequest, PermissionEvaluation,
+};
+use crate::permissions::core::error::PermissionDataError;
+
+#[derive(serde::Serialize)]
+pub struct ActionBundleResponse {
+    bundle: ActionBundle,
+    actions: Vec<String>,
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn check_permissions(
+    api: tauri::State<'_, Arc<dyn PermissionDataAPI>>,
+    profile_name: String,
+    request: PermissionCheckRequest,
+) -> Result<Vec<PermissionEvaluation>, PermissionDataError> {
+    api.check_permissions(profile_name.as_str(), request)
+        .await
+        .map_err(PermissionDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn get_action_bundles() -> Vec<ActionBundleResponse> {
+    ActionBundle::all()
+        .into_iter()
+        .map(|bundle| ActionBundleResponse {
+            bundle,
+            actions: bundle.actions(),
+        })
+        .collect()
+}
@@ -0,0 +1,5 @@
+pub mod api;
+pub mod domain;
+pub mod error;
+pub mod permission_service;
+pub mod spi;
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::permissions::core::domain::{PermissionCheckRequest, PermissionEvaluation};
+use crate::permissions::core::error::PermissionDataError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait PermissionDataAPI: Send + Sync {
+    async fn check_permissions(
+        &self,
+        profile_name: &str,
+        request: PermissionCheckRequest,
+    ) -> Result<Vec<PermissionEvaluation>, PermissionDataError>;
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+use crate::permissions::core::error::PermissionDataError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActionBundle {
+    Parameters,
+    Profiles,
+    Credentials,
+}
+
+impl ActionBundle {
+    pub fn all() -> Vec<ActionBundle> {
+        vec![
+            ActionBundle::Parameters,
+            ActionBundle::Profiles,
+            ActionBundle::Credentials,
+        ]
+    }
+
+    /// The IAM actions the backend calls to implement the respective feature.
+    pub fn actions(&self) -> Vec<String> {
+        let actions: &[&str] = match self {
+            ActionBundle::Parameters => &[
+                "ssm:DescribeParameters",
+                "ssm:GetParameters",
+                "ssm:GetParametersByPath",
+                "ssm:GetParameterHistory",
+                "ssm:PutParameter",
+                "ssm:DeleteParameter",
+                "ssm:DeleteParameters",
+                "ssm:LabelParameterVersion",
+                "ssm:UnlabelParameterVersion",
+                "ssm:ListTagsForResource",
+                "ssm:AddTagsToResource",
+                "ssm:RemoveTagsFromResource",
+            ],
+            ActionBundle::Profiles => &["iam:ListAccountAliases", "organizations:DescribeAccount"],
+            ActionBundle::Credentials => &[
+                "sts:GetCallerIdentity",
+                "sts:GetFederationToken",
+                "sts:DecodeAuthorizationMessage",
+            ],
+        };
+
+        actions.iter().map(|action| action.to_string()).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PermissionCheckRequest {
+    #[serde(default)]
+    pub actions: Vec<String>,
+    #[serde(default)]
+    pub bundles: Vec<ActionBundle>,
+    #[serde(default)]
+    pub resources: Vec<String>,
+}
+
+impl PermissionCheckRequest {
+    pub fn new(actions: Vec<String>, bundles: Vec<ActionBundle>, resources: Vec<String>) -> Self {
+        Self {
+            actions,
+            bundles,
+            resources,
+        }
+    }
+
+    /// All requested actions, explicit ones first, without duplicates.
+    pub fn effective_actions(&self) -> Vec<String> {
+        let mut effective_actions: Vec<String> = vec![];
+        let bundle_actions = self.bundles.iter().flat_map(|bundle| bundle.actions());
+
+        for action in self.actions.iter().cloned().chain(bundle_actions) {
+            if !effective_actions.contains(&action) {
+                effective_actions.push(action);
+            }
+        }
+
+        effective_actions
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EvaluationDecision {
+    Allowed,
+    ExplicitDeny,
+    ImplicitDeny,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchedStatement {
+    pub source_policy_id: Option<String>,
+    pub source_policy_type: Option<String>,
+    pub start_line: Option<i32>,
+    pub end_line: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionEvaluation {
+    pub action: String,
+    pub resource: Option<String>,
+    pub decision: EvaluationDecision,
+    pub matched_statements: Vec<MatchedStatement>,
+}
+
+impl PermissionEvaluation {
+    pub fn is_allowed(&self) -> bool {
+        self.decision == EvaluationDecision::Allowed
+    }
+}
+
+/// Converts the ARN returned by `GetCallerIdentity` into the ARN of the IAM principal that can
+/// be passed to `SimulatePrincipalPolicy`. Assumed-role sessions are mapped back to their role.
+///
+/// Role paths are not part of the session ARN, so roles with a path other than `/` cannot be
+/// resolved this way.
+pub fn principal_arn(caller_arn: &str) -> Result<String, PermissionDataError> {
+    let invalid = || PermissionDataError::InvalidPrincipal(caller_arn.to_string());
+
+    let parts: Vec<&str> = caller_arn.splitn(6, ':').collect();
+    if parts.len() != 6 || parts[0] != "arn" {
+        return Err(invalid());
+    }
+    let (partition, service, account_id, resource) = (parts[1], parts[2], parts[4], parts[5]);
+
+    match service {
+        "iam" => Ok(caller_arn.to_string()),
+        "sts" => {
+            let mut segments = resource.split('/');
+            match (segments.next(), segments.next()) {
+                (Some("assumed-role"), Some(role_name)) if !role_name.is_empty() => Ok(format!(
+                    "arn:{}:iam::{}:role/{}",
+                    partition, account_id, role_name
+                )),
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_keep_iam_user_arn() {
+        let actual = principal_arn("arn:aws:iam::123456789012:user/ci/deployer");
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to("arn:aws:iam::123456789012:user/ci/deployer".to_string());
+    }
+
+    #[test]
+    fn should_map_assumed_role_session_to_role_arn() {
+        let actual = principal_arn("arn:aws:sts::123456789012:assumed-role/Deployer/session-1");
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to("arn:aws:iam::123456789012:role/Deployer".to_string());
+    }
+
+    #[test]
+    fn should_keep_partition_of_assumed_role() {
+        let actual = principal_arn("arn:aws-cn:sts::123456789012:assumed-role/Deployer/session");
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to("arn:aws-cn:iam::123456789012:role/Deployer".to_string());
+    }
+
+    #[test]
+    fn should_reject_federated_user() {
+        let actual = principal_arn("arn:aws:sts::123456789012:federated-user/alice");
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_reject_malformed_arn() {
+        let actual = principal_arn("not-an-arn");
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_combine_explicit_and_bundle_actions_without_duplicates() {
+        let cut = PermissionCheckRequest::new(
+            vec!["ssm:PutParameter".to_string(), "kms:Decrypt".to_string()],
+            vec![ActionBundle::Parameters],
+            vec![],
+        );
+
+        let actual = cut.effective_actions();
+
+        assert_that!(actual).is_equal_to(vec![
+            "ssm:PutParameter".to_string(),
+            "kms:Decrypt".to_string(),
+            "ssm:DescribeParameters".to_string(),
+            "ssm:GetParameters".to_string(),
+            "ssm:GetParametersByPath".to_string(),
+            "ssm:GetParameterHistory".to_string(),
+            "ssm:DeleteParameter".to_string(),
+            "ssm:DeleteParameters".to_string(),
+            "ssm:LabelParameterVersion".to_string(),
+            "ssm:UnlabelParameterVersion".to_string(),
+            "ssm:ListTagsForResource".to_string(),
+            "ssm:AddTagsToResource".to_string(),
+            "ssm:RemoveTagsFromResource".to_string(),
+        ]);
+    }
+}
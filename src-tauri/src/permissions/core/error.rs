@@ -0,0 +1,96 @@
+use std::fmt::{Display, Formatter};
+
+use error_stack::{Context, Report};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum PermissionDataError {
+    InvalidCredentialsError,
+    CallerIdentityLoadError(String),
+    InvalidPrincipal(String),
+    NoActionsRequested,
+    PolicySimulationError(String),
+}
+
+impl Display for PermissionDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionDataError::InvalidCredentialsError => write!(f, "invalid credentials error"),
+            PermissionDataError::CallerIdentityLoadError(reason) => {
+                write!(f, "failed to load caller identity: {}", reason)
+            }
+            PermissionDataError::InvalidPrincipal(arn) => {
+                write!(f, "principal cannot be simulated: {}", arn)
+            }
+            PermissionDataError::NoActionsRequested => write!(f, "no actions requested"),
+            PermissionDataError::PolicySimulationError(reason) => {
+                write!(f, "failed to simulate policies: {}", reason)
+            }
+        }
+    }
+}
+
+impl Context for PermissionDataError {}
+
+impl From<Report<PermissionDataError>> for PermissionDataError {
+    fn from(value: Report<PermissionDataError>) -> Self {
+        let context = value.current_context();
+        context.clone()
+    }
+}
+
+impl Serialize for PermissionDataError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PermissionDataError", 1)?;
+        let code = match self {
+            PermissionDataError::InvalidCredentialsError => "InvalidCredentialsError",
+            PermissionDataError::CallerIdentityLoadError(_) => "CallerIdentityLoadError",
+            PermissionDataError::InvalidPrincipal(_) => "InvalidPrincipal",
+            PermissionDataError::NoActionsRequested => "NoActionsRequested",
+            PermissionDataError::PolicySimulationError(_) => "PolicySimulationError",
+        };
+        state.serialize_field(
+            "error",
+            &json!({ "code": code, "message": self.to_string() }),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_return_invalid_principal_when_calling_from_on_report_with_context_invalid_principal()
+    {
+        let error = PermissionDataError::InvalidPrincipal("arn".to_string());
+        let report = Report::new(error.clone());
+
+        let result: PermissionDataError = report.into();
+
+        assert_eq!(error, result);
+    }
+
+    #[test]
+    fn should_serialize_policy_simulation_error_to_json() {
+        let error = PermissionDataError::PolicySimulationError("AccessDenied".to_string());
+        let expected = json!({
+            "error": {
+                "code": "PolicySimulationError",
+                "message": "failed to simulate policies: AccessDenied"
+            }
+        });
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected.to_string());
+    }
+}
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use error_stack::Report;
+
+use crate::permissions::core::api::PermissionDataAPI;
+use crate::permissions::core::domain::{
+    principal_arn, PermissionCheckRequest, PermissionEvaluation,
+};
+use crate::permissions::core::error::PermissionDataError;
+use crate::permissions::core::spi::PermissionDataSPI;
+
+pub struct PermissionService {
+    permission_data_spi: Box<dyn PermissionDataSPI>,
+}
+
+impl PermissionService {
+    pub fn new(permission_data_spi: Box<dyn PermissionDataSPI>) -> Self {
+        Self {
+            permission_data_spi,
+        }
+    }
+}
+
+#[async_trait]
+impl PermissionDataAPI for PermissionService {
+    async fn check_permissions(
+        &self,
+        profile_name: &str,
+        request: PermissionCheckRequest,
+    ) -> error_stack::Result<Vec<PermissionEvaluation>, PermissionDataError> {
+        let actions = request.effective_actions();
+        if actions.is_empty() {
+            return Err(Report::new(PermissionDataError::NoActionsRequested));
+        }
+
+        let caller_arn = self
+            .permission_data_spi
+            .load_caller_arn(profile_name)
+            .await?;
+        let principal_arn = principal_arn(&caller_arn).map_err(Report::new)?;
+
+        self.permission_data_spi
+            .simulate_principal_policy(profile_name, &principal_arn, actions, request.resources)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::permissions::core::domain::{ActionBundle, EvaluationDecision};
+    use crate::permissions::core::spi::MockPermissionDataSPI;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_simulate_bundle_actions_for_role_of_assumed_role_session() {
+        let mut permission_data_spi_mock = MockPermissionDataSPI::new();
+        permission_data_spi_mock
+            .expect_load_caller_arn()
+            .with(eq("prod"))
+            .returning(|_| {
+                Ok("arn:aws:sts::123456789012:assumed-role/Deployer/session".to_string())
+            });
+        permission_data_spi_mock
+            .expect_simulate_principal_policy()
+            .with(
+                eq("prod"),
+                eq("arn:aws:iam::123456789012:role/Deployer"),
+                eq(vec![
+                    "sts:GetCallerIdentity".to_string(),
+                    "sts:GetFederationToken".to_string(),
+                    "sts:DecodeAuthorizationMessage".to_string(),
+                ]),
+                eq(vec!["*".to_string()]),
+            )
+            .times(1)
+            .returning(|_, _, actions, _| {
+                Ok(actions
+                    .into_iter()
+                    .map(|action| PermissionEvaluation {
+                        action,
+                        resource: Some("*".to_string()),
+                        decision: EvaluationDecision::Allowed,
+                        matched_statements: vec![],
+                    })
+                    .collect())
+            });
+        let cut = PermissionService::new(Box::new(permission_data_spi_mock));
+
+        let actual = cut
+            .check_permissions(
+                "prod",
+                PermissionCheckRequest::new(
+                    vec![],
+                    vec![ActionBundle::Credentials],
+                    vec!["*".to_string()],
+                ),
+            )
+            .await;
+
+        assert_that!(actual).is_ok().has_length(3);
+    }
+
+    #[tokio::test]
+    async fn should_reject_request_without_actions() {
+        let mut permission_data_spi_mock = MockPermissionDataSPI::new();
+        permission_data_spi_mock.expect_load_caller_arn().never();
+        let cut = PermissionService::new(Box::new(permission_data_spi_mock));
+
+        let actual = cut
+            .check_permissions("prod", PermissionCheckRequest::default())
+            .await;
+
+        assert_that!(actual).is_err();
+        assert_that!(actual.unwrap_err().current_context())
+            .is_equal_to(&PermissionDataError::NoActionsRequested);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_for_principal_that_cannot_be_simulated() {
+        let mut permission_data_spi_mock = MockPermissionDataSPI::new();
+        permission_data_spi_mock
+            .expect_load_caller_arn()
+            .returning(|_| Ok("arn:aws:sts::123456789012:federated-user/alice".to_string()));
+        permission_data_spi_mock
+            .expect_simulate_principal_policy()
+            .never();
+        let cut = PermissionService::new(Box::new(permission_data_spi_mock));
+
+        let actual = cut
+            .check_permissions(
+                "prod",
+                PermissionCheckRequest::new(vec!["ssm:PutParameter".to_string()], vec![], vec![]),
+            )
+            .await;
+
+        assert_that!(actual).is_err();
+    }
+}
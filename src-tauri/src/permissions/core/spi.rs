@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::permissions::core::domain::PermissionEvaluation;
+use crate::permissions::core::error::PermissionDataError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait PermissionDataSPI: Send + Sync {
+    async fn load_caller_arn(&self, profile_name: &str) -> Result<String, PermissionDataError>;
+
+    async fn simulate_principal_policy(
+        &self,
+        profile_name: &str,
+        principal_arn: &str,
+        actions: Vec<String>,
+        resources: Vec<String>,
+    ) -> Result<Vec<PermissionEvaluation>, PermissionDataError>;
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use aws_sdk_iam::error::ProvideErrorMetadata;
+use aws_sdk_iam::types::{
+    EvaluationResult, PolicyEvaluationDecisionType, Statement as IAMStatement,
+};
+use error_stack::Report;
+
+use crate::common::aws::client_factory;
+use crate::permissions::core::domain::{
+    EvaluationDecision, MatchedStatement, PermissionEvaluation,
+};
+use crate::permissions::core::error::PermissionDataError;
+use crate::permissions::core::spi::PermissionDataSPI;
+
+pub struct PolicySimulationAdapter;
+
+#[async_trait]
+impl PermissionDataSPI for PolicySimulationAdapter {
+    async fn load_caller_arn(
+        &self,
+        profile_name: &str,
+    ) -> error_stack::Result<String, PermissionDataError> {
+        let client = client_factory()
+            .sts_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(PermissionDataError::CallerIdentityLoadError(err)))?;
+
+        let result = client.get_caller_identity().send().await;
+        match result {
+            Ok(output) => output.arn().map(|arn| arn.to_string()).ok_or_else(|| {
+                Report::new(PermissionDataError::CallerIdentityLoadError(
+                    "caller identity should have an arn".to_string(),
+                ))
+            }),
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                match error_code {
+                    Some("InvalidClientTokenId") => {
+                        Err(Report::new(PermissionDataError::InvalidCredentialsError))
+                    }
+                    _ => Err(Report::new(PermissionDataError::CallerIdentityLoadError(
+                        error_code.unwrap_or("Server Error").to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+
+    async fn simulate_principal_policy(
+        &self,
+        profile_name: &str,
+        principal_arn: &str,
+        actions: Vec<String>,
+        resources: Vec<String>,
+    ) -> error_stack::Result<Vec<PermissionEvaluation>, PermissionDataError> {
+        let client = client_factory()
+            .iam_client(profile_name, None)
+            .await
+            .map_err(|err| Report::new(PermissionDataError::PolicySimulationError(err)))?;
+        let resources = if resources.is_empty() {
+            None
+        } else {
+            Some(resources)
+        };
+
+        let result: Result<Vec<_>, _> = client
+            .simulate_principal_policy()
+            .policy_source_arn(principal_arn)
+            .set_action_names(Some(actions))
+            .set_resource_arns(resources)
+            .into_paginator()
+            .send()
+            .collect()
+            .await;
+
+        match result {
+            Ok(responses) => Ok(responses
+                .iter()
+                .flat_map(|response| response.evaluation_results().iter())
+                .map(Self::parse_evaluation_result)
+                .collect()),
+            Err(sdk_error) => {
+                let error_code = sdk_error.code();
+                let error_message = sdk_error.message();
+
+                tracing::error!("Error: [{:?}] {:?}", error_code, error_message);
+
+                Err(Report::new(PermissionDataError::PolicySimulationError(
+                    error_message
+                        .or(error_code)
+                        .unwrap_or("unknown simulation error")
+                        .to_string(),
+                )))
+            }
+        }
+    }
+}
+
+impl PolicySimulationAdapter {
+    fn parse_evaluation_result(evaluation_result: &EvaluationResult) -> PermissionEvaluation {
+        let decision = match evaluation_result.eval_decision() {
+            PolicyEvaluationDecisionType::Allowed => EvaluationDecision::Allowed,
+            PolicyEvaluationDecisionType::ExplicitDeny => EvaluationDecision::ExplicitDeny,
+            _ => EvaluationDecision::ImplicitDeny,
+        };
+
+        PermissionEvaluation {
+            action: evaluation_result.eval_action_name().to_string(),
+            resource: evaluation_result
+                .eval_resource_name()
+                .map(|resource| resource.to_string()),
+            decision,
+            matched_statements: evaluation_result
+                .matched_statements()
+                .iter()
+                .map(Self::parse_statement)
+                .collect(),
+        }
+    }
+
+    fn parse_statement(statement: &IAMStatement) -> MatchedStatement {
+        MatchedStatement {
+            source_policy_id: statement.source_policy_id().map(|id| id.to_string()),
+            source_policy_type: statement
+                .source_policy_type()
+                .map(|policy_type| policy_type.as_str().to_string()),
+            start_line: statement.start_position().map(|position| position.line),
+            end_line: statement.end_position().map(|position| position.line),
+        }
+    }
+}
@@ -1,7 +1,8 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::profiles::core::api::ProfileDataAPI;
-use crate::profiles::core::domain::{Profile, ProfileSet};
+use crate::profiles::core::domain::{Profile, ProfileSet, ProtectionLevel};
 use crate::profiles::core::error::ProfileDataError;
 
 #[tauri::command]
@@ -28,9 +29,11 @@ pub fn create_profile(
 pub fn edit_profile(
     api: tauri::State<'_, Arc<dyn ProfileDataAPI>>,
     profile: Profile,
+    confirmation_token: Option<String>,
 ) -> Result<(), ProfileDataError> {
     tracing::info!("edit_profile: {:#?}", profile);
-    api.edit_profile(&profile).map_err(ProfileDataError::from)
+    api.edit_profile(&profile, confirmation_token)
+        .map_err(ProfileDataError::from)
 }
 
 #[tauri::command]
@@ -38,9 +41,10 @@ pub fn edit_profile(
 pub async fn delete_profile(
     api: tauri::State<'_, Arc<dyn ProfileDataAPI>>,
     profile_name: String,
+    confirmation_token: Option<String>,
 ) -> Result<(), ProfileDataError> {
     tracing::info!("delete_profile: {}", profile_name);
-    api.delete_profile(&profile_name)
+    api.delete_profile(&profile_name, confirmation_token)
         .map_err(ProfileDataError::from)
 }
 
@@ -49,8 +53,33 @@ pub async fn delete_profile(
 pub async fn delete_profiles(
     api: tauri::State<'_, Arc<dyn ProfileDataAPI>>,
     profile_names: Vec<String>,
+    confirmation_token: Option<String>,
 ) -> Result<(), ProfileDataError> {
     tracing::info!("delete_profiles: {:?}", profile_names);
-    api.delete_profiles(profile_names.as_slice())
+    api.delete_profiles(profile_names.as_slice(), confirmation_token)
+        .map_err(ProfileDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn get_protection_levels(
+    api: tauri::State<'_, Arc<dyn ProfileDataAPI>>,
+) -> Result<HashMap<String, ProtectionLevel>, ProfileDataError> {
+    api.get_protection_levels().map_err(ProfileDataError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn set_protection_level(
+    api: tauri::State<'_, Arc<dyn ProfileDataAPI>>,
+    profile_name: String,
+    protection_level: ProtectionLevel,
+) -> Result<(), ProfileDataError> {
+    tracing::info!(
+        "set_protection_level: {} {:?}",
+        profile_name,
+        protection_level
+    );
+    api.set_protection_level(&profile_name, protection_level)
         .map_err(ProfileDataError::from)
 }
@@ -2,4 +2,5 @@ pub mod api;
 pub mod domain;
 pub mod error;
 pub mod profile_service;
+pub mod protection_guard;
 pub mod spi;
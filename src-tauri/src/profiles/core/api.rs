@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use error_stack::Result;
 #[cfg(test)]
 use mockall::automock;
 
-use crate::profiles::core::domain::{Profile, ProfileSet};
+use crate::profiles::core::domain::{Profile, ProfileSet, ProtectionLevel};
 use crate::profiles::core::error::ProfileDataError;
 
 #[cfg_attr(test, automock)]
@@ -13,9 +15,29 @@ pub trait ProfileDataAPI: Send + Sync {
 
     fn create_profile(&self, profile: &Profile) -> Result<(), ProfileDataError>;
 
-    fn edit_profile(&self, profile: &Profile) -> Result<(), ProfileDataError>;
+    fn edit_profile(
+        &self,
+        profile: &Profile,
+        confirmation_token: Option<String>,
+    ) -> Result<(), ProfileDataError>;
+
+    fn delete_profile(
+        &self,
+        profile_name: &str,
+        confirmation_token: Option<String>,
+    ) -> Result<(), ProfileDataError>;
+
+    fn delete_profiles(
+        &self,
+        profile_names: &[String],
+        confirmation_token: Option<String>,
+    ) -> Result<(), ProfileDataError>;
 
-    fn delete_profile(&self, profile_name: &str) -> Result<(), ProfileDataError>;
+    fn get_protection_levels(&self) -> Result<HashMap<String, ProtectionLevel>, ProfileDataError>;
 
-    fn delete_profiles(&self, profile_names: &[String]) -> Result<(), ProfileDataError>;
+    fn set_protection_level(
+        &self,
+        profile_name: &str,
+        protection_level: ProtectionLevel,
+    ) -> Result<(), ProfileDataError>;
 }
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
@@ -86,6 +88,87 @@ impl Default for ProfileSet {
     }
 }
 
+/// How far a profile is protected against operations that change data.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProtectionLevel {
+    ReadOnly,
+    ConfirmRequired,
+    #[default]
+    Unrestricted,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProtectedOperation {
+    SetParameter,
+    DeleteParameter,
+    LabelParameter,
+    TagParameter,
+    ImportParameters,
+    EditProfile,
+    DeleteProfile,
+}
+
+impl Display for ProtectedOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProtectedOperation::SetParameter => write!(f, "set parameter"),
+            ProtectedOperation::DeleteParameter => write!(f, "delete parameter"),
+            ProtectedOperation::LabelParameter => write!(f, "label parameter"),
+            ProtectedOperation::TagParameter => write!(f, "tag parameter"),
+            ProtectedOperation::ImportParameters => write!(f, "import parameters"),
+            ProtectedOperation::EditProfile => write!(f, "edit profile"),
+            ProtectedOperation::DeleteProfile => write!(f, "delete profile"),
+        }
+    }
+}
+
+/// Why an operation was refused because of the protection of the profiles it targets.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(tag = "level", rename_all = "camelCase")]
+pub enum ProtectionDenial {
+    ReadOnly {
+        operation: ProtectedOperation,
+        #[serde(rename = "profileNames")]
+        profile_names: Vec<String>,
+    },
+    /// The operation runs once it is repeated with the confirmation token.
+    ConfirmationRequired {
+        operation: ProtectedOperation,
+        #[serde(rename = "profileNames")]
+        profile_names: Vec<String>,
+        #[serde(rename = "confirmationToken")]
+        confirmation_token: String,
+    },
+}
+
+impl Display for ProtectionDenial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProtectionDenial::ReadOnly {
+                operation,
+                profile_names,
+            } => write!(
+                f,
+                "cannot {}, profile '{}' is read-only",
+                operation,
+                profile_names.join("', '")
+            ),
+            ProtectionDenial::ConfirmationRequired {
+                operation,
+                profile_names,
+                ..
+            } => write!(
+                f,
+                "profile '{}' requires confirmation to {}",
+                profile_names.join("', '"),
+                operation
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fake::faker::lorem::en::Word;
@@ -154,8 +237,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "should serialize to utf8 string")]
-    fn should_panic_when_failing_to_serialize_credentials() {
+    fn should_fail_to_serialize_credentials_with_non_utf8_secret() {
         let bad_data = vec![0, 159, 146, 150]; // Non UTF-8 bytes
         let bad_sec_str = SecureString::from(bad_data);
 
@@ -164,7 +246,9 @@ mod tests {
             secret_access_key: Some(bad_sec_str),
         };
 
-        let _ = serde_json::to_string(&cred).unwrap();
+        let actual = serde_json::to_string(&cred);
+
+        assert_that!(actual).is_err();
     }
 
     #[test]
@@ -213,7 +297,7 @@ mod tests {
         // Test the deserialized data
         assert_eq!(deserialized.access_key_id, Some("myAccessKey".to_string()));
         let secret_access_key = deserialized.secret_access_key.unwrap();
-        assert_eq!(secret_access_key.as_str(), "mySecretKey");
+        assert_eq!(secret_access_key.try_as_str(), Ok("mySecretKey"));
     }
 
     #[test]
@@ -4,6 +4,8 @@ use serde::{Serialize, Serializer};
 use serde_json::json;
 use std::fmt::{Debug, Display, Formatter};
 
+use crate::profiles::core::domain::ProtectionDenial;
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum ProfileDataError {
     InvalidProfileNameError,
@@ -13,6 +15,9 @@ pub enum ProfileDataError {
     ConfigFileWriteError,
     CredentialsFileLoadError,
     CredentialsFileWriteError,
+    ProtectionLevelsLoadError,
+    ProtectionLevelsWriteError,
+    ProfileProtected(ProtectionDenial),
 }
 
 impl Display for ProfileDataError {
@@ -29,6 +34,13 @@ impl Display for ProfileDataError {
             ProfileDataError::CredentialsFileWriteError => {
                 write!(f, "failed to write credentials file")
             }
+            ProfileDataError::ProtectionLevelsLoadError => {
+                write!(f, "failed to load profile protection levels")
+            }
+            ProfileDataError::ProtectionLevelsWriteError => {
+                write!(f, "failed to write profile protection levels")
+            }
+            ProfileDataError::ProfileProtected(denial) => write!(f, "{}", denial),
         }
     }
 }
@@ -77,8 +89,21 @@ impl Serialize for ProfileDataError {
                 "CredentialsFileWriteError",
                 ProfileDataError::CredentialsFileWriteError.to_string(),
             ),
+            ProfileDataError::ProtectionLevelsLoadError => (
+                "ProtectionLevelsLoadError",
+                ProfileDataError::ProtectionLevelsLoadError.to_string(),
+            ),
+            ProfileDataError::ProtectionLevelsWriteError => (
+                "ProtectionLevelsWriteError",
+                ProfileDataError::ProtectionLevelsWriteError.to_string(),
+            ),
+            ProfileDataError::ProfileProtected(_) => ("ProfileProtected", self.to_string()),
         };
-        state.serialize_field("error", &json!({ "code": code, "message": message }))?;
+        let mut error = json!({ "code": code, "message": message });
+        if let ProfileDataError::ProfileProtected(denial) = self {
+            error["protection"] = json!(denial);
+        }
+        state.serialize_field("error", &error)?;
         state.end()
     }
 }
@@ -86,6 +111,7 @@ impl Serialize for ProfileDataError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::profiles::core::domain::ProtectedOperation;
     use serde_json::json;
 
     #[test]
@@ -139,4 +165,30 @@ mod tests {
 
         assert_eq!(serialized, expected);
     }
+
+    #[test]
+    fn serialize_profile_protected_error_with_confirmation_token_to_json() {
+        let error = ProfileDataError::ProfileProtected(ProtectionDenial::ConfirmationRequired {
+            operation: ProtectedOperation::DeleteProfile,
+            profile_names: vec!["prod".to_string()],
+            confirmation_token: "c0ffee".to_string(),
+        });
+        let expected = json!({
+            "error": {
+                "code": "ProfileProtected",
+                "message": "profile 'prod' requires confirmation to delete profile",
+                "protection": {
+                    "level": "confirmationRequired",
+                    "operation": "deleteProfile",
+                    "profileNames": ["prod"],
+                    "confirmationToken": "c0ffee"
+                }
+            }
+        })
+        .to_string();
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected);
+    }
 }
@@ -1,17 +1,45 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use error_stack::Report;
 
 use crate::profiles::core::api::ProfileDataAPI;
-use crate::profiles::core::domain::{Profile, ProfileSet};
+use crate::profiles::core::domain::{Profile, ProfileSet, ProtectedOperation, ProtectionLevel};
 use crate::profiles::core::error::ProfileDataError;
+use crate::profiles::core::protection_guard::ProfileProtectionGuard;
 use crate::profiles::core::spi::ProfileDataSPI;
 
 pub struct ProfileService {
     profile_data_spi: Box<dyn ProfileDataSPI>,
+    protection_guard: Arc<ProfileProtectionGuard>,
 }
 
 impl ProfileService {
-    pub fn new(profile_data_spi: Box<dyn ProfileDataSPI>) -> Self {
-        Self { profile_data_spi }
+    pub fn new(
+        profile_data_spi: Box<dyn ProfileDataSPI>,
+        protection_guard: Arc<ProfileProtectionGuard>,
+    ) -> Self {
+        Self {
+            profile_data_spi,
+            protection_guard,
+        }
+    }
+
+    fn check_protection(
+        &self,
+        operation: ProtectedOperation,
+        profile_names: &[String],
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        self.protection_guard
+            .check(
+                operation,
+                profile_names,
+                &profile_names.join(","),
+                confirmation_token,
+            )
+            .map_err(|denial| Report::new(ProfileDataError::ProfileProtected(denial)))
     }
 }
 
@@ -29,20 +57,62 @@ impl ProfileDataAPI for ProfileService {
         self.profile_data_spi.save_profile_data(profile)
     }
 
-    fn edit_profile(&self, profile: &Profile) -> error_stack::Result<(), ProfileDataError> {
+    fn edit_profile(
+        &self,
+        profile: &Profile,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        self.check_protection(
+            ProtectedOperation::EditProfile,
+            &[profile.name.clone()],
+            confirmation_token,
+        )?;
+
         self.profile_data_spi.update_profile_data(profile)
     }
 
-    fn delete_profile(&self, profile_name: &str) -> error_stack::Result<(), ProfileDataError> {
+    fn delete_profile(
+        &self,
+        profile_name: &str,
+        confirmation_token: Option<String>,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        self.check_protection(
+            ProtectedOperation::DeleteProfile,
+            &[profile_name.to_string()],
+            confirmation_token,
+        )?;
+
         self.profile_data_spi.remove_profile_data(profile_name)
     }
 
     fn delete_profiles(
         &self,
         profile_names: &[String],
+        confirmation_token: Option<String>,
     ) -> error_stack::Result<(), ProfileDataError> {
+        self.check_protection(
+            ProtectedOperation::DeleteProfile,
+            profile_names,
+            confirmation_token,
+        )?;
+
         self.profile_data_spi.remove_profiles_data(profile_names)
     }
+
+    fn get_protection_levels(
+        &self,
+    ) -> error_stack::Result<HashMap<String, ProtectionLevel>, ProfileDataError> {
+        self.protection_guard.protection_levels()
+    }
+
+    fn set_protection_level(
+        &self,
+        profile_name: &str,
+        protection_level: ProtectionLevel,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        self.protection_guard
+            .set_protection_level(profile_name, protection_level)
+    }
 }
 
 #[cfg(test)]
@@ -50,11 +120,26 @@ mod tests {
     use mockall::predicate::eq;
     use spectral::prelude::*;
 
-    use crate::profiles::core::domain::{Config, Credentials};
-    use crate::profiles::core::spi::MockProfileDataSPI;
+    use crate::profiles::core::domain::{Config, Credentials, ProtectionDenial};
+    use crate::profiles::core::spi::{MockProfileDataSPI, MockProfileProtectionSPI};
 
     use super::*;
 
+    fn protection_guard_with(
+        protection_levels: Vec<(&str, ProtectionLevel)>,
+    ) -> Arc<ProfileProtectionGuard> {
+        let protection_levels: HashMap<String, ProtectionLevel> = protection_levels
+            .into_iter()
+            .map(|(profile_name, level)| (profile_name.to_string(), level))
+            .collect();
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(move || Ok(protection_levels.clone()));
+
+        Arc::new(ProfileProtectionGuard::new(Box::new(protection_spi_mock)))
+    }
+
     #[tokio::test]
     async fn should_sort_profiles_asc_by_name() {
         let profile_1 = Profile::new("b".to_string(), Credentials::default(), Config::default());
@@ -69,7 +154,10 @@ mod tests {
             .expect_load_profile_data()
             .returning(move || Ok(profile_set.clone()));
 
-        let profile_service = ProfileService::new(Box::new(profile_data_spi_mock));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![]),
+        );
 
         let actual = profile_service.get_profiles().await.unwrap();
 
@@ -85,7 +173,10 @@ mod tests {
             .with(eq(profile.clone()))
             .times(1)
             .returning(move |_| Ok(()));
-        let profile_service = ProfileService::new(Box::new(profile_data_spi_mock));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![]),
+        );
 
         let actual = profile_service.create_profile(&profile);
 
@@ -101,9 +192,12 @@ mod tests {
             .with(eq(profile.clone()))
             .times(1)
             .returning(move |_| Ok(()));
-        let profile_service = ProfileService::new(Box::new(profile_data_spi_mock));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![]),
+        );
 
-        let actual = profile_service.edit_profile(&profile);
+        let actual = profile_service.edit_profile(&profile, None);
 
         assert_that!(actual).is_ok();
     }
@@ -117,9 +211,12 @@ mod tests {
             .with(eq(profile_name.clone()))
             .times(1)
             .returning(move |_| Ok(()));
-        let profile_service = ProfileService::new(Box::new(profile_data_spi_mock));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![]),
+        );
 
-        let actual = profile_service.delete_profile(&profile_name);
+        let actual = profile_service.delete_profile(&profile_name, None);
 
         assert_that!(actual).is_ok();
     }
@@ -133,9 +230,64 @@ mod tests {
             .with(eq(profile_names.clone()))
             .times(1)
             .returning(move |_| Ok(()));
-        let profile_service = ProfileService::new(Box::new(profile_data_spi_mock));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![]),
+        );
+
+        let actual = profile_service.delete_profiles(&profile_names, None);
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[test]
+    fn should_not_edit_read_only_profile() {
+        let profile = Profile::new(
+            "prod".to_string(),
+            Credentials::default(),
+            Config::default(),
+        );
+        let mut profile_data_spi_mock = MockProfileDataSPI::new();
+        profile_data_spi_mock.expect_update_profile_data().never();
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![("prod", ProtectionLevel::ReadOnly)]),
+        );
+
+        let actual = profile_service.edit_profile(&profile, None);
+
+        assert_that!(actual).is_err();
+        assert_that!(ProfileDataError::from(actual.unwrap_err())).is_equal_to(
+            ProfileDataError::ProfileProtected(ProtectionDenial::ReadOnly {
+                operation: ProtectedOperation::EditProfile,
+                profile_names: vec!["prod".to_string()],
+            }),
+        );
+    }
+
+    #[test]
+    fn should_delete_confirm_required_profiles_once_confirmed() {
+        let profile_names = ["dev".to_string(), "prod".to_string()];
+        let mut profile_data_spi_mock = MockProfileDataSPI::new();
+        profile_data_spi_mock
+            .expect_remove_profiles_data()
+            .with(eq(profile_names.clone()))
+            .times(1)
+            .returning(move |_| Ok(()));
+        let profile_service = ProfileService::new(
+            Box::new(profile_data_spi_mock),
+            protection_guard_with(vec![("prod", ProtectionLevel::ConfirmRequired)]),
+        );
 
-        let actual = profile_service.delete_profiles(&profile_names);
+        let unconfirmed = profile_service.delete_profiles(&profile_names, None);
+        let confirmation_token = match ProfileDataError::from(unconfirmed.unwrap_err()) {
+            ProfileDataError::ProfileProtected(ProtectionDenial::ConfirmationRequired {
+                confirmation_token,
+                ..
+            }) => confirmation_token,
+            other => panic!("expected confirmation to be required, got {:?}", other),
+        };
+        let actual = profile_service.delete_profiles(&profile_names, Some(confirmation_token));
 
         assert_that!(actual).is_ok();
     }
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::profiles::core::domain::{ProtectedOperation, ProtectionDenial, ProtectionLevel};
+use crate::profiles::core::error::ProfileDataError;
+use crate::profiles::core::spi::ProfileProtectionSPI;
+
+const CONFIRMATION_TOKEN_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone)]
+struct PendingConfirmation {
+    operation: ProtectedOperation,
+    profile_names: Vec<String>,
+    target: String,
+    issued_at: Instant,
+}
+
+/// Decides whether an operation that changes data may run against the given profiles. Every
+/// service offering such operations consults the same guard, so the protection cannot be
+/// bypassed by a single handler.
+pub struct ProfileProtectionGuard {
+    protection_spi: Box<dyn ProfileProtectionSPI>,
+    pending_confirmations: Mutex<HashMap<String, PendingConfirmation>>,
+}
+
+impl ProfileProtectionGuard {
+    pub fn new(protection_spi: Box<dyn ProfileProtectionSPI>) -> Self {
+        Self {
+            protection_spi,
+            pending_confirmations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn protection_levels(
+        &self,
+    ) -> error_stack::Result<HashMap<String, ProtectionLevel>, ProfileDataError> {
+        self.protection_spi.load_protection_levels()
+    }
+
+    pub fn set_protection_level(
+        &self,
+        profile_name: &str,
+        protection_level: ProtectionLevel,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        let mut protection_levels = self.protection_spi.load_protection_levels()?;
+
+        match protection_level {
+            ProtectionLevel::Unrestricted => protection_levels.remove(profile_name),
+            _ => protection_levels.insert(profile_name.to_string(), protection_level),
+        };
+
+        self.protection_spi
+            .save_protection_levels(&protection_levels)
+    }
+
+    /// Lets the operation pass if none of the profiles is protected. Read-only profiles always
+    /// refuse it, confirm-required profiles refuse it with a new single-use token until it is
+    /// repeated with that token. The token only confirms the operation on the same target, e.g.
+    /// the same parameters, so confirming one change cannot approve another.
+    pub fn check(
+        &self,
+        operation: ProtectedOperation,
+        profile_names: &[String],
+        target: &str,
+        confirmation_token: Option<String>,
+    ) -> Result<(), ProtectionDenial> {
+        let protection_levels = match self.protection_spi.load_protection_levels() {
+            Ok(protection_levels) => protection_levels,
+            Err(report) => {
+                tracing::error!("Error: failed to load protection levels: {:?}", report);
+                // Any of the profiles might be protected, so at least ask before going ahead.
+                profile_names
+                    .iter()
+                    .map(|profile_name| (profile_name.clone(), ProtectionLevel::ConfirmRequired))
+                    .collect()
+            }
+        };
+        let profiles_with_level = |protection_level: ProtectionLevel| {
+            let mut names: Vec<String> = profile_names
+                .iter()
+                .filter(|profile_name| {
+                    protection_levels
+                        .get(*profile_name)
+                        .copied()
+                        .unwrap_or_default()
+                        == protection_level
+                })
+                .cloned()
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        };
+
+        let read_only_profiles = profiles_with_level(ProtectionLevel::ReadOnly);
+        if !read_only_profiles.is_empty() {
+            return Err(ProtectionDenial::ReadOnly {
+                operation,
+                profile_names: read_only_profiles,
+            });
+        }
+
+        let confirm_required_profiles = profiles_with_level(ProtectionLevel::ConfirmRequired);
+        if confirm_required_profiles.is_empty() {
+            return Ok(());
+        }
+
+        let mut pending_confirmations = self
+            .pending_confirmations
+            .lock()
+            .expect("pending confirmations lock should not be poisoned");
+        pending_confirmations
+            .retain(|_, pending| pending.issued_at.elapsed() < CONFIRMATION_TOKEN_TTL);
+
+        if let Some(confirmation_token) = confirmation_token {
+            let confirmed =
+                pending_confirmations
+                    .get(&confirmation_token)
+                    .map_or(false, |pending| {
+                        pending.operation == operation
+                            && pending.profile_names == confirm_required_profiles
+                            && pending.target == target
+                    });
+            if confirmed {
+                pending_confirmations.remove(&confirmation_token);
+                return Ok(());
+            }
+        }
+
+        let confirmation_token = Uuid::new_v4().to_string();
+        pending_confirmations.insert(
+            confirmation_token.clone(),
+            PendingConfirmation {
+                operation,
+                profile_names: confirm_required_profiles.clone(),
+                target: target.to_string(),
+                issued_at: Instant::now(),
+            },
+        );
+
+        Err(ProtectionDenial::ConfirmationRequired {
+            operation,
+            profile_names: confirm_required_profiles,
+            confirmation_token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use spectral::prelude::*;
+
+    use crate::profiles::core::spi::MockProfileProtectionSPI;
+
+    use super::*;
+
+    fn guard_with(protection_levels: Vec<(&str, ProtectionLevel)>) -> ProfileProtectionGuard {
+        let protection_levels: HashMap<String, ProtectionLevel> = protection_levels
+            .into_iter()
+            .map(|(profile_name, level)| (profile_name.to_string(), level))
+            .collect();
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(move || Ok(protection_levels.clone()));
+
+        ProfileProtectionGuard::new(Box::new(protection_spi_mock))
+    }
+
+    fn confirmation_token_of(denial: ProtectionDenial) -> String {
+        match denial {
+            ProtectionDenial::ConfirmationRequired {
+                confirmation_token, ..
+            } => confirmation_token,
+            other => panic!("expected confirmation to be required, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_let_operations_on_unrestricted_profiles_pass() {
+        let cut = guard_with(vec![("prod", ProtectionLevel::ReadOnly)]);
+
+        let actual = cut.check(
+            ProtectedOperation::SetParameter,
+            &["dev".to_string()],
+            "/app/db",
+            None,
+        );
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[test]
+    fn should_refuse_operations_on_read_only_profiles_even_with_token() {
+        let cut = guard_with(vec![("prod", ProtectionLevel::ReadOnly)]);
+
+        let actual = cut.check(
+            ProtectedOperation::DeleteProfile,
+            &["dev".to_string(), "prod".to_string()],
+            "dev,prod",
+            Some("token".to_string()),
+        );
+
+        assert_that!(actual).is_equal_to(Err(ProtectionDenial::ReadOnly {
+            operation: ProtectedOperation::DeleteProfile,
+            profile_names: vec!["prod".to_string()],
+        }));
+    }
+
+    #[test]
+    fn should_require_confirmation_and_accept_issued_token_once() {
+        let cut = guard_with(vec![("prod", ProtectionLevel::ConfirmRequired)]);
+        let profile_names = vec!["prod".to_string()];
+
+        let denial = cut
+            .check(
+                ProtectedOperation::SetParameter,
+                &profile_names,
+                "/app/db",
+                None,
+            )
+            .unwrap_err();
+        let confirmation_token = confirmation_token_of(denial);
+
+        let confirmed = cut.check(
+            ProtectedOperation::SetParameter,
+            &profile_names,
+            "/app/db",
+            Some(confirmation_token.clone()),
+        );
+        let replayed = cut.check(
+            ProtectedOperation::SetParameter,
+            &profile_names,
+            "/app/db",
+            Some(confirmation_token),
+        );
+
+        assert_that!(confirmed).is_ok();
+        assert_that!(replayed).is_err();
+    }
+
+    #[test]
+    fn should_not_accept_token_issued_for_another_operation() {
+        let cut = guard_with(vec![("prod", ProtectionLevel::ConfirmRequired)]);
+        let profile_names = vec!["prod".to_string()];
+        let denial = cut
+            .check(
+                ProtectedOperation::EditProfile,
+                &profile_names,
+                "prod",
+                None,
+            )
+            .unwrap_err();
+
+        let actual = cut.check(
+            ProtectedOperation::DeleteProfile,
+            &profile_names,
+            "prod",
+            Some(confirmation_token_of(denial)),
+        );
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_not_accept_token_issued_for_another_target() {
+        let cut = guard_with(vec![("prod", ProtectionLevel::ConfirmRequired)]);
+        let profile_names = vec!["prod".to_string()];
+        let denial = cut
+            .check(
+                ProtectedOperation::DeleteParameter,
+                &profile_names,
+                "/app/feature-flag",
+                None,
+            )
+            .unwrap_err();
+
+        let actual = cut.check(
+            ProtectedOperation::DeleteParameter,
+            &profile_names,
+            "/app/db/password",
+            Some(confirmation_token_of(denial)),
+        );
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_require_confirmation_when_protection_levels_cannot_be_loaded() {
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(|| Err(Report::new(ProfileDataError::ProtectionLevelsLoadError)));
+        let cut = ProfileProtectionGuard::new(Box::new(protection_spi_mock));
+
+        let actual = cut.check(
+            ProtectedOperation::SetParameter,
+            &["dev".to_string()],
+            "/app/db",
+            None,
+        );
+
+        assert_that!(actual).is_err();
+    }
+
+    #[test]
+    fn should_drop_entry_when_profile_becomes_unrestricted() {
+        let mut protection_spi_mock = MockProfileProtectionSPI::new();
+        protection_spi_mock
+            .expect_load_protection_levels()
+            .returning(|| {
+                Ok(HashMap::from([(
+                    "prod".to_string(),
+                    ProtectionLevel::ReadOnly,
+                )]))
+            });
+        protection_spi_mock
+            .expect_save_protection_levels()
+            .withf(|protection_levels| protection_levels.is_empty())
+            .times(1)
+            .returning(|_| Ok(()));
+        let cut = ProfileProtectionGuard::new(Box::new(protection_spi_mock));
+
+        let actual = cut.set_protection_level("prod", ProtectionLevel::Unrestricted);
+
+        assert_that!(actual).is_ok();
+    }
+}
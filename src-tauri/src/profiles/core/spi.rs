@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use error_stack::Result;
 #[cfg(test)]
 use mockall::automock;
 
-use crate::profiles::core::domain::{Profile, ProfileSet};
+use crate::profiles::core::domain::{Profile, ProfileSet, ProtectionLevel};
 use crate::profiles::core::error::ProfileDataError;
 
 #[cfg_attr(test, automock)]
@@ -19,3 +21,14 @@ pub trait ProfileDataSPI: Send + Sync {
 
     fn update_profile_data(&self, profile: &Profile) -> Result<(), ProfileDataError>;
 }
+
+#[cfg_attr(test, automock)]
+pub trait ProfileProtectionSPI: Send + Sync {
+    /// Loads the protection levels by profile name. Profiles without an entry are unrestricted.
+    fn load_protection_levels(&self) -> Result<HashMap<String, ProtectionLevel>, ProfileDataError>;
+
+    fn save_protection_levels(
+        &self,
+        protection_levels: &HashMap<String, ProtectionLevel>,
+    ) -> Result<(), ProfileDataError>;
+}
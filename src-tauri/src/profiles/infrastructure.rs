@@ -1 +1,3 @@
 pub mod aws;
+pub mod json;
+pub mod memory;
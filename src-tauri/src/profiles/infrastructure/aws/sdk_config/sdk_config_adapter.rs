@@ -201,9 +201,12 @@ impl SdkConfigAdapter {
             &profile.credentials.access_key_id,
             &profile.credentials.secret_access_key,
         ) {
+            let secret_access_key = secret_access_key
+                .try_as_str()
+                .change_context(ProfileDataError::CredentialsFileWriteError)?;
             profile_section
                 .set("aws_access_key_id", access_key_id)
-                .set("aws_secret_access_key", secret_access_key.as_str());
+                .set("aws_secret_access_key", secret_access_key);
 
             credentials_file
                 .write_to_file(credentials_file_location.as_str())
@@ -273,8 +276,11 @@ impl SdkConfigAdapter {
             &profile.credentials.access_key_id,
             &profile.credentials.secret_access_key,
         ) {
+            let secret_access_key = secret_access_key
+                .try_as_str()
+                .change_context(ProfileDataError::CredentialsFileWriteError)?;
             properties.insert("aws_access_key_id", access_key_id);
-            properties.insert("aws_secret_access_key", secret_access_key.as_str());
+            properties.insert("aws_secret_access_key", secret_access_key);
 
             credentials_file
                 .write_to_file(credentials_file_location.as_str())
@@ -0,0 +1 @@
+pub mod json_profile_protection_adapter;
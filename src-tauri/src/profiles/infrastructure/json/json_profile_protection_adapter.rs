@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+
+use crate::common::app_data::JsonFileStore;
+use crate::profiles::core::domain::ProtectionLevel;
+use crate::profiles::core::error::ProfileDataError;
+use crate::profiles::core::spi::ProfileProtectionSPI;
+
+const PROTECTION_LEVELS_FILE_NAME: &str = "profile_protection.json";
+
+/// Keeps the protection levels in the app data directory rather than in the AWS config file, so
+/// other tools reading the config file are not affected by them.
+pub struct JsonProfileProtectionAdapter {
+    store: JsonFileStore,
+}
+
+impl JsonProfileProtectionAdapter {
+    pub fn new(protection_file_location: PathBuf) -> Self {
+        Self {
+            store: JsonFileStore::new(protection_file_location),
+        }
+    }
+}
+
+impl Default for JsonProfileProtectionAdapter {
+    fn default() -> Self {
+        Self {
+            store: JsonFileStore::in_app_data_dir(PROTECTION_LEVELS_FILE_NAME),
+        }
+    }
+}
+
+impl ProfileProtectionSPI for JsonProfileProtectionAdapter {
+    fn load_protection_levels(
+        &self,
+    ) -> error_stack::Result<HashMap<String, ProtectionLevel>, ProfileDataError> {
+        self.store
+            .load()
+            .change_context(ProfileDataError::ProtectionLevelsLoadError)
+    }
+
+    fn save_protection_levels(
+        &self,
+        protection_levels: &HashMap<String, ProtectionLevel>,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        self.store
+            .save(protection_levels)
+            .change_context(ProfileDataError::ProtectionLevelsWriteError)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod in_memory_profile_adapter;
+pub mod in_memory_profile_protection_adapter;
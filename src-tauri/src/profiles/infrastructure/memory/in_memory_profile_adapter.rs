@@ -0,0 +1,206 @@
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use error_stack::Report;
+
+use crate::common::demo::DemoFixture;
+use crate::profiles::core::domain::{Config, Credentials, Profile, ProfileSet};
+use crate::profiles::core::error::ProfileDataError;
+use crate::profiles::core::spi::ProfileDataSPI;
+
+/// Profile store for the demo mode. Changes live as long as the app runs and never touch the
+/// AWS config and credentials files.
+pub struct InMemoryProfileAdapter {
+    fixture: DemoFixture,
+    profiles: Mutex<Vec<Profile>>,
+}
+
+impl InMemoryProfileAdapter {
+    pub fn new(fixture: DemoFixture) -> Self {
+        let profiles = fixture
+            .profiles
+            .iter()
+            .map(|demo_profile| {
+                Profile::new(
+                    demo_profile.name.clone(),
+                    Credentials::new(
+                        demo_profile.access_key_id.as_deref(),
+                        demo_profile.secret_access_key.clone(),
+                    ),
+                    Config::new(
+                        demo_profile.region.as_deref(),
+                        demo_profile.output_format.as_deref(),
+                    ),
+                )
+            })
+            .collect();
+
+        Self {
+            fixture,
+            profiles: Mutex::new(profiles),
+        }
+    }
+
+    fn check_injected_error(
+        &self,
+        operation: &str,
+        profile_name: &str,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        match self.fixture.injected_error(operation, profile_name) {
+            Some(injected_error) => {
+                let error = match injected_error.code.as_str() {
+                    "InvalidProfileNameError" => ProfileDataError::InvalidProfileNameError,
+                    "ProfileNotFoundError" => ProfileDataError::ProfileNotFoundError,
+                    "ConfigFileLoadError" => ProfileDataError::ConfigFileLoadError,
+                    "ConfigFileWriteError" => ProfileDataError::ConfigFileWriteError,
+                    "CredentialsFileLoadError" => ProfileDataError::CredentialsFileLoadError,
+                    "CredentialsFileWriteError" => ProfileDataError::CredentialsFileWriteError,
+                    _ => ProfileDataError::ProfileDataLoadError,
+                };
+
+                Err(Report::new(error))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn lock_profiles(&self) -> MutexGuard<'_, Vec<Profile>> {
+        self.profiles
+            .lock()
+            .expect("profile store lock should not be poisoned")
+    }
+}
+
+#[async_trait]
+impl ProfileDataSPI for InMemoryProfileAdapter {
+    async fn load_profile_data(&self) -> error_stack::Result<ProfileSet, ProfileDataError> {
+        // Errors injected for loading apply to all profiles, as profiles are loaded at once.
+        self.check_injected_error("load_profile_data", "")?;
+
+        let mut profile_set = ProfileSet::new();
+        for profile in self.lock_profiles().iter() {
+            profile_set.add_profile(profile.clone());
+        }
+
+        Ok(profile_set)
+    }
+
+    fn save_profile_data(&self, profile: &Profile) -> error_stack::Result<(), ProfileDataError> {
+        self.check_injected_error("save_profile_data", &profile.name)?;
+        if profile.name.trim().is_empty() {
+            return Err(Report::new(ProfileDataError::InvalidProfileNameError));
+        }
+
+        let mut profiles = self.lock_profiles();
+        profiles.retain(|existing| existing.name != profile.name);
+        profiles.push(profile.clone());
+
+        Ok(())
+    }
+
+    fn remove_profile_data(&self, profile_name: &str) -> error_stack::Result<(), ProfileDataError> {
+        self.check_injected_error("remove_profile_data", profile_name)?;
+
+        let mut profiles = self.lock_profiles();
+        let profile_count = profiles.len();
+        profiles.retain(|existing| existing.name != profile_name);
+        if profiles.len() == profile_count {
+            return Err(Report::new(ProfileDataError::ProfileNotFoundError));
+        }
+
+        Ok(())
+    }
+
+    fn remove_profiles_data(
+        &self,
+        profile_names: &[String],
+    ) -> error_stack::Result<(), ProfileDataError> {
+        for profile_name in profile_names {
+            self.remove_profile_data(profile_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_profile_data(&self, profile: &Profile) -> error_stack::Result<(), ProfileDataError> {
+        self.check_injected_error("update_profile_data", &profile.name)?;
+
+        let mut profiles = self.lock_profiles();
+        match profiles
+            .iter_mut()
+            .find(|existing| existing.name == profile.name)
+        {
+            Some(existing) => {
+                *existing = profile.clone();
+                Ok(())
+            }
+            None => Err(Report::new(ProfileDataError::ProfileNotFoundError)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::common::demo::{DemoProfile, InjectedError};
+
+    use super::*;
+
+    fn fixture() -> DemoFixture {
+        DemoFixture {
+            profiles: vec![DemoProfile {
+                name: "dev".to_string(),
+                region: Some("eu-west-1".to_string()),
+                output_format: Some("json".to_string()),
+                access_key_id: Some("AKIADEMO".to_string()),
+                secret_access_key: None,
+                session_minutes: None,
+                protection_level: Default::default(),
+            }],
+            ..DemoFixture::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn should_load_profiles_from_fixture() {
+        let cut = InMemoryProfileAdapter::new(fixture());
+
+        let actual = cut.load_profile_data().await;
+
+        assert_that!(actual).is_ok();
+        let profile_set = actual.unwrap();
+        assert_that!(profile_set.profiles()[0].config.region)
+            .is_equal_to(Some("eu-west-1".to_string()));
+    }
+
+    #[test]
+    fn should_return_profile_not_found_error_when_removing_unknown_profile() {
+        let cut = InMemoryProfileAdapter::new(fixture());
+
+        let actual = cut.remove_profile_data("unknown");
+
+        assert_that!(actual).is_err();
+        let error = ProfileDataError::from(actual.unwrap_err());
+        assert_that!(error).is_equal_to(ProfileDataError::ProfileNotFoundError);
+    }
+
+    #[tokio::test]
+    async fn should_fail_loading_profiles_with_injected_error() {
+        let cut = InMemoryProfileAdapter::new(DemoFixture {
+            errors: vec![InjectedError {
+                operation: "load_profile_data".to_string(),
+                profile_name: None,
+                code: "ConfigFileLoadError".to_string(),
+                message: None,
+            }],
+            ..fixture()
+        });
+
+        let actual = cut.load_profile_data().await;
+
+        assert_that!(actual).is_err();
+        let error = ProfileDataError::from(actual.unwrap_err());
+        assert_that!(error).is_equal_to(ProfileDataError::ConfigFileLoadError);
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::common::demo::DemoFixture;
+use crate::profiles::core::domain::ProtectionLevel;
+use crate::profiles::core::error::ProfileDataError;
+use crate::profiles::core::spi::ProfileProtectionSPI;
+
+/// Protection levels for the demo mode, seeded from the fixture so the demo profiles never pick
+/// up the levels stored for real profiles of the same name.
+pub struct InMemoryProfileProtectionAdapter {
+    protection_levels: Mutex<HashMap<String, ProtectionLevel>>,
+}
+
+impl InMemoryProfileProtectionAdapter {
+    pub fn new(fixture: &DemoFixture) -> Self {
+        let protection_levels = fixture
+            .profiles
+            .iter()
+            .filter(|demo_profile| demo_profile.protection_level != ProtectionLevel::Unrestricted)
+            .map(|demo_profile| (demo_profile.name.clone(), demo_profile.protection_level))
+            .collect();
+
+        Self {
+            protection_levels: Mutex::new(protection_levels),
+        }
+    }
+}
+
+impl ProfileProtectionSPI for InMemoryProfileProtectionAdapter {
+    fn load_protection_levels(
+        &self,
+    ) -> error_stack::Result<HashMap<String, ProtectionLevel>, ProfileDataError> {
+        Ok(self
+            .protection_levels
+            .lock()
+            .expect("protection level lock should not be poisoned")
+            .clone())
+    }
+
+    fn save_protection_levels(
+        &self,
+        protection_levels: &HashMap<String, ProtectionLevel>,
+    ) -> error_stack::Result<(), ProfileDataError> {
+        *self
+            .protection_levels
+            .lock()
+            .expect("protection level lock should not be poisoned") = protection_levels.clone();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::common::demo::DemoProfile;
+
+    use super::*;
+
+    fn demo_profile(name: &str, protection_level: ProtectionLevel) -> DemoProfile {
+        DemoProfile {
+            name: name.to_string(),
+            region: None,
+            output_format: None,
+            access_key_id: None,
+            secret_access_key: None,
+            session_minutes: None,
+            protection_level,
+        }
+    }
+
+    #[test]
+    fn should_seed_protection_levels_of_protected_demo_profiles() {
+        let fixture = DemoFixture {
+            profiles: vec![
+                demo_profile("dev", ProtectionLevel::Unrestricted),
+                demo_profile("prod", ProtectionLevel::ReadOnly),
+            ],
+            ..DemoFixture::default()
+        };
+        let cut = InMemoryProfileProtectionAdapter::new(&fixture);
+
+        let actual = cut.load_protection_levels().unwrap();
+
+        assert_that!(actual).is_equal_to(HashMap::from([(
+            "prod".to_string(),
+            ProtectionLevel::ReadOnly,
+        )]));
+    }
+}
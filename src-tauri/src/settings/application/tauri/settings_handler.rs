@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::common::aws::client_settings::{ClientSettings, EffectiveClientSettings};
+use crate::settings::core::api::SettingsAPI;
+use crate::settings::core::error::SettingsError;
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn get_client_defaults(
+    api: tauri::State<'_, Arc<dyn SettingsAPI>>,
+) -> Result<ClientSettings, SettingsError> {
+    api.get_client_defaults().map_err(SettingsError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub fn set_client_defaults(
+    api: tauri::State<'_, Arc<dyn SettingsAPI>>,
+    client_defaults: ClientSettings,
+) -> Result<(), SettingsError> {
+    api.set_client_defaults(client_defaults)
+        .map_err(SettingsError::from)
+}
+
+#[tauri::command]
+#[cfg(not(tarpaulin_include))]
+pub async fn get_effective_client_settings(
+    api: tauri::State<'_, Arc<dyn SettingsAPI>>,
+    profile_name: String,
+) -> Result<EffectiveClientSettings, SettingsError> {
+    Ok(api.get_effective_client_settings(&profile_name).await)
+}
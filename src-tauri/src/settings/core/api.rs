@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::common::aws::client_settings::{ClientSettings, EffectiveClientSettings};
+use crate::settings::core::error::SettingsError;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SettingsAPI: Send + Sync {
+    /// Applies the stored settings, e.g. on startup.
+    fn apply_settings(&self) -> Result<(), SettingsError>;
+
+    fn get_client_defaults(&self) -> Result<ClientSettings, SettingsError>;
+
+    fn set_client_defaults(&self, client_defaults: ClientSettings) -> Result<(), SettingsError>;
+
+    async fn get_effective_client_settings(&self, profile_name: &str) -> EffectiveClientSettings;
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::aws::client_settings::ClientSettings;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppSettings {
+    #[serde(default = "ClientSettings::app_defaults")]
+    pub client_defaults: ClientSettings,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            client_defaults: ClientSettings::app_defaults(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn should_fall_back_to_app_defaults_when_client_defaults_are_missing() {
+        let actual: AppSettings = serde_json::from_str("{}").unwrap();
+
+        assert_that!(actual.client_defaults).is_equal_to(ClientSettings::app_defaults());
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+use error_stack::{Context, Report};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SettingsError {
+    SettingsLoadError,
+    SettingsWriteError,
+    InvalidClientSettings(String),
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::SettingsLoadError => write!(f, "failed to load settings"),
+            SettingsError::SettingsWriteError => write!(f, "failed to write settings"),
+            SettingsError::InvalidClientSettings(reason) => {
+                write!(f, "invalid client settings: {}", reason)
+            }
+        }
+    }
+}
+
+impl Context for SettingsError {}
+
+impl From<Report<SettingsError>> for SettingsError {
+    fn from(value: Report<SettingsError>) -> Self {
+        let context = value.current_context();
+        context.clone()
+    }
+}
+
+impl Serialize for SettingsError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SettingsError", 1)?;
+        let (code, message) = match self {
+            SettingsError::SettingsLoadError => ("SettingsLoadError", self.to_string()),
+            SettingsError::SettingsWriteError => ("SettingsWriteError", self.to_string()),
+            SettingsError::InvalidClientSettings(_) => ("InvalidClientSettings", self.to_string()),
+        };
+        state.serialize_field("error", &json!({ "code": code, "message": message }))?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn should_return_settings_write_error_when_calling_from_on_report_with_context_settings_write_error(
+    ) {
+        let error = SettingsError::SettingsWriteError;
+        let report = Report::new(error.clone());
+
+        let result: SettingsError = report.into();
+
+        assert_eq!(error, result);
+    }
+
+    #[test]
+    fn should_serialize_invalid_client_settings_to_json() {
+        let error =
+            SettingsError::InvalidClientSettings("max attempts must be at least 1".to_string());
+        let expected = json!({
+            "error": {
+                "code": "InvalidClientSettings",
+                "message": "invalid client settings: max attempts must be at least 1"
+            }
+        });
+
+        let serialized = serde_json::to_string(&error).unwrap();
+
+        assert_eq!(serialized, expected.to_string());
+    }
+}
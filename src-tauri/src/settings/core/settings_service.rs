@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use error_stack::Report;
+
+use crate::common::aws::client_settings::{ClientSettings, EffectiveClientSettings};
+use crate::settings::core::api::SettingsAPI;
+use crate::settings::core::error::SettingsError;
+use crate::settings::core::spi::{ClientSettingsSPI, SettingsSPI};
+
+pub struct SettingsService {
+    settings_spi: Box<dyn SettingsSPI>,
+    client_settings_spi: Box<dyn ClientSettingsSPI>,
+}
+
+impl SettingsService {
+    pub fn new(
+        settings_spi: Box<dyn SettingsSPI>,
+        client_settings_spi: Box<dyn ClientSettingsSPI>,
+    ) -> Self {
+        Self {
+            settings_spi,
+            client_settings_spi,
+        }
+    }
+}
+
+#[async_trait]
+impl SettingsAPI for SettingsService {
+    fn apply_settings(&self) -> error_stack::Result<(), SettingsError> {
+        let settings = self.settings_spi.load_settings()?;
+
+        self.client_settings_spi
+            .apply_client_defaults(&settings.client_defaults);
+
+        Ok(())
+    }
+
+    fn get_client_defaults(&self) -> error_stack::Result<ClientSettings, SettingsError> {
+        self.settings_spi
+            .load_settings()
+            .map(|settings| settings.client_defaults)
+    }
+
+    fn set_client_defaults(
+        &self,
+        client_defaults: ClientSettings,
+    ) -> error_stack::Result<(), SettingsError> {
+        client_defaults
+            .validate()
+            .map_err(|reason| Report::new(SettingsError::InvalidClientSettings(reason)))?;
+
+        let mut settings = self.settings_spi.load_settings()?;
+        settings.client_defaults = client_defaults;
+        self.settings_spi.save_settings(&settings)?;
+
+        self.client_settings_spi
+            .apply_client_defaults(&settings.client_defaults);
+
+        Ok(())
+    }
+
+    async fn get_effective_client_settings(&self, profile_name: &str) -> EffectiveClientSettings {
+        self.client_settings_spi
+            .load_effective_client_settings(profile_name)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::common::aws::client_settings::RetryMode;
+    use crate::settings::core::domain::AppSettings;
+    use crate::settings::core::spi::{MockClientSettingsSPI, MockSettingsSPI};
+
+    use super::*;
+
+    #[test]
+    fn should_apply_stored_client_defaults() {
+        let client_defaults = ClientSettings {
+            max_attempts: Some(3),
+            ..ClientSettings::default()
+        };
+        let stored_settings = AppSettings {
+            client_defaults: client_defaults.clone(),
+        };
+        let mut settings_spi_mock = MockSettingsSPI::new();
+        settings_spi_mock
+            .expect_load_settings()
+            .returning(move || Ok(stored_settings.clone()));
+        let mut client_settings_spi_mock = MockClientSettingsSPI::new();
+        client_settings_spi_mock
+            .expect_apply_client_defaults()
+            .with(eq(client_defaults))
+            .times(1)
+            .return_const(());
+        let cut = SettingsService::new(
+            Box::new(settings_spi_mock),
+            Box::new(client_settings_spi_mock),
+        );
+
+        let actual = cut.apply_settings();
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[test]
+    fn should_save_and_apply_valid_client_defaults() {
+        let client_defaults = ClientSettings {
+            retry_mode: Some(RetryMode::Standard),
+            max_attempts: Some(5),
+            ..ClientSettings::default()
+        };
+        let mut settings_spi_mock = MockSettingsSPI::new();
+        settings_spi_mock
+            .expect_load_settings()
+            .returning(|| Ok(AppSettings::default()));
+        let expected_settings = AppSettings {
+            client_defaults: client_defaults.clone(),
+        };
+        settings_spi_mock
+            .expect_save_settings()
+            .with(eq(expected_settings))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut client_settings_spi_mock = MockClientSettingsSPI::new();
+        client_settings_spi_mock
+            .expect_apply_client_defaults()
+            .with(eq(client_defaults.clone()))
+            .times(1)
+            .return_const(());
+        let cut = SettingsService::new(
+            Box::new(settings_spi_mock),
+            Box::new(client_settings_spi_mock),
+        );
+
+        let actual = cut.set_client_defaults(client_defaults);
+
+        assert_that!(actual).is_ok();
+    }
+
+    #[test]
+    fn should_reject_invalid_client_defaults_without_saving_them() {
+        let mut settings_spi_mock = MockSettingsSPI::new();
+        settings_spi_mock.expect_save_settings().never();
+        let mut client_settings_spi_mock = MockClientSettingsSPI::new();
+        client_settings_spi_mock
+            .expect_apply_client_defaults()
+            .never();
+        let cut = SettingsService::new(
+            Box::new(settings_spi_mock),
+            Box::new(client_settings_spi_mock),
+        );
+
+        let actual = cut.set_client_defaults(ClientSettings {
+            https_proxy: Some("socks5://proxy.corp:1080".to_string()),
+            ..ClientSettings::default()
+        });
+
+        assert_that!(actual).is_err();
+        let error = SettingsError::from(actual.unwrap_err());
+        assert_that!(error).is_equal_to(SettingsError::InvalidClientSettings(
+            "unsupported proxy scheme 'socks5'".to_string(),
+        ));
+    }
+}
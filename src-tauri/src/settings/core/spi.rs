@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use error_stack::Result;
+#[cfg(test)]
+use mockall::automock;
+
+use crate::common::aws::client_settings::{ClientSettings, EffectiveClientSettings};
+use crate::settings::core::domain::AppSettings;
+use crate::settings::core::error::SettingsError;
+
+#[cfg_attr(test, automock)]
+pub trait SettingsSPI: Send + Sync {
+    fn load_settings(&self) -> Result<AppSettings, SettingsError>;
+
+    fn save_settings(&self, settings: &AppSettings) -> Result<(), SettingsError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ClientSettingsSPI: Send + Sync {
+    /// Makes the defaults take effect for all clients created from now on.
+    fn apply_client_defaults(&self, client_defaults: &ClientSettings);
+
+    async fn load_effective_client_settings(&self, profile_name: &str) -> EffectiveClientSettings;
+}
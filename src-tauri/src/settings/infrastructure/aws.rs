@@ -0,0 +1 @@
+pub mod sdk_config;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::common::aws::client_factory;
+use crate::common::aws::client_settings::{ClientSettings, EffectiveClientSettings};
+use crate::settings::core::spi::ClientSettingsSPI;
+
+pub struct ClientSettingsAdapter;
+
+#[async_trait]
+impl ClientSettingsSPI for ClientSettingsAdapter {
+    fn apply_client_defaults(&self, client_defaults: &ClientSettings) {
+        client_factory().set_client_defaults(client_defaults.clone());
+    }
+
+    async fn load_effective_client_settings(&self, profile_name: &str) -> EffectiveClientSettings {
+        client_factory()
+            .effective_client_settings(profile_name)
+            .await
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+
+use crate::common::app_data::JsonFileStore;
+use crate::settings::core::domain::AppSettings;
+use crate::settings::core::error::SettingsError;
+use crate::settings::core::spi::SettingsSPI;
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+pub struct JsonSettingsAdapter {
+    store: JsonFileStore,
+}
+
+impl JsonSettingsAdapter {
+    pub fn new(settings_file_location: PathBuf) -> Self {
+        Self {
+            store: JsonFileStore::new(settings_file_location),
+        }
+    }
+}
+
+impl Default for JsonSettingsAdapter {
+    fn default() -> Self {
+        Self {
+            store: JsonFileStore::in_app_data_dir(SETTINGS_FILE_NAME),
+        }
+    }
+}
+
+impl SettingsSPI for JsonSettingsAdapter {
+    fn load_settings(&self) -> error_stack::Result<AppSettings, SettingsError> {
+        self.store
+            .load()
+            .change_context(SettingsError::SettingsLoadError)
+    }
+
+    fn save_settings(&self, settings: &AppSettings) -> error_stack::Result<(), SettingsError> {
+        self.store
+            .save(settings)
+            .change_context(SettingsError::SettingsWriteError)
+    }
+}
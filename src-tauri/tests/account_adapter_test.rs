@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::{env, fs};
+
+    use directories::UserDirs;
+    use ini::Ini;
+    use serial_test::serial;
+    use spectral::prelude::*;
+    use tempfile::{tempdir, TempDir};
+    use test_context::{test_context, AsyncTestContext};
+    use testcontainers::core::{ExecCommand, WaitFor};
+    use testcontainers::runners::AsyncRunner;
+    use testcontainers::RunnableImage;
+    use testcontainers_modules::localstack::LocalStack;
+
+    use backend::accounts::core::spi::AccountDataSPI;
+    use backend::accounts::infrastructure::aws::account::account_adapter::AccountAdapter;
+
+    struct TestContext {
+        _test_dir: TempDir,
+        profile: String,
+        config_file_path: PathBuf,
+        original_config_file_location: String,
+        original_credentials_file_location: String,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncTestContext for TestContext {
+        async fn setup() -> Self {
+            let user_dir = UserDirs::new().expect("user dir should exist");
+
+            let default_aws_config_file_location = user_dir.home_dir().join(".aws").join("config");
+            let original_config_file_location = env::var("AWS_CONFIG_FILE").ok().unwrap_or(
+                default_aws_config_file_location
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+
+            let default_aws_credentials_file_location =
+                user_dir.home_dir().join(".aws").join("credentials");
+            let original_credentials_file_location =
+                env::var("AWS_SHARED_CREDENTIALS_FILE").ok().unwrap_or(
+                    default_aws_credentials_file_location
+                        .to_string_lossy()
+                        .into_owned(),
+                );
+
+            let test_dir = tempdir().unwrap();
+            let test_aws_dir_path = test_dir.path().join(".aws");
+            fs::create_dir_all(&test_aws_dir_path).unwrap();
+
+            let profile_name = "dev";
+
+            let mut test_config = Ini::new();
+            test_config
+                .with_section(Some(format!("profile {}", profile_name)))
+                .set("region", "eu-west-1")
+                .set("output", "json");
+            let test_config_file_path = test_aws_dir_path.join("config");
+            test_config.write_to_file(&test_config_file_path).unwrap();
+            env::set_var("AWS_CONFIG_FILE", &test_config_file_path);
+
+            let mut test_credentials = Ini::new();
+            test_credentials
+                .with_section(Some(profile_name))
+                .set("aws_access_key_id", "devAccessKeyID")
+                .set("aws_secret_access_key", "devSecretAccessKey");
+            let test_credentials_file_path = test_aws_dir_path.join("credentials");
+            test_credentials
+                .write_to_file(&test_credentials_file_path)
+                .unwrap();
+            env::set_var("AWS_SHARED_CREDENTIALS_FILE", test_credentials_file_path);
+
+            TestContext {
+                _test_dir: test_dir,
+                profile: profile_name.to_string(),
+                config_file_path: test_config_file_path,
+                original_config_file_location,
+                original_credentials_file_location,
+            }
+        }
+
+        async fn teardown(self) {
+            env::set_var("AWS_CONFIG_FILE", self.original_config_file_location);
+            env::set_var(
+                "AWS_SHARED_CREDENTIALS_FILE",
+                self.original_credentials_file_location,
+            );
+        }
+    }
+
+    impl TestContext {
+        fn configure_endpoint_url(&self, endpoint_url: &str) {
+            let mut test_config = Ini::load_from_file(&self.config_file_path).unwrap();
+            test_config
+                .with_section(Some(format!("profile {}", self.profile)))
+                .set("endpoint_url", endpoint_url);
+            test_config.write_to_file(&self.config_file_path).unwrap();
+        }
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_account_id_and_alias(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "iam,sts"));
+        let localstack_container = localstack.start().await;
+        localstack_container
+            .exec(
+                ExecCommand::new(vec![
+                    "awslocal",
+                    "iam",
+                    "create-account-alias",
+                    "--account-alias",
+                    "payments-prod",
+                ])
+                .with_cmd_ready_condition(WaitFor::Healthcheck),
+            )
+            .await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn AccountDataSPI> = Box::new(AccountAdapter);
+
+        let account_id = cut.load_account_id(&ctx.profile).await;
+        let alias = cut.load_account_alias(&ctx.profile).await;
+
+        assert_that!(account_id).is_ok();
+        assert_that!(alias)
+            .is_ok()
+            .is_equal_to(Some("payments-prod".to_string()));
+    }
+}
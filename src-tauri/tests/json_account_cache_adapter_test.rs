@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use spectral::prelude::*;
+    use tempfile::tempdir;
+
+    use backend::accounts::core::domain::{Account, AccountCache};
+    use backend::accounts::core::spi::AccountCacheSPI;
+    use backend::accounts::infrastructure::json::json_account_cache_adapter::JsonAccountCacheAdapter;
+
+    #[test]
+    fn should_load_empty_cache_when_cache_file_does_not_exist() {
+        let test_dir = tempdir().unwrap();
+        let cut: Box<dyn AccountCacheSPI> = Box::new(JsonAccountCacheAdapter::new(
+            test_dir.path().join("accounts.json"),
+        ));
+
+        let actual = cut.load_account_cache();
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to(AccountCache::new());
+    }
+
+    #[test]
+    fn should_save_and_load_account_cache() {
+        let test_dir = tempdir().unwrap();
+        let cut: Box<dyn AccountCacheSPI> = Box::new(JsonAccountCacheAdapter::new(
+            test_dir.path().join("nested").join("accounts.json"),
+        ));
+        let account = Account::new(
+            "123456789012".to_string(),
+            Some("payments-prod".to_string()),
+            Some("Payments Production".to_string()),
+            Utc::now(),
+        );
+        let mut account_cache = AccountCache::new();
+        account_cache.add_account(account.clone());
+        account_cache.assign_profile("prod", &account.account_id);
+
+        let result = cut.save_account_cache(&account_cache);
+
+        assert_that!(result).is_ok();
+        let actual = cut.load_account_cache();
+        assert_that!(actual).is_ok().is_equal_to(account_cache);
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use spectral::prelude::*;
+    use tempfile::tempdir;
+
+    use backend::common::aws::client_settings::{ClientSettings, RetryMode};
+    use backend::settings::core::domain::AppSettings;
+    use backend::settings::core::error::SettingsError;
+    use backend::settings::core::spi::SettingsSPI;
+    use backend::settings::infrastructure::json::json_settings_adapter::JsonSettingsAdapter;
+
+    #[test]
+    fn should_load_default_settings_when_settings_file_does_not_exist() {
+        let test_dir = tempdir().unwrap();
+        let cut: Box<dyn SettingsSPI> = Box::new(JsonSettingsAdapter::new(
+            test_dir.path().join("settings.json"),
+        ));
+
+        let actual = cut.load_settings();
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to(AppSettings::default());
+    }
+
+    #[test]
+    fn should_save_and_load_settings() {
+        let test_dir = tempdir().unwrap();
+        let cut: Box<dyn SettingsSPI> = Box::new(JsonSettingsAdapter::new(
+            test_dir.path().join("nested").join("settings.json"),
+        ));
+        let settings = AppSettings {
+            client_defaults: ClientSettings {
+                retry_mode: Some(RetryMode::Standard),
+                max_attempts: Some(3),
+                https_proxy: Some("http://proxy.corp:3128".to_string()),
+                ..ClientSettings::default()
+            },
+        };
+
+        let result = cut.save_settings(&settings);
+
+        assert_that!(result).is_ok();
+        let actual = cut.load_settings();
+        assert_that!(actual).is_ok().is_equal_to(settings);
+    }
+
+    #[test]
+    fn should_return_settings_load_error_when_settings_file_is_corrupt() {
+        let test_dir = tempdir().unwrap();
+        let settings_file_location = test_dir.path().join("settings.json");
+        fs::write(&settings_file_location, "{ not json").unwrap();
+        let cut: Box<dyn SettingsSPI> = Box::new(JsonSettingsAdapter::new(settings_file_location));
+
+        let actual = cut.load_settings();
+
+        assert_that!(actual).is_err();
+        assert_that!(SettingsError::from(actual.unwrap_err()))
+            .is_equal_to(SettingsError::SettingsLoadError);
+    }
+}
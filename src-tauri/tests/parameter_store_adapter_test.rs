@@ -1,8 +1,16 @@
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+    use std::path::PathBuf;
     use std::{env, fs};
 
-    use backend::parameters::core::domain::ParameterValue;
+    use backend::common::aws::{shared_config_loader, ssm_client};
+    use backend::common::secure_string::SecureString;
+    use backend::parameters::core::domain::{
+        DeletionStatus, Parameter, ParameterDeletion, ParameterFilters, ParameterMetadata,
+        ParameterTag, ParameterTier, ParameterType, ParameterValue, WriteMode,
+    };
+    use backend::parameters::core::error::ParameterDataError;
     use backend::parameters::core::spi::ParameterDataSPI;
     use backend::parameters::infrastructure::aws::ssm::parameter_store_adapter::ParameterStoreAdapter;
     use directories::UserDirs;
@@ -16,11 +24,11 @@ mod tests {
     use testcontainers::runners::AsyncRunner;
     use testcontainers::RunnableImage;
     use testcontainers_modules::localstack::LocalStack;
-    use backend::common::aws::{localstack_endpoint, shared_config_loader, ssm_client};
 
     struct TestContext {
         _test_dir: TempDir,
         profile: String,
+        config_file_path: PathBuf,
         original_config_file_location: String,
         original_credentials_file_location: String,
     }
@@ -59,7 +67,7 @@ mod tests {
                 .set("output", "json");
             let test_config_file_path = test_aws_dir_path.join("config");
             test_config.write_to_file(&test_config_file_path).unwrap();
-            env::set_var("AWS_CONFIG_FILE", test_config_file_path);
+            env::set_var("AWS_CONFIG_FILE", &test_config_file_path);
 
             let mut test_credentials = Ini::new();
             test_credentials
@@ -75,6 +83,7 @@ mod tests {
             TestContext {
                 _test_dir: test_dir,
                 profile: profile_name.to_string(),
+                config_file_path: test_config_file_path,
                 original_config_file_location,
                 original_credentials_file_location,
             }
@@ -89,6 +98,38 @@ mod tests {
         }
     }
 
+    impl TestContext {
+        /// Routes the test profile to LocalStack. The profile keeps its own region, so resources
+        /// are created there with `awslocal --region`.
+        fn configure_endpoint_url(&self, endpoint_url: &str) {
+            let mut test_config = Ini::load_from_file(&self.config_file_path).unwrap();
+            test_config
+                .with_section(Some(format!("profile {}", self.profile)))
+                .set("endpoint_url", endpoint_url);
+            test_config.write_to_file(&self.config_file_path).unwrap();
+        }
+
+        fn configure_service_endpoint_url(&self, service: &str, endpoint_url: &str) {
+            let mut test_config = Ini::load_from_file(&self.config_file_path).unwrap();
+            test_config
+                .with_section(Some(format!("profile {}", self.profile)))
+                .set("services", "local");
+            test_config.write_to_file(&self.config_file_path).unwrap();
+
+            // Nested service settings are not supported by the ini writer.
+            let mut config_file = fs::OpenOptions::new()
+                .append(true)
+                .open(&self.config_file_path)
+                .unwrap();
+            writeln!(
+                config_file,
+                "[services local]\n{} =\n  endpoint_url = {}",
+                service, endpoint_url
+            )
+            .unwrap();
+        }
+    }
+
     #[test_context(TestContext)]
     #[tokio::test]
     #[serial]
@@ -108,6 +149,8 @@ mod tests {
                     "val1",
                     "--type",
                     "String",
+                    "--region",
+                    "eu-west-1",
                 ])
                 .with_cmd_ready_condition(WaitFor::Healthcheck),
             )
@@ -124,16 +167,18 @@ mod tests {
                     "val2",
                     "--type",
                     "String",
+                    "--region",
+                    "eu-west-1",
                 ])
                 .with_cmd_ready_condition(WaitFor::Healthcheck),
             )
             .await;
         let host_port = localstack_container.get_host_port_ipv4(4566).await;
         let endpoint_url = format!("http://127.0.0.1:{host_port}");
-        env::set_var("LOCALSTACK_ENDPOINT", endpoint_url);
+        ctx.configure_endpoint_url(&endpoint_url);
         let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
 
-        let actual = cut.load_available_parameter_names(&ctx.profile).await;
+        let actual = cut.load_available_parameter_names(&ctx.profile, None).await;
 
         assert_that!(actual).is_ok();
         let actual_names = actual.unwrap();
@@ -141,6 +186,43 @@ mod tests {
         assert_that!(actual_names).contains("key2".to_string());
     }
 
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_available_parameter_names_from_service_specific_endpoint(
+        ctx: &mut TestContext,
+    ) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        localstack_container
+            .exec(
+                ExecCommand::new(vec![
+                    "awslocal",
+                    "ssm",
+                    "put-parameter",
+                    "--name",
+                    "key1",
+                    "--value",
+                    "val1",
+                    "--type",
+                    "String",
+                    "--region",
+                    "eu-west-1",
+                ])
+                .with_cmd_ready_condition(WaitFor::Healthcheck),
+            )
+            .await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_service_endpoint_url("ssm", &endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+
+        let actual = cut.load_available_parameter_names(&ctx.profile, None).await;
+
+        assert_that!(actual).is_ok().contains("key1".to_string());
+    }
+
     #[test_context(TestContext)]
     #[tokio::test]
     #[serial]
@@ -160,6 +242,8 @@ mod tests {
                     "val1",
                     "--type",
                     "String",
+                    "--region",
+                    "eu-west-1",
                 ])
                 .with_cmd_ready_condition(WaitFor::Healthcheck),
             )
@@ -176,17 +260,23 @@ mod tests {
                     "val2",
                     "--type",
                     "String",
+                    "--region",
+                    "eu-west-1",
                 ])
                 .with_cmd_ready_condition(WaitFor::Healthcheck),
             )
             .await;
         let host_port = localstack_container.get_host_port_ipv4(4566).await;
         let endpoint_url = format!("http://127.0.0.1:{host_port}");
-        env::set_var("LOCALSTACK_ENDPOINT", endpoint_url);
+        ctx.configure_endpoint_url(&endpoint_url);
         let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
 
         let actual = cut
-            .load_parameters(&ctx.profile, vec!["key1".to_string(), "key2".to_string()])
+            .load_parameters(
+                &ctx.profile,
+                None,
+                vec!["key1".to_string(), "key2".to_string()],
+            )
             .await;
 
         assert_that!(actual).is_ok();
@@ -199,6 +289,65 @@ mod tests {
         assert_that!(contains_value_2).is_equal_to(true);
     }
 
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_keep_profile_region_with_custom_endpoint(ctx: &mut TestContext) {
+        ctx.configure_endpoint_url("http://127.0.0.1:4566");
+
+        let actual = shared_config_loader(&ctx.profile).await.load().await;
+
+        assert_that!(actual.region().map(|region| region.to_string()))
+            .is_equal_to(Some("eu-west-1".to_string()));
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_parameters_from_requested_region(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        localstack_container
+            .exec(
+                ExecCommand::new(vec![
+                    "awslocal",
+                    "ssm",
+                    "put-parameter",
+                    "--name",
+                    "key1",
+                    "--value",
+                    "val1",
+                    "--type",
+                    "String",
+                    "--region",
+                    "eu-central-1",
+                ])
+                .with_cmd_ready_condition(WaitFor::Healthcheck),
+            )
+            .await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+
+        let actual_in_region = cut
+            .load_parameters(
+                &ctx.profile,
+                Some("eu-central-1".to_string()),
+                vec!["key1".to_string()],
+            )
+            .await;
+        let actual_in_default_region = cut
+            .load_parameters(&ctx.profile, None, vec!["key1".to_string()])
+            .await;
+
+        assert_that!(actual_in_region).is_ok();
+        assert_that(actual_in_region.unwrap().values()).has_length(1);
+        assert_that!(actual_in_default_region).is_ok();
+        assert_that(actual_in_default_region.unwrap().values()).has_length(0);
+    }
+
     #[test_context(TestContext)]
     #[tokio::test]
     #[serial]
@@ -208,30 +357,31 @@ mod tests {
         let localstack_container = localstack.start().await;
         let host_port = localstack_container.get_host_port_ipv4(4566).await;
         let endpoint_url = format!("http://127.0.0.1:{host_port}");
-        env::set_var("LOCALSTACK_ENDPOINT", &endpoint_url);
+        ctx.configure_endpoint_url(&endpoint_url);
         let parameter_value = "value1";
         let parameter_name = "param1";
         let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
-        let mut shared_config_loader = shared_config_loader(&ctx.profile).await;
-        shared_config_loader = shared_config_loader
-            .region("us-east-1")
-            .endpoint_url(endpoint_url);
-        let shared_config = shared_config_loader.load().await;
+        let shared_config = shared_config_loader(&ctx.profile).await.load().await;
         let client = ssm_client(&shared_config);
 
         let result = cut
             .upsert_parameter(
                 &ctx.profile,
+                None,
                 (
                     parameter_name.to_string(),
                     ParameterValue::String(parameter_value.to_string()),
                 )
                     .into(),
+                WriteMode::Overwrite,
+                vec![],
             )
             .await;
-        let actual = client.get_parameter()
+        let actual = client
+            .get_parameter()
             .name(parameter_name.clone())
-            .send().await;
+            .send()
+            .await;
 
         assert_that!(result).is_ok();
         assert_that!(actual).is_ok();
@@ -241,4 +391,574 @@ mod tests {
         assert_that!(actual_value).is_some();
         assert_that!(actual_value).contains("value1".to_string());
     }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_parameters_by_path(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for name in ["/app/name", "/app/db/url"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    name.to_string(),
+                    ParameterValue::String("value".to_string()),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+
+        let actual_direct = cut
+            .load_parameters_by_path(&ctx.profile, None, "/app".to_string(), false)
+            .await;
+        let actual_recursive = cut
+            .load_parameters_by_path(&ctx.profile, None, "/app".to_string(), true)
+            .await;
+        let actual_names = cut
+            .load_parameter_names_by_path(&ctx.profile, None, "/app".to_string())
+            .await;
+
+        assert_that!(actual_direct).is_ok().has_length(1);
+        assert_that!(actual_recursive).is_ok().has_length(2);
+        assert_that!(actual_names).is_ok().has_length(2);
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_parameter_infos_by_path_without_decrypting(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        cut.upsert_parameter(
+            &ctx.profile,
+            None,
+            (
+                "/app/db/password".to_string(),
+                ParameterValue::SecureString(SecureString::from("secret")),
+            )
+                .into(),
+            WriteMode::Overwrite,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        let actual = cut
+            .load_parameter_infos_by_path(&ctx.profile, None, "/app".to_string(), true)
+            .await;
+
+        assert_that!(actual).is_ok().has_length(1);
+        let info = &actual.unwrap()[0];
+        assert_that!(info.name).is_equal_to("/app/db/password".to_string());
+        assert_that!(info.parameter_type).is_equal_to(ParameterType::SecureString);
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_current_version_of_secure_parameter(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for value in ["old", "new"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    "/app/db/password".to_string(),
+                    ParameterValue::SecureString(SecureString::from(value)),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+
+        let actual = cut
+            .load_parameter_version(&ctx.profile, None, "/app/db/password".to_string())
+            .await;
+        let missing = cut
+            .load_parameter_version(&ctx.profile, None, "/app/db/missing".to_string())
+            .await;
+
+        assert_that!(actual).is_ok().is_equal_to(Some(2));
+        assert_that!(missing).is_ok().is_none();
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_filtered_parameter_names_page_by_page(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for name in ["/app/name", "/app/url", "/infra/vpc"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    name.to_string(),
+                    ParameterValue::String("value".to_string()),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+        let filters = ParameterFilters {
+            name_prefix: Some("/app".to_string()),
+            ..ParameterFilters::default()
+        };
+
+        let first_page = cut
+            .load_parameter_names_page(&ctx.profile, None, filters.clone(), None, Some(1))
+            .await;
+
+        assert_that!(first_page).is_ok();
+        let first_page = first_page.unwrap();
+        assert_that!(first_page.names).has_length(1);
+        assert_that!(first_page.next_cursor).is_some();
+        let second_page = cut
+            .load_parameter_names_page(&ctx.profile, None, filters, first_page.next_cursor, Some(1))
+            .await;
+        assert_that!(second_page).is_ok();
+        let second_page = second_page.unwrap();
+        assert_that!(second_page.names).has_length(1);
+        assert_that!(second_page.names).is_not_equal_to(first_page.names);
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_parameter_metadata_without_value(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        localstack_container
+            .exec(
+                ExecCommand::new(vec![
+                    "awslocal",
+                    "ssm",
+                    "put-parameter",
+                    "--name",
+                    "/app/password",
+                    "--value",
+                    "secret",
+                    "--type",
+                    "SecureString",
+                    "--description",
+                    "Database password",
+                    "--region",
+                    "eu-west-1",
+                ])
+                .with_cmd_ready_condition(WaitFor::Healthcheck),
+            )
+            .await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+
+        let actual = cut
+            .load_parameter_metadata(&ctx.profile, None, vec!["/app/password".to_string()])
+            .await;
+
+        assert_that!(actual).is_ok().has_length(1);
+        let parameter_info = &actual.unwrap()[0];
+        assert_that!(parameter_info.parameter_type).is_equal_to(ParameterType::SecureString);
+        assert_that!(parameter_info.metadata.description)
+            .is_equal_to(Some("Database password".to_string()));
+        assert_that!(parameter_info.metadata.tier).is_equal_to(Some(ParameterTier::Standard));
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_put_parameter_with_metadata_and_refuse_to_create_it_twice(
+        ctx: &mut TestContext,
+    ) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        let parameter = Parameter::from((
+            "/app/name".to_string(),
+            ParameterValue::String("custodian".to_string()),
+        ))
+        .with_metadata(ParameterMetadata {
+            description: Some("Application name".to_string()),
+            tier: Some(ParameterTier::Advanced),
+            ..ParameterMetadata::default()
+        });
+
+        let created = cut
+            .upsert_parameter(
+                &ctx.profile,
+                None,
+                parameter.clone(),
+                WriteMode::CreateOnly,
+                vec![],
+            )
+            .await;
+        let created_again = cut
+            .upsert_parameter(&ctx.profile, None, parameter, WriteMode::CreateOnly, vec![])
+            .await;
+
+        assert_that!(created).is_ok();
+        assert_that!(created_again).is_err();
+        assert_that!(ParameterDataError::from(created_again.unwrap_err())).is_equal_to(
+            ParameterDataError::ParameterAlreadyExists("/app/name".to_string()),
+        );
+        let parameter_infos = cut
+            .load_parameter_metadata(&ctx.profile, None, vec!["/app/name".to_string()])
+            .await
+            .unwrap();
+        assert_that!(parameter_infos[0].metadata.tier).is_equal_to(Some(ParameterTier::Advanced));
+        assert_that!(parameter_infos[0].metadata.description)
+            .is_equal_to(Some("Application name".to_string()));
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_delete_parameter(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        localstack_container
+            .exec(
+                ExecCommand::new(vec![
+                    "awslocal",
+                    "ssm",
+                    "put-parameter",
+                    "--name",
+                    "key1",
+                    "--value",
+                    "val1",
+                    "--type",
+                    "String",
+                    "--region",
+                    "eu-west-1",
+                ])
+                .with_cmd_ready_condition(WaitFor::Healthcheck),
+            )
+            .await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+
+        let deleted = cut
+            .delete_parameter(&ctx.profile, None, "key1".to_string())
+            .await;
+        let deleted_again = cut
+            .delete_parameter(&ctx.profile, None, "key1".to_string())
+            .await;
+
+        assert_that!(deleted)
+            .is_ok()
+            .is_equal_to(ParameterDeletion::new(
+                "key1".to_string(),
+                DeletionStatus::Deleted,
+            ));
+        assert_that!(deleted_again)
+            .is_ok()
+            .is_equal_to(ParameterDeletion::new(
+                "key1".to_string(),
+                DeletionStatus::NotFound,
+            ));
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_delete_parameters_in_chunks_and_report_each_name(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        let existing_names: Vec<String> = (1..=11).map(|index| format!("key{}", index)).collect();
+        for name in &existing_names {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (name.clone(), ParameterValue::String("value".to_string())).into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+        let mut names = existing_names.clone();
+        names.push("missing".to_string());
+
+        let actual = cut.delete_parameters(&ctx.profile, None, names).await;
+
+        assert_that!(actual).is_ok().has_length(12);
+        let deletions = actual.unwrap();
+        let deleted_count = deletions
+            .iter()
+            .filter(|deletion| deletion.status == DeletionStatus::Deleted)
+            .count();
+        assert_that!(deleted_count).is_equal_to(11);
+        assert_that!(deletions[11]).is_equal_to(ParameterDeletion::new(
+            "missing".to_string(),
+            DeletionStatus::NotFound,
+        ));
+        let remaining = cut.load_available_parameter_names(&ctx.profile, None).await;
+        assert_that!(remaining).is_ok().is_empty();
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_load_parameter_history(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for value in ["old", "new"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    "/app/password".to_string(),
+                    ParameterValue::SecureString(SecureString::from(value)),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+
+        let actual = cut
+            .load_parameter_history(&ctx.profile, None, "/app/password".to_string())
+            .await;
+        let actual_missing = cut
+            .load_parameter_history(&ctx.profile, None, "/app/missing".to_string())
+            .await;
+
+        assert_that!(actual).is_ok();
+        let history = actual.unwrap();
+        assert_that!(history.versions).has_length(2);
+        assert_that!(history.versions[0].version).is_equal_to(1);
+        assert_that!(history.versions[0].value)
+            .is_equal_to(ParameterValue::SecureString(SecureString::from("old")));
+        assert_that!(actual_missing).is_err();
+        assert_that!(ParameterDataError::from(actual_missing.unwrap_err())).is_equal_to(
+            ParameterDataError::ParameterNotFound("/app/missing".to_string()),
+        );
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_label_version_and_load_it_by_label(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for value in ["old", "new"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    "/app/db-url".to_string(),
+                    ParameterValue::String(value.to_string()),
+                )
+                    .into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+
+        let actual_update = cut
+            .label_parameter_version(
+                &ctx.profile,
+                None,
+                "/app/db-url".to_string(),
+                1,
+                vec!["live".to_string()],
+            )
+            .await;
+        let actual = cut
+            .load_parameters(
+                &ctx.profile,
+                None,
+                vec!["/app/db-url:live".to_string(), "/app/db-url:2".to_string()],
+            )
+            .await;
+
+        assert_that!(actual_update).is_ok();
+        assert_that!(actual_update.unwrap().labels).is_equal_to(vec!["live".to_string()]);
+        let parameter_set = actual.unwrap();
+        let values: Vec<(Option<String>, ParameterValue)> = parameter_set
+            .values()
+            .iter()
+            .map(|parameter| (parameter.selector.clone(), parameter.value.clone()))
+            .collect();
+        assert_that!(values).contains((
+            Some("live".to_string()),
+            ParameterValue::String("old".to_string()),
+        ));
+        assert_that!(values).contains((
+            Some("2".to_string()),
+            ParameterValue::String("new".to_string()),
+        ));
+        let history = cut
+            .load_parameter_history(&ctx.profile, None, "/app/db-url".to_string())
+            .await
+            .unwrap();
+        assert_that!(history.versions[0].labels).is_equal_to(vec!["live".to_string()]);
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_tag_parameter_and_filter_by_tag(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        for name in ["/app/payments", "/app/search"] {
+            cut.upsert_parameter(
+                &ctx.profile,
+                None,
+                (name.to_string(), ParameterValue::String("x".to_string())).into(),
+                WriteMode::Overwrite,
+                vec![],
+            )
+            .await
+            .unwrap();
+        }
+        cut.add_parameter_tags(
+            &ctx.profile,
+            None,
+            "/app/payments".to_string(),
+            vec![
+                ParameterTag::new("team".to_string(), "payments".to_string()),
+                ParameterTag::new("cost-center".to_string(), "42".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let actual_removal = cut
+            .remove_parameter_tags(
+                &ctx.profile,
+                None,
+                "/app/payments".to_string(),
+                vec!["cost-center".to_string()],
+            )
+            .await;
+        let actual_tags = cut
+            .load_parameter_tags(&ctx.profile, None, "/app/payments".to_string())
+            .await;
+        let actual_page = cut
+            .load_parameter_names_page(
+                &ctx.profile,
+                None,
+                ParameterFilters {
+                    tag_key: Some("team".to_string()),
+                    tag_value: Some("payments".to_string()),
+                    ..ParameterFilters::default()
+                },
+                None,
+                None,
+            )
+            .await;
+
+        assert_that!(actual_removal).is_ok();
+        assert_that!(actual_tags)
+            .is_ok()
+            .is_equal_to(vec![ParameterTag::new(
+                "team".to_string(),
+                "payments".to_string(),
+            )]);
+        assert_that!(actual_page.unwrap().names).is_equal_to(vec!["/app/payments".to_string()]);
+    }
+
+    #[test_context(TestContext)]
+    #[tokio::test]
+    #[serial]
+    async fn should_create_parameter_together_with_its_tags(ctx: &mut TestContext) {
+        let localstack: RunnableImage<LocalStack> = LocalStack.into();
+        let localstack = localstack.with_env_var(("SERVICES", "ssm"));
+        let localstack_container = localstack.start().await;
+        let host_port = localstack_container.get_host_port_ipv4(4566).await;
+        let endpoint_url = format!("http://127.0.0.1:{host_port}");
+        ctx.configure_endpoint_url(&endpoint_url);
+        let cut: Box<dyn ParameterDataSPI> = Box::new(ParameterStoreAdapter);
+        let tags = vec![ParameterTag::new(
+            "team".to_string(),
+            "payments".to_string(),
+        )];
+
+        let created = cut
+            .upsert_parameter(
+                &ctx.profile,
+                None,
+                (
+                    "/app/payments".to_string(),
+                    ParameterValue::String("x".to_string()),
+                )
+                    .into(),
+                WriteMode::CreateOnly,
+                tags.clone(),
+            )
+            .await;
+        let actual_tags = cut
+            .load_parameter_tags(&ctx.profile, None, "/app/payments".to_string())
+            .await;
+
+        assert_that!(created).is_ok();
+        assert_that!(actual_tags).is_ok().is_equal_to(tags);
+    }
 }
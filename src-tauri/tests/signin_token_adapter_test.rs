@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::oneshot;
+    use url::Url;
+
+    use backend::common::secure_string::SecureString;
+    use backend::console::core::domain::{
+        ConsoleSession, ConsoleSignInConfig, Partition, SignInUrlBuilder,
+    };
+    use backend::console::core::error::ConsoleError;
+    use backend::console::core::spi::SignInTokenSPI;
+    use backend::console::infrastructure::http::signin_token_adapter::SignInTokenAdapter;
+
+    /// Serves a single canned response and hands back the request line it received.
+    async fn start_signin_stub(
+        status_line: &'static str,
+        body: &'static str,
+    ) -> (ConsoleSignInConfig, oneshot::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (request_sender, request_receiver) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0u8; 8192];
+            let read = stream.read(&mut buffer).await.unwrap();
+            let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+            let request_line = request.lines().next().unwrap_or_default().to_string();
+            let _ = request_sender.send(request_line);
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status_line,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let config = ConsoleSignInConfig {
+            signin_endpoint: Some(Url::parse(&format!("http://{}/federation", address)).unwrap()),
+            ..ConsoleSignInConfig::default()
+        };
+
+        (config, request_receiver)
+    }
+
+    fn session() -> ConsoleSession {
+        ConsoleSession::new(
+            "ASIAEXAMPLE".to_string(),
+            SecureString::from("secret"),
+            SecureString::from("token"),
+            Some("eu-west-1".to_string()),
+            false,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_load_signin_token_from_configured_endpoint() {
+        let (config, request_receiver) =
+            start_signin_stub("200 OK", r#"{"SigninToken":"stub-token"}"#).await;
+        let signin_token_url = SignInUrlBuilder::new(&config, Partition::Aws)
+            .signin_token_url(&session())
+            .unwrap();
+        let cut: Box<dyn SignInTokenSPI> = Box::new(SignInTokenAdapter);
+
+        let actual = cut.load_signin_token("dev", signin_token_url).await;
+
+        assert_that!(actual)
+            .is_ok()
+            .is_equal_to("stub-token".to_string());
+        let request_line = request_receiver.await.unwrap();
+        assert_that!(request_line.as_str()).starts_with("GET /federation?Action=getSigninToken");
+    }
+
+    #[tokio::test]
+    async fn should_return_signin_token_load_error_when_endpoint_rejects_session() {
+        let (config, _request_receiver) = start_signin_stub("400 Bad Request", "").await;
+        let signin_token_url = SignInUrlBuilder::new(&config, Partition::Aws)
+            .signin_token_url(&session())
+            .unwrap();
+        let cut: Box<dyn SignInTokenSPI> = Box::new(SignInTokenAdapter);
+
+        let actual = cut.load_signin_token("dev", signin_token_url).await;
+
+        assert_that!(actual).is_err();
+        let error = ConsoleError::from(actual.unwrap_err());
+        assert_that!(error).is_equal_to(ConsoleError::SignInTokenLoadError(
+            "400 Bad Request".to_string(),
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
     use std::{env, fs};
 
     use directories::UserDirs;
@@ -18,6 +19,7 @@ mod tests {
     struct TestContext {
         _test_dir: TempDir,
         profile: String,
+        config_file_path: PathBuf,
         original_config_file_location: String,
         original_credentials_file_location: String,
     }
@@ -56,7 +58,7 @@ mod tests {
                 .set("output", "json");
             let test_config_file_path = test_aws_dir_path.join("config");
             test_config.write_to_file(&test_config_file_path).unwrap();
-            env::set_var("AWS_CONFIG_FILE", test_config_file_path);
+            env::set_var("AWS_CONFIG_FILE", &test_config_file_path);
 
             let mut test_credentials = Ini::new();
             test_credentials
@@ -72,6 +74,7 @@ mod tests {
             TestContext {
                 _test_dir: test_dir,
                 profile: profile_name.to_string(),
+                config_file_path: test_config_file_path,
                 original_config_file_location,
                 original_credentials_file_location,
             }
@@ -86,6 +89,16 @@ mod tests {
         }
     }
 
+    impl TestContext {
+        fn configure_endpoint_url(&self, endpoint_url: &str) {
+            let mut test_config = Ini::load_from_file(&self.config_file_path).unwrap();
+            test_config
+                .with_section(Some(format!("profile {}", self.profile)))
+                .set("endpoint_url", endpoint_url);
+            test_config.write_to_file(&self.config_file_path).unwrap();
+        }
+    }
+
     #[test_context(TestContext)]
     #[tokio::test]
     #[serial]
@@ -96,7 +109,7 @@ mod tests {
 
         let host_port = localstack_container.get_host_port_ipv4(4566).await;
         let endpoint_url = format!("http://127.0.0.1:{host_port}");
-        env::set_var("LOCALSTACK_ENDPOINT", endpoint_url);
+        ctx.configure_endpoint_url(&endpoint_url);
         let cut: Box<dyn CredentialsDataSPI> = Box::new(STSAdapter);
 
         let actual = cut.get_caller_identity(&ctx.profile).await;